@@ -1,6 +1,6 @@
 //! This module contains the base elements of an OrbTk application (Application, WindowBuilder and Window).
 
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc};
 
 use dces::prelude::Entity;
 
@@ -17,6 +17,12 @@ pub struct Application {
     name: Box<str>,
     theme: Rc<Theme>,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    settings_storage: Option<Arc<dyn SettingsStorage>>,
+    settings_observer: SettingsObserver,
+    #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+    theme_loader: Option<Rc<RefCell<ThemeLoader>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    color_scheme_themes: Option<(Rc<Theme>, Rc<Theme>)>,
 }
 
 impl Default for Application {
@@ -37,6 +43,49 @@ impl Application {
         self
     }
 
+    /// Loads the theme of the application from the files of the given `ThemeLoader`.
+    /// In debug builds the files are watched and each change is applied to all windows.
+    /// If the files could not be loaded the previous theme remains active, the error with file, line
+    /// and column is reported to the handler set by `ThemeLoader::on_error`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn theme_loader(mut self, mut theme_loader: ThemeLoader) -> Self {
+        if let Ok(theme) = theme_loader.load() {
+            self.theme = theme;
+        }
+
+        #[cfg(debug_assertions)]
+        {
+            self.theme_loader = Some(Rc::new(RefCell::new(theme_loader)));
+        }
+
+        self
+    }
+
+    /// Sets a light and a dark theme. The theme that matches the preferred color scheme of the desktop
+    /// is used for all windows and switched automatically if the color scheme changes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Application::new()
+    ///     .color_scheme_themes(theme_default_light(), theme_default_dark())
+    ///     .window(|ctx| Window::new().build(ctx))
+    ///     .run();
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn color_scheme_themes(mut self, light: Theme, dark: Theme) -> Self {
+        let light = Rc::new(light);
+        let dark = Rc::new(dark);
+
+        self.theme = match ColorScheme::detect() {
+            ColorScheme::Light => light.clone(),
+            ColorScheme::Dark => dark.clone(),
+        };
+        self.color_scheme_themes = Some((light, dark));
+
+        self
+    }
+
     pub fn localization<L>(mut self, localization: L) -> Self
     where
         L: Localization + 'static,
@@ -45,6 +94,25 @@ impl Application {
         self
     }
 
+    /// Sets the storage of the `Settings` service of all windows. By default the settings are
+    /// stored as `ron` files in the user's config dir.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Application::new()
+    ///     .settings_storage(DirectoryStorage::new("settings", SettingsFormat::Ron))
+    ///     .window(|ctx| Window::new().build(ctx))
+    ///     .run();
+    /// ```
+    pub fn settings_storage<S>(mut self, storage: S) -> Self
+    where
+        S: SettingsStorage + 'static,
+    {
+        self.settings_storage = Some(Arc::new(storage));
+        self
+    }
+
     /// Create a new application with the given name.
     pub fn from_name(name: impl Into<Box<str>>) -> Self {
         let (sender, receiver) = mpsc::channel();
@@ -55,19 +123,38 @@ impl Application {
             shell: Shell::new(receiver),
             theme: Rc::new(crate::widgets::themes::theme_orbtk::theme_default()),
             localization: None,
+            settings_storage: None,
+            settings_observer: SettingsObserver::new(),
+            #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+            theme_loader: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            color_scheme_themes: None,
         }
     }
 
     /// Creates a new window and add it to the application.
     pub fn window<F: Fn(&mut BuildContext) -> Entity + 'static>(mut self, create_fn: F) -> Self {
-        let (adapter, settings, receiver) = create_window(
+        #[allow(unused_mut)]
+        let (mut adapter, settings, receiver) = create_window(
             self.name.clone(),
             &self.theme,
             self.request_sender.clone(),
             create_fn,
             self.localization.clone(),
+            self.settings_storage.clone(),
+            self.settings_observer.clone(),
         );
 
+        #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+        if let Some(theme_loader) = &self.theme_loader {
+            adapter.watch_theme(theme_loader.clone());
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some((light, dark)) = &self.color_scheme_themes {
+            adapter.follow_color_scheme(light.clone(), dark.clone());
+        }
+
         self.shell
             .create_window_from_settings(settings, adapter)
             .request_receiver(receiver)
@@ -2,7 +2,7 @@ use std::{
     cell::{Cell, RefCell},
     collections::BTreeMap,
     rc::Rc,
-    sync::mpsc,
+    sync::{mpsc, Arc},
 };
 
 use dces::prelude::*;
@@ -14,6 +14,7 @@ use crate::{
     layout::*,
     localization::Localization,
     render_object::*,
+    services::{SettingsObserver, SettingsStorage},
     shell::{ShellRequest, WindowRequest},
     utils::Point,
     widget_base::*,
@@ -49,6 +50,10 @@ pub struct ContextProvider {
     // TODO: make it thread safe
     /// Reference counted cells that hold the supported localization identifiers.
     pub localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    /// The storage of the settings service, if not set the settings are stored in the user's config dir.
+    pub settings_storage: Option<Arc<dyn SettingsStorage>>,
+    /// The observer of settings changes, that is shared by the settings services of all windows.
+    pub settings_observer: SettingsObserver,
 }
 
 impl ContextProvider {
@@ -58,6 +63,8 @@ impl ContextProvider {
         shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
         application_name: impl Into<String>,
         localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+        settings_storage: Option<Arc<dyn SettingsStorage>>,
+        settings_observer: SettingsObserver,
     ) -> Self {
         ContextProvider {
             render_objects: Rc::new(RefCell::new(BTreeMap::new())),
@@ -73,6 +80,8 @@ impl ContextProvider {
             first_run: Rc::new(Cell::new(true)),
             raw_window_handle: None,
             localization,
+            settings_storage,
+            settings_observer,
         }
     }
 }
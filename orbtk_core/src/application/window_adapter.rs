@@ -1,19 +1,28 @@
-use std::{cell::RefCell, sync::mpsc};
+use std::{
+    cell::RefCell,
+    sync::{mpsc, Arc},
+};
 
 use dces::prelude::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::services::{ColorScheme, ColorSchemeService};
+
 use crate::{
     application::*,
     event::*,
     localization::Localization,
     render,
-    services::{Clipboard, Settings},
+    services::{
+        Clipboard, ClipboardContent, ClipboardFormat, ClipboardSelection, ClipboardSync, Settings,
+        SettingsObserver, SettingsStorage,
+    },
     shell,
     shell::{ShellRequest, WindowRequest, WindowSettings},
     systems::*,
     theming::Theme,
     tree::Tree,
-    utils::{Constraint, Point, Rectangle},
+    utils::{Constraint, FlowDirection, Point, Rectangle},
     widget_base::*,
 };
 
@@ -22,7 +31,11 @@ pub struct WindowAdapter {
     world: World<Tree, render::RenderContext2D>,
     ctx: ContextProvider,
     registry: Rc<RefCell<Registry>>,
-    old_clipboard_value: Option<String>,
+    clipboard_sync: ClipboardSync,
+    #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+    theme_loader: Option<(Rc<RefCell<crate::theming::ThemeLoader>>, usize)>,
+    #[cfg(not(target_arch = "wasm32"))]
+    color_scheme_themes: Option<(Rc<Theme>, Rc<Theme>)>,
 }
 
 impl WindowAdapter {
@@ -36,12 +49,83 @@ impl WindowAdapter {
             world,
             ctx,
             registry,
-            old_clipboard_value: None,
+            clipboard_sync: ClipboardSync::default(),
+            #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+            theme_loader: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            color_scheme_themes: None,
+        }
+    }
+
+    /// Watches the files of the given theme loader. On changes the reloaded theme is switched on the window.
+    #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+    pub fn watch_theme(&mut self, theme_loader: Rc<RefCell<crate::theming::ThemeLoader>>) {
+        let generation = theme_loader.borrow().generation();
+        self.theme_loader = Some((theme_loader, generation));
+    }
+
+    /// Switches between the given light and dark theme if the preferred color scheme of the desktop changes.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn follow_color_scheme(&mut self, light: Rc<Theme>, dark: Rc<Theme>) {
+        self.color_scheme_themes = Some((light, dark));
+    }
+
+    // checks the theme loader for a reloaded theme
+    #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+    fn poll_theme_loader(&mut self) -> Option<Rc<Theme>> {
+        let (theme_loader, generation) = self.theme_loader.as_mut()?;
+        let mut theme_loader = theme_loader.borrow_mut();
+
+        // a failed reload keeps the previous theme, the error is reported to `ThemeLoader::on_error`
+        theme_loader.poll();
+
+        if theme_loader.generation() == *generation {
+            return None;
         }
+
+        *generation = theme_loader.generation();
+        theme_loader.theme()
+    }
+
+    // checks the color scheme service for a changed color scheme and sends a `ColorSchemeChangedEvent`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn poll_color_scheme(&mut self) -> Option<ColorScheme> {
+        let scheme = self
+            .registry
+            .borrow_mut()
+            .get_mut::<ColorSchemeService>("color_scheme")
+            .poll()?;
+
+        let root = self.root();
+        self.ctx
+            .event_adapter
+            .push_event_direct(root, ColorSchemeChangedEvent { scheme });
+
+        Some(scheme)
     }
 }
 
 impl WindowAdapter {
+    // sends a `ClipboardChangedEvent` for the given clipboard to the window
+    fn push_clipboard_changed(&mut self, selection: ClipboardSelection) {
+        let root = self.root();
+        self.ctx
+            .event_adapter
+            .push_event_direct(root, ClipboardChangedEvent { selection });
+    }
+
+    // sends a `ClipboardChangedEvent` for clipboard changes that are not synchronized with the
+    // system by the shell e.g. the primary selection if the shell does not support it
+    fn sync_clipboard(&mut self) {
+        let changed = self
+            .clipboard_sync
+            .changed(self.registry.borrow_mut().get_mut::<Clipboard>("clipboard"));
+
+        for selection in changed {
+            self.push_clipboard_changed(selection);
+        }
+    }
+
     fn root(&mut self) -> Entity {
         self.world
             .entity_component_manager()
@@ -52,23 +136,21 @@ impl WindowAdapter {
 }
 
 impl shell::WindowAdapter for WindowAdapter {
-    fn clipboard_update(&mut self, value: &mut Option<String>) {
-        // internal clipboard value is new => update system clipboard value.
-        if self.registry.borrow().get::<Clipboard>("clipboard").get() != self.old_clipboard_value {
-            *value = self.registry.borrow().get::<Clipboard>("clipboard").get();
-
-            self.old_clipboard_value = value.clone();
-
-            return;
-        }
+    fn clipboard_update(
+        &mut self,
+        selection: ClipboardSelection,
+        formats: &[ClipboardFormat],
+        content: &mut Option<ClipboardContent>,
+    ) {
+        let changed = self.clipboard_sync.update(
+            self.registry.borrow_mut().get_mut::<Clipboard>("clipboard"),
+            selection,
+            formats,
+            content,
+        );
 
-        //  system clipboard value is newer => update internal clipboard
-        if let Some(value) = value.clone() {
-            self.registry
-                .borrow_mut()
-                .get_mut::<Clipboard>("clipboard")
-                .set(value.clone());
-            self.old_clipboard_value = Some(value);
+        if changed {
+            self.push_clipboard_changed(selection);
         }
     }
 
@@ -155,6 +237,13 @@ impl shell::WindowAdapter for WindowAdapter {
             .push_event(root, TextInputEvent { text });
     }
 
+    fn composition_event(&mut self, event: shell::CompositionEvent) {
+        let root = self.root();
+        self.ctx
+            .event_adapter
+            .push_event(root, TextCompositionEvent { event });
+    }
+
     fn quit_event(&mut self) {
         let root = self.root();
 
@@ -171,7 +260,44 @@ impl shell::WindowAdapter for WindowAdapter {
             .push_event_direct(root, WindowEvent::ActiveChanged(active));
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn poll(&mut self) -> bool {
+        self.registry.borrow().get::<Settings>("settings").poll();
+
+        let scheme = self.poll_color_scheme();
+
+        #[allow(unused_mut)]
+        let mut theme = match (scheme, &self.color_scheme_themes) {
+            (Some(ColorScheme::Light), Some((light, _))) => Some(light.clone()),
+            (Some(ColorScheme::Dark), Some((_, dark))) => Some(dark.clone()),
+            _ => None,
+        };
+
+        #[cfg(debug_assertions)]
+        {
+            if let Some(loaded_theme) = self.poll_theme_loader() {
+                theme = Some(loaded_theme);
+            }
+        }
+
+        if let Some(theme) = theme {
+            let root = self.root();
+            self.ctx
+                .event_adapter
+                .push_event_direct(root, WindowEvent::ThemeChanged(theme));
+            return true;
+        }
+
+        scheme.is_some()
+    }
+
+    fn redraw(&mut self) {
+        let root = self.root();
+        mark_as_dirty_self(root, self.world.entity_component_manager());
+    }
+
     fn run(&mut self, render_context: &mut render::RenderContext2D) {
+        self.sync_clipboard();
         self.world.run_with_context(render_context);
     }
 
@@ -209,6 +335,8 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     create_fn: F,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    settings_storage: Option<Arc<dyn SettingsStorage>>,
+    settings_observer: SettingsObserver,
 ) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
     let app_name = app_name.into();
     let mut world: World<Tree, render::RenderContext2D> = World::from_entity_store(Tree::default());
@@ -217,25 +345,41 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
 
     let registry = Rc::new(RefCell::new(Registry::new()));
 
-    let context_provider =
-        ContextProvider::new(sender, request_sender, app_name.clone(), localization);
-
-    if app_name.is_empty() {
-        registry.borrow_mut().register(
-            "settings",
-            Settings::new(context_provider.message_adapter.clone()),
-        );
+    let context_provider = ContextProvider::new(
+        sender,
+        request_sender,
+        app_name.clone(),
+        localization,
+        settings_storage,
+        settings_observer,
+    );
+
+    let settings = if let Some(storage) = &context_provider.settings_storage {
+        Settings::from_storage(
+            app_name,
+            storage.clone(),
+            context_provider.message_adapter.clone(),
+        )
+    } else if app_name.is_empty() {
+        Settings::new(context_provider.message_adapter.clone())
     } else {
-        registry.borrow_mut().register(
-            "settings",
-            Settings::from_name(app_name, context_provider.message_adapter.clone()),
-        );
+        Settings::from_name(app_name, context_provider.message_adapter.clone())
     };
 
+    registry.borrow_mut().register(
+        "settings",
+        settings.with_observer(context_provider.settings_observer.clone()),
+    );
+
     registry
         .borrow_mut()
         .register("clipboard", Clipboard::new());
 
+    #[cfg(not(target_arch = "wasm32"))]
+    registry
+        .borrow_mut()
+        .register("color_scheme", ColorSchemeService::new());
+
     let window = {
         let overlay = Overlay::new().build(&mut BuildContext::new(
             world.entity_component_manager(),
@@ -283,6 +427,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .unwrap();
 
     let fonts = theme.fonts().clone();
+    let images = theme.images().clone();
 
     let settings = WindowSettings {
         title: world
@@ -309,6 +454,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         position: (position.x(), position.y()),
         size: (constraint.width(), constraint.height()),
         fonts,
+        images,
     };
 
     // let mut global = Global::default();
@@ -327,6 +473,31 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             Rectangle::from((0.0, 0.0, constraint.width(), constraint.height())),
         );
 
+    // the window flows in the direction of the current language, if not set explicit
+    let follows_locale = world
+        .entity_component_manager()
+        .component_store()
+        .get::<FlowDirection>("flow_direction", window)
+        .is_err();
+
+    world
+        .entity_component_manager()
+        .component_store_mut()
+        .register("flow_direction_from_locale", window, follows_locale);
+
+    if follows_locale {
+        let flow_direction = context_provider
+            .localization
+            .as_ref()
+            .map(|localization| localization.borrow().locale().flow_direction())
+            .unwrap_or_default();
+
+        world
+            .entity_component_manager()
+            .component_store_mut()
+            .register("flow_direction", window, flow_direction);
+    }
+
     world.register_init_system(InitSystem::new(context_provider.clone(), registry.clone()));
 
     world.register_cleanup_system(CleanupSystem::new(
@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, proc_macros::*, services::ClipboardSelection};
+
+/// The clipboard changed event occurs if the content of the clipboard or of the primary
+/// selection is changed by the application or by the system. It is sent to the window.
+#[derive(Clone, Debug, Event)]
+pub struct ClipboardChangedEvent {
+    /// The changed clipboard.
+    pub selection: ClipboardSelection,
+}
+
+/// Callback closure to handle clipboard changed events.
+pub type ClipboardHandlerFn = dyn Fn(&mut StatesContext, ClipboardSelection) -> bool + 'static;
+
+/// Internal struct to manage clipboard changed event handlers.
+#[derive(IntoHandler)]
+pub struct ClipboardEventHandler {
+    handler: Rc<ClipboardHandlerFn>,
+}
+
+impl EventHandler for ClipboardEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ClipboardChangedEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.selection)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ClipboardChangedEvent>()
+    }
+}
+
+/// Implement this trait for widgets that should react on changes of the clipboard.
+pub trait ClipboardHandler: Sized + Widget {
+    /// Callback that is called when the clipboard changed event reaches the widget.
+    ///
+    /// If the callback returns `true` the event is marked as handled and will not available to
+    /// to other widgets.
+    fn on_clipboard_changed<H: Fn(&mut StatesContext, ClipboardSelection) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ClipboardEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, proc_macros::*, services::ColorScheme};
+
+/// The color scheme changed event occurs if the preferred color scheme of the desktop changes.
+#[derive(Clone, Debug, Event)]
+pub struct ColorSchemeChangedEvent {
+    /// The new color scheme.
+    pub scheme: ColorScheme,
+}
+
+/// Callback closure to handle color scheme changed events.
+pub type ColorSchemeHandlerFn = dyn Fn(&mut StatesContext, ColorScheme) -> bool + 'static;
+
+/// Internal struct to manage color scheme changed event handlers.
+#[derive(IntoHandler)]
+pub struct ColorSchemeEventHandler {
+    handler: Rc<ColorSchemeHandlerFn>,
+}
+
+impl EventHandler for ColorSchemeEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ColorSchemeChangedEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.scheme))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ColorSchemeChangedEvent>()
+    }
+}
+
+/// Implement this trait for widgets that should react on changes of the preferred color scheme.
+pub trait ColorSchemeHandler: Sized + Widget {
+    /// Callback that is called when the color scheme changed event reaches the widget.
+    ///
+    /// If the callback returns `true` the event is marked as handled and will not available to
+    /// to other widgets.
+    fn on_color_scheme_changed<H: Fn(&mut StatesContext, ColorScheme) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ColorSchemeEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
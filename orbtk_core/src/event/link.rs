@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+/// `LinkClickedEvent` occurs when the user clicks on a link of a rich text.
+#[derive(Event, Clone)]
+pub struct LinkClickedEvent {
+    /// The target of the clicked link.
+    pub href: String,
+
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// Callback closure to handle link clicked events.
+pub type LinkHandlerFn = dyn Fn(&mut StatesContext, String) -> bool + 'static;
+
+/// Structure to handle clicked links.
+#[derive(IntoHandler)]
+pub struct LinkClickedEventHandler {
+    /// A reference counted handler object.
+    pub handler: Rc<LinkHandlerFn>,
+}
+
+impl EventHandler for LinkClickedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<LinkClickedEvent>() {
+            return (self.handler)(states, event.href.clone());
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<LinkClickedEvent>()
+    }
+}
+
+/// Implement this trait for widgets that draw rich text with links.
+pub trait LinkHandler: Sized + Widget {
+    /// Inserts a handler that is called with the href of a clicked link.
+    fn on_link_click<H: Fn(&mut StatesContext, String) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(LinkClickedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -6,6 +6,8 @@ use dces::entity::Entity;
 
 use crate::widget_base::StatesContext;
 
+pub use self::clipboard::*;
+pub use self::color_scheme::*;
 pub use self::drop::*;
 pub use self::editable::*;
 pub use self::event_adapter::*;
@@ -13,11 +15,15 @@ pub use self::event_handler::*;
 pub use self::event_queue::*;
 pub use self::focus::*;
 pub use self::key::*;
+pub use self::link::*;
 pub use self::mouse::*;
 pub use self::system::*;
+pub use self::text_composition::*;
 pub use self::text_input::*;
 pub use self::window::*;
 
+mod clipboard;
+mod color_scheme;
 mod drop;
 mod editable;
 mod event_adapter;
@@ -25,8 +31,10 @@ mod event_handler;
 mod event_queue;
 mod focus;
 mod key;
+mod link;
 mod mouse;
 mod system;
+mod text_composition;
 mod text_input;
 mod window;
 
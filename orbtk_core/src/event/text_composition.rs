@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, proc_macros::*, shell::CompositionEvent};
+
+/// The text composition event occurs if an input method starts, updates or commits a composition.
+#[derive(Clone, Debug, Event)]
+pub struct TextCompositionEvent {
+    /// The composition event of the shell.
+    pub event: CompositionEvent,
+}
+
+/// Callback closure to handle text composition events.
+pub type TextCompositionHandlerFn = dyn Fn(&mut StatesContext, CompositionEvent) -> bool + 'static;
+
+/// Internal struct to manage text composition event handlers.
+#[derive(IntoHandler)]
+pub struct TextCompositionEventHandler {
+    handler: Rc<TextCompositionHandlerFn>,
+}
+
+impl EventHandler for TextCompositionEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TextCompositionEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.event.clone())
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TextCompositionEvent>()
+    }
+}
+
+/// Implement this trait for widgets that should handle the text composition of input methods.
+pub trait TextCompositionHandler: Sized + Widget {
+    /// Callback that is called when a text composition event reaches the widget.
+    ///
+    /// If the callback returns `true` the event is marked as handled and will not available to
+    /// to other widgets.
+    fn on_text_composition<H: Fn(&mut StatesContext, CompositionEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TextCompositionEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -2,13 +2,14 @@ use std::rc::Rc;
 
 use super::*;
 
-use crate::{proc_macros::*, widget_base::*};
+use crate::{proc_macros::*, theming::Theme, widget_base::*};
 
 /// The enumeration of valid window events.
 #[derive(Clone, Event)]
 pub enum WindowEvent {
     Resize { width: f64, height: f64 },
     ActiveChanged(bool),
+    ThemeChanged(Rc<Theme>),
     None,
 }
 
@@ -10,22 +10,31 @@ use smallvec::SmallVec;
 
 use crate::{
     proc_macros::IntoLayout,
+    properties::{RichText, TextSpan},
     render::Image,
     render::RenderContext2D,
+    render_object::{TextFormat, TextLayout, TextStyle},
     theming::*,
     tree::Tree,
     utils::prelude::*,
     widget_base::{mark_as_dirty, WidgetContainer},
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, request_relayout, Layout};
 
 /// A fixed sized layout is defined by the fixed bounds.
 /// Examples are the size of an image or the size of a text.
+///
+/// Text is wrapped and trimmed to the `max_width` of the widget or to the
+/// width, the parent provides on arrange. If the width changes, the text is
+/// measured again on arrange and the layout requests a relayout of the tree.
 #[derive(Default, IntoLayout)]
 pub struct FixedSizeLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    available_width: Cell<f64>,
+    wraps: Cell<bool>,
+    relayout: Cell<bool>,
 }
 
 impl FixedSizeLayout {
@@ -33,6 +42,18 @@ impl FixedSizeLayout {
     pub fn new() -> Self {
         FixedSizeLayout::default()
     }
+
+    // Gets the width text is wrapped to or `None` if the width is unlimited.
+    fn wrap_width(&self, max_width: f64) -> Option<f64> {
+        let available_width = self.available_width.get();
+
+        match (available_width > 0.0, max_width < f64::MAX) {
+            (true, true) => Some(available_width.min(max_width)),
+            (true, false) => Some(available_width),
+            (false, true) => Some(max_width),
+            (false, false) => None,
+        }
+    }
 }
 
 impl Layout for FixedSizeLayout {
@@ -49,6 +70,9 @@ impl Layout for FixedSizeLayout {
             return *self.desired_size.borrow();
         }
 
+        self.relayout.set(false);
+        self.wraps.set(false);
+
         let widget = WidgetContainer::new(entity, ecm, theme, None);
 
         let horizontal_alignment: Alignment = *widget.get("h_align");
@@ -60,9 +84,27 @@ impl Layout for FixedSizeLayout {
             self.desired_size.borrow_mut().set_dirty(true);
         }
 
+        let max_width = widget.get::<Constraint>("constraint").max_width();
+        let format = TextFormat::from_widget(&widget);
+
         let size = widget
             .try_get::<Image>("image")
             .map(|image| (Size::new(image.width(), image.height())))
+            .or_else(|| {
+                widget
+                    .try_get::<RichText>("rich_text")
+                    .filter(|rich_text| !rich_text.is_empty())
+                    .map(|rich_text| {
+                        self.wraps.set(true);
+                        measure_rich_text(
+                            render_context_2_d,
+                            &widget,
+                            rich_text,
+                            &format,
+                            self.wrap_width(max_width),
+                        )
+                    })
+            })
             .or_else(|| {
                 text(&widget).and_then(|text| {
                     let font = widget.get::<String>("font");
@@ -80,6 +122,15 @@ impl Layout for FixedSizeLayout {
                                     *font_size,
                                 )
                             })
+                    } else if !format.is_single_line() {
+                        self.wraps.set(true);
+                        Some(measure_rich_text(
+                            render_context_2_d,
+                            &widget,
+                            &RichText::from(vec![TextSpan::from(text)]),
+                            &format,
+                            self.wrap_width(max_width),
+                        ))
                     } else {
                         Some(measure_text(
                             render_context_2_d,
@@ -142,7 +193,7 @@ impl Layout for FixedSizeLayout {
     fn arrange(
         &self,
         render_context_2_d: &mut RenderContext2D,
-        _parent_size: (f64, f64),
+        parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -153,6 +204,24 @@ impl Layout for FixedSizeLayout {
             return (0.0, 0.0);
         }
 
+        let margin: Thickness = component(ecm, entity, "margin");
+        let available_width = parent_size.0 - margin.left() - margin.right();
+
+        // wrapped text depends on the available width => measure it again with the new width
+        if (available_width - self.available_width.get()).abs() > f64::EPSILON {
+            self.available_width.set(available_width);
+
+            if self.wraps.get() {
+                let old_size = self.desired_size.borrow().size();
+                self.measure(render_context_2_d, entity, ecm, layouts, theme);
+
+                if self.desired_size.borrow().size() != old_size {
+                    self.relayout.set(true);
+                    request_relayout();
+                }
+            }
+        }
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(self.desired_size.borrow().width());
             bounds.set_height(self.desired_size.borrow().height());
@@ -177,7 +246,11 @@ impl Layout for FixedSizeLayout {
             }
         }
 
-        self.desired_size.borrow_mut().set_dirty(false);
+        // keeps the changed size dirty, the parents measure it again on relayout
+        if !self.relayout.get() {
+            self.desired_size.borrow_mut().set_dirty(false);
+        }
+
         self.desired_size.borrow().size()
     }
 }
@@ -198,6 +271,22 @@ fn text(widget: &WidgetContainer) -> Option<String> {
     None
 }
 
+fn measure_rich_text(
+    render_context_2_d: &mut RenderContext2D,
+    widget: &WidgetContainer,
+    rich_text: &RichText,
+    format: &TextFormat,
+    max_width: Option<f64>,
+) -> Size {
+    let style = TextStyle {
+        font: widget.clone::<String>("font"),
+        font_size: *widget.get::<f64>("font_size"),
+        foreground: Brush::default(),
+    };
+
+    TextLayout::new(render_context_2_d, rich_text, &style, format, max_width).size()
+}
+
 fn measure_text(
     render_context_2_d: &mut RenderContext2D,
     text: &str,
@@ -222,3 +311,112 @@ fn measure_text(
         text_metrics.height * (((lines.len() - 1) as f64) * 1.15 + 1.0),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        layout::{begin_layout_pass, end_layout_pass, take_relayout},
+        properties::TextWrap,
+    };
+
+    const FONT: &str = "Roboto-Regular";
+    const FONT_SIZE: f64 = 14.0;
+    const TEXT: &str = "The quick brown fox jumps over the lazy dog and keeps on running";
+
+    // Creates a text block inside of a world, the text is set as rich text or as text.
+    fn text_block(rich_text: bool, text_wrap: TextWrap) -> World<Tree, RenderContext2D> {
+        let mut world: World<Tree, RenderContext2D> = World::from_entity_store(Tree::default());
+        let ecm = world.entity_component_manager();
+        let entity = ecm.create_entity().build();
+        ecm.entity_store_mut().set_root(entity);
+
+        let store = ecm.component_store_mut();
+        store.register("visibility", entity, Visibility::Visible);
+        store.register("h_align", entity, Alignment::Stretch);
+        store.register("v_align", entity, Alignment::Start);
+        store.register("margin", entity, Thickness::default());
+        store.register("constraint", entity, Constraint::default());
+        store.register("bounds", entity, Rectangle::default());
+        store.register("dirty", entity, false);
+        store.register("font", entity, FONT.to_string());
+        store.register("font_size", entity, FONT_SIZE);
+        store.register("text_wrap", entity, text_wrap);
+
+        if rich_text {
+            store.register("rich_text", entity, RichText::from(TEXT));
+        } else {
+            store.register("text", entity, TEXT.to_string());
+        }
+
+        world
+    }
+
+    // Lays out the text block once with the given width. Returns its bounds, the line height and
+    // if a relayout is requested.
+    fn layout_once(rich_text: bool, text_wrap: TextWrap, width: f64) -> (Rectangle, f64, bool) {
+        let mut world = text_block(rich_text, text_wrap);
+        let ecm = world.entity_component_manager();
+        let entity = ecm.entity_store().root();
+
+        let mut render_context = RenderContext2D::new(width, 400.0);
+        render_context.register_font(
+            FONT,
+            include_bytes!("../../../orbtk_widgets/assets/fonts/roboto/Roboto-Regular.ttf"),
+        );
+        let line_height = render_context.measure(TEXT, FONT_SIZE, FONT).height;
+
+        let theme = Theme::default();
+        let mut layouts: BTreeMap<Entity, Box<dyn Layout>> = BTreeMap::new();
+        layouts.insert(entity, Box::new(FixedSizeLayout::new()));
+
+        begin_layout_pass();
+        layouts[&entity].measure(&mut render_context, entity, ecm, &layouts, &theme);
+        layouts[&entity].arrange(
+            &mut render_context,
+            (width, 400.0),
+            entity,
+            ecm,
+            &layouts,
+            &theme,
+        );
+        let relayout = take_relayout();
+        end_layout_pass();
+
+        let bounds = *ecm
+            .component_store()
+            .get::<Rectangle>("bounds", entity)
+            .unwrap();
+
+        (bounds, line_height, relayout)
+    }
+
+    #[test]
+    fn wraps_text_on_first_layout() {
+        let (bounds, line_height, relayout) = layout_once(false, TextWrap::Word, 100.0);
+
+        assert!(relayout);
+        assert!(bounds.width() <= 100.0);
+        assert!(bounds.height() > line_height * 2.0);
+    }
+
+    #[test]
+    fn wraps_rich_text_on_first_layout() {
+        let (bounds, line_height, relayout) = layout_once(true, TextWrap::Word, 100.0);
+
+        assert!(relayout);
+        assert!(bounds.width() <= 100.0);
+        assert!(bounds.height() > line_height * 2.0);
+    }
+
+    #[test]
+    fn wraps_only_rich_text_by_default() {
+        let (bounds, line_height, _) = layout_once(true, TextWrap::Auto, 100.0);
+        assert!(bounds.height() > line_height * 2.0);
+
+        let (bounds, line_height, relayout) = layout_once(false, TextWrap::Auto, 100.0);
+        assert!(!relayout);
+        assert!(bounds.width() > 100.0);
+        assert!(bounds.height() < line_height * 2.0);
+    }
+}
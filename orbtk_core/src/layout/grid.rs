@@ -10,7 +10,7 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, flow_direction, mirror_x, Layout};
 
 /// Orders its children in a grid layout with columns and rows. If no
 /// columns and rows are defined the grid layout can also be used as
@@ -20,6 +20,7 @@ pub struct GridLayout {
     desired_size: RefCell<DirtySize>,
     children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    old_flow_direction: Cell<FlowDirection>,
 }
 
 impl GridLayout {
@@ -200,6 +201,14 @@ impl Layout for GridLayout {
             self.desired_size.borrow_mut().set_dirty(true);
         }
 
+        // columns are mirrored, if the flow direction has changed
+        let flow_direction = flow_direction(ecm, entity);
+
+        if flow_direction != self.old_flow_direction.get() {
+            self.old_flow_direction.set(flow_direction);
+            self.desired_size.borrow_mut().set_dirty(true);
+        }
+
         // go ahead an calculate all child size requirements
         self.children_sizes.borrow_mut().clear();
 
@@ -487,6 +496,10 @@ impl Layout for GridLayout {
                             child_margin.bottom(),
                         ),
                 );
+
+                if self.old_flow_direction.get().is_right_to_left() {
+                    mirror_x(child_bounds, size.0);
+                }
             }
 
             mark_as_dirty("bounds", child, ecm);
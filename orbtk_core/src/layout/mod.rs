@@ -1,5 +1,9 @@
 //! This module contains the layout types of an OrbTk application (Absolute, Fixed, Grid, Padding, Popup, Stack).
-use std::{any::Any, collections::BTreeMap};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
 
 use dces::prelude::*;
 
@@ -51,6 +55,84 @@ pub trait Layout: Any {
     ) -> (f64, f64);
 }
 
+thread_local! {
+    // flow directions that are resolved during the current layout pass, `None` outside of a pass
+    static FLOW_DIRECTIONS: RefCell<Option<BTreeMap<Entity, FlowDirection>>> = RefCell::new(None);
+
+    // set if a layout requests to measure and arrange the tree again
+    static RELAYOUT: Cell<bool> = Cell::new(false);
+}
+
+/// Starts a layout pass. Until `end_layout_pass` is called the inherited flow directions are
+/// resolved only once per entity.
+pub(crate) fn begin_layout_pass() {
+    FLOW_DIRECTIONS.with(|flow_directions| *flow_directions.borrow_mut() = Some(BTreeMap::new()));
+    RELAYOUT.with(|relayout| relayout.set(false));
+}
+
+/// Requests to measure and arrange the tree again after the current layout pass, e.g. if the
+/// desired size of a layout changed during arrange because text is wrapped to the arranged width.
+pub fn request_relayout() {
+    RELAYOUT.with(|relayout| relayout.set(true));
+}
+
+/// Returns `true` if a relayout was requested since the last call and resets the request.
+pub(crate) fn take_relayout() -> bool {
+    RELAYOUT.with(|relayout| relayout.replace(false))
+}
+
+/// Ends the layout pass and drops the resolved flow directions.
+pub(crate) fn end_layout_pass() {
+    FLOW_DIRECTIONS.with(|flow_directions| *flow_directions.borrow_mut() = None);
+}
+
+/// Returns the flow direction of the given entity. If the entity doesn't define a flow direction,
+/// the flow direction of the nearest ancestor is used, defaults to `LeftToRight`.
+pub fn flow_direction(ecm: &EntityComponentManager<Tree>, entity: Entity) -> FlowDirection {
+    FLOW_DIRECTIONS.with(|flow_directions| {
+        let mut flow_directions = flow_directions.borrow_mut();
+        let mut flow_direction = FlowDirection::default();
+        let mut resolved = vec![];
+        let mut current = Some(entity);
+
+        while let Some(entity) = current {
+            if let Some(cached) = flow_directions
+                .as_ref()
+                .and_then(|flow_directions| flow_directions.get(&entity))
+            {
+                flow_direction = *cached;
+                break;
+            }
+
+            resolved.push(entity);
+
+            if let Ok(own) = ecm
+                .component_store()
+                .get::<FlowDirection>("flow_direction", entity)
+            {
+                flow_direction = *own;
+                break;
+            }
+
+            current = ecm.entity_store().parent.get(&entity).copied().flatten();
+        }
+
+        // the walked ancestors inherit the same flow direction
+        if let Some(flow_directions) = flow_directions.as_mut() {
+            for entity in resolved {
+                flow_directions.insert(entity, flow_direction);
+            }
+        }
+
+        flow_direction
+    })
+}
+
+// mirrors the horizontal position of the given bounds inside of the given width
+fn mirror_x(bounds: &mut Rectangle, width: f64) {
+    bounds.set_x(width - bounds.x() - bounds.width());
+}
+
 fn component<C: Component + Clone>(
     ecm: &mut EntityComponentManager<Tree>,
     entity: Entity,
@@ -94,3 +176,33 @@ fn component_try_mut<'a, C: Component>(
         .get_mut::<C>(component, entity)
         .ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flow_direction() {
+        let mut world: World<Tree, RenderContext2D> = World::from_entity_store(Tree::default());
+        let ecm = world.entity_component_manager();
+        let window = ecm.create_entity().build();
+        let child = ecm.create_entity().build();
+        ecm.entity_store_mut().set_root(window);
+        ecm.entity_store_mut().append_child(window, child).unwrap();
+        ecm.component_store_mut()
+            .register("flow_direction", window, FlowDirection::RightToLeft);
+
+        assert_eq!(flow_direction(ecm, child), FlowDirection::RightToLeft);
+
+        // during a layout pass the inherited flow direction is resolved once
+        begin_layout_pass();
+        assert_eq!(flow_direction(ecm, child), FlowDirection::RightToLeft);
+        *ecm.component_store_mut()
+            .get_mut::<FlowDirection>("flow_direction", window)
+            .unwrap() = FlowDirection::LeftToRight;
+        assert_eq!(flow_direction(ecm, child), FlowDirection::RightToLeft);
+        end_layout_pass();
+
+        assert_eq!(flow_direction(ecm, child), FlowDirection::LeftToRight);
+    }
+}
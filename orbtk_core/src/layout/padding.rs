@@ -10,7 +10,7 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, flow_direction, mirror_x, Layout};
 
 /// Add padding to the widget.
 #[derive(Default, IntoLayout)]
@@ -156,6 +156,7 @@ impl Layout for PaddingLayout {
             size.0 - padding.left() - padding.right(),
             size.1 - padding.top() - padding.bottom(),
         );
+        let right_to_left = flow_direction(ecm, entity).is_right_to_left();
 
         for index in 0..ecm.entity_store().children[&entity].len() {
             let child = ecm.entity_store().children[&entity][index];
@@ -199,6 +200,10 @@ impl Layout for PaddingLayout {
                             child_margin.bottom(),
                         ),
                 );
+
+                if right_to_left {
+                    mirror_x(child_bounds, size.0);
+                }
             }
 
             mark_as_dirty("bounds", child, ecm);
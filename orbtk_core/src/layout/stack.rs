@@ -10,13 +10,14 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_or_default, component_try_mut, Layout};
+use super::{component, component_or_default, component_try_mut, flow_direction, mirror_x, Layout};
 
 /// Stacks visual the children widgets vertical or horizontal.
 #[derive(Default, IntoLayout)]
 pub struct StackLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    old_flow_direction: Cell<FlowDirection>,
 }
 
 impl StackLayout {
@@ -53,6 +54,13 @@ impl Layout for StackLayout {
             self.set_dirty(true);
         }
 
+        let flow_direction = flow_direction(ecm, entity);
+
+        if flow_direction != self.old_flow_direction.get() {
+            self.old_flow_direction.set(flow_direction);
+            self.set_dirty(true);
+        }
+
         let orientation: Orientation = component(ecm, entity, "orientation");
         let mut dirty = false;
         let mut desired_size: (f64, f64) = (0.0, 0.0);
@@ -206,6 +214,17 @@ impl Layout for StackLayout {
             }
         }
 
+        // right to left the children are stacked from the right edge
+        if self.old_flow_direction.get().is_right_to_left() {
+            for index in 0..nchildren {
+                let child = ecm.entity_store().children[&entity][index];
+
+                if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                    mirror_x(child_bounds, size.0);
+                }
+            }
+        }
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
             bounds.set_height(size.1);
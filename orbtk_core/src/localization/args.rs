@@ -0,0 +1,276 @@
+use std::{collections::HashMap, fmt};
+
+use crate::localization::{DateFormat, DateTime, Locale};
+
+/// Value of a localization argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocalizationValue {
+    /// A number, selects the plural form of a translation.
+    Number(f64),
+
+    /// A text e.g. a name, selects the case of a select translation.
+    String(String),
+
+    /// A date and time, formatted with the locale of the language.
+    DateTime(DateTime),
+}
+
+impl fmt::Display for LocalizationValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LocalizationValue::Number(number) => write!(f, "{}", number),
+            LocalizationValue::String(string) => write!(f, "{}", string),
+            LocalizationValue::DateTime(date) => write!(
+                f,
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                date.year, date.month, date.day, date.hour, date.minute, date.second
+            ),
+        }
+    }
+}
+
+impl From<f64> for LocalizationValue {
+    fn from(n: f64) -> Self {
+        LocalizationValue::Number(n)
+    }
+}
+
+impl From<f32> for LocalizationValue {
+    fn from(n: f32) -> Self {
+        LocalizationValue::Number(n as f64)
+    }
+}
+
+impl From<i32> for LocalizationValue {
+    fn from(n: i32) -> Self {
+        LocalizationValue::Number(n as f64)
+    }
+}
+
+impl From<i64> for LocalizationValue {
+    fn from(n: i64) -> Self {
+        LocalizationValue::Number(n as f64)
+    }
+}
+
+impl From<u32> for LocalizationValue {
+    fn from(n: u32) -> Self {
+        LocalizationValue::Number(n as f64)
+    }
+}
+
+impl From<usize> for LocalizationValue {
+    fn from(n: usize) -> Self {
+        LocalizationValue::Number(n as f64)
+    }
+}
+
+impl From<DateTime> for LocalizationValue {
+    fn from(date: DateTime) -> Self {
+        LocalizationValue::DateTime(date)
+    }
+}
+
+impl From<&str> for LocalizationValue {
+    fn from(s: &str) -> Self {
+        LocalizationValue::String(s.to_string())
+    }
+}
+
+impl From<String> for LocalizationValue {
+    fn from(s: String) -> Self {
+        LocalizationValue::String(s)
+    }
+}
+
+/// Named arguments of a translation. The placeholders `{name}` of a translated text are
+/// replaced by the values of the arguments.
+///
+/// Numbers and dates are formatted with the locale of the language. A placeholder could
+/// choose the format with a style:
+///
+/// * `{count}` or `{count:number}`: number with up to three fraction digits e.g. `1,234.5`.
+/// * `{size:fixed:2}`: number with two fraction digits.
+/// * `{ratio:percent}`: percentage e.g. `25%` for `0.25`.
+/// * `{price:currency:EUR}`: amount with currency e.g. `€12.50`.
+/// * `{day:date}` or `{day:date:long}`: date e.g. `1/31/2024` or `January 31, 2024`.
+/// * `{day:time}` or `{day:time:long}`: time e.g. `3:05 PM` or `3:05:09 PM`.
+///
+/// # Example
+///
+/// ```rust
+/// let args = LocalizationArgs::new().arg("name", "Anna").arg("count", 3);
+/// let text = localization.text_with_args("files_selected".to_string(), &args);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocalizationArgs {
+    args: HashMap<String, LocalizationValue>,
+}
+
+impl LocalizationArgs {
+    /// Creates new empty arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder method that adds the argument with the given name.
+    pub fn arg(mut self, name: impl Into<String>, value: impl Into<LocalizationValue>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    /// Sets the value of the argument with the given name.
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<LocalizationValue>) {
+        self.args.insert(name.into(), value.into());
+    }
+
+    /// Gets the value of the argument with the given name.
+    pub fn get(&self, name: &str) -> Option<&LocalizationValue> {
+        self.args.get(name)
+    }
+
+    /// Returns an iterator over the names and values of the arguments.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &LocalizationValue)> {
+        self.args.iter()
+    }
+
+    /// Returns `true` if there are no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Replaces the placeholders `{name}` of the given text with the values of the arguments
+    /// formatted with the given locale. Placeholders without argument are kept, `{{` and `}}`
+    /// are written as single braces.
+    pub fn format(&self, text: &str, locale: &Locale) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    result.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    result.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    let mut closed = false;
+
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            closed = true;
+                            break;
+                        }
+                        name.push(c);
+                    }
+
+                    let mut spec = name.split(':').map(str::trim);
+                    let value = spec.next().and_then(|name| self.get(name));
+
+                    match value {
+                        Some(value) if closed => {
+                            result.push_str(&format_value(value, spec.next(), spec.next(), locale))
+                        }
+                        _ => {
+                            result.push('{');
+                            result.push_str(&name);
+
+                            if closed {
+                                result.push('}');
+                            }
+                        }
+                    }
+                }
+                _ => result.push(c),
+            }
+        }
+
+        result
+    }
+}
+
+// formats the value with the style and option of a placeholder e.g. `currency` and `EUR`
+fn format_value(
+    value: &LocalizationValue,
+    style: Option<&str>,
+    option: Option<&str>,
+    locale: &Locale,
+) -> String {
+    match value {
+        LocalizationValue::Number(number) => match (style, option) {
+            (Some("fixed"), Some(digits)) => {
+                locale.format_fixed(*number, digits.parse().unwrap_or_default())
+            }
+            (Some("percent"), _) => locale.format_percent(*number),
+            (Some("currency"), Some(currency)) => locale.format_currency(*number, currency),
+            _ => locale.format_number(*number),
+        },
+        LocalizationValue::DateTime(date) => {
+            let format = DateFormat::from(option.unwrap_or_default());
+
+            match style {
+                Some("time") => locale.format_time(date, format),
+                _ => locale.format_date(date, format),
+            }
+        }
+        LocalizationValue::String(string) => string.clone(),
+    }
+}
+
+impl<N: Into<String>, V: Into<LocalizationValue>> From<Vec<(N, V)>> for LocalizationArgs {
+    fn from(args: Vec<(N, V)>) -> Self {
+        args.into_iter()
+            .fold(LocalizationArgs::new(), |args, (name, value)| {
+                args.arg(name, value)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let args = LocalizationArgs::new().arg("name", "Anna").arg("count", 3);
+        let locale = Locale::default();
+
+        assert_eq!(
+            args.format("Welcome back, {name}!", &locale),
+            "Welcome back, Anna!"
+        );
+        assert_eq!(args.format("{count} files", &locale), "3 files");
+        assert_eq!(
+            args.format("{ name } has {missing}", &locale),
+            "Anna has {missing}"
+        );
+        assert_eq!(args.format("{{name}} {name", &locale), "{name} {name");
+        assert_eq!(
+            LocalizationArgs::from(vec![("value", 2.5)]).format("{value}", &locale),
+            "2.5"
+        );
+    }
+
+    #[test]
+    fn test_format_styles() {
+        let args = LocalizationArgs::new()
+            .arg("size", 1234.5)
+            .arg("ratio", 0.25)
+            .arg("day", DateTime::date(2024, 1, 31).time(15, 5, 0));
+
+        let en = Locale::new("en_US");
+        let de = Locale::new("de_DE");
+
+        assert_eq!(args.format("{size}", &en), "1,234.5");
+        assert_eq!(args.format("{size}", &de), "1.234,5");
+        assert_eq!(args.format("{size:fixed:2}", &de), "1.234,50");
+        assert_eq!(args.format("{ratio:percent}", &en), "25%");
+        assert_eq!(args.format("{size:currency:EUR}", &en), "€1,234.50");
+        assert_eq!(args.format("{day:date:long}", &de), "31. Januar 2024");
+        assert_eq!(args.format("{day:time}", &en), "3:05 PM");
+    }
+}
@@ -0,0 +1,119 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A date and time of the proleptic gregorian calendar without time zone, used to format dates
+/// and times with a `Locale`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    /// The year e.g. `2024`.
+    pub year: i32,
+
+    /// The month from `1` to `12`.
+    pub month: u32,
+
+    /// The day of the month from `1` to `31`.
+    pub day: u32,
+
+    /// The hour from `0` to `23`.
+    pub hour: u32,
+
+    /// The minute from `0` to `59`.
+    pub minute: u32,
+
+    /// The second from `0` to `59`.
+    pub second: u32,
+}
+
+impl DateTime {
+    /// Creates a new date at midnight.
+    pub fn date(year: i32, month: u32, day: u32) -> Self {
+        DateTime {
+            year,
+            month,
+            day,
+            ..Default::default()
+        }
+    }
+
+    /// Builder method that sets the time.
+    pub fn time(mut self, hour: u32, minute: u32, second: u32) -> Self {
+        self.hour = hour;
+        self.minute = minute;
+        self.second = second;
+        self
+    }
+
+    /// Creates the date and time of the given seconds since the unix epoch in UTC.
+    pub fn from_unix(seconds: i64) -> Self {
+        let days = seconds.div_euclid(86_400);
+        let time = seconds.rem_euclid(86_400) as u32;
+
+        // civil from days, see http://howardhinnant.github.io/date_algorithms.html
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+
+        DateTime::date(year, month, day).time(time / 3600, time / 60 % 60, time % 60)
+    }
+
+    /// Returns the current date and time in UTC.
+    pub fn now() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs() as i64);
+
+        DateTime::from_unix(seconds)
+    }
+}
+
+/// Describes the length of a formatted date or time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DateFormat {
+    /// Numeric date e.g. `1/31/2024` or time without seconds e.g. `3:05 PM`.
+    Short,
+
+    /// Date with month name e.g. `January 31, 2024` or time with seconds e.g. `3:05:09 PM`.
+    Long,
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        DateFormat::Short
+    }
+}
+
+impl From<&str> for DateFormat {
+    fn from(s: &str) -> DateFormat {
+        match s {
+            "long" | "Long" => DateFormat::Long,
+            _ => DateFormat::Short,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_unix() {
+        assert_eq!(DateTime::from_unix(0), DateTime::date(1970, 1, 1));
+        assert_eq!(
+            DateTime::from_unix(1_706_713_509),
+            DateTime::date(2024, 1, 31).time(15, 5, 9)
+        );
+        assert_eq!(
+            DateTime::from_unix(951_782_400),
+            DateTime::date(2000, 2, 29)
+        );
+        assert_eq!(
+            DateTime::from_unix(-1),
+            DateTime::date(1969, 12, 31).time(23, 59, 59)
+        );
+    }
+}
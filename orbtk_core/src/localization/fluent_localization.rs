@@ -0,0 +1,348 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+use crate::localization::{
+    negotiate_languages, DateFormat, Localization, LocalizationArgs, LocalizationError,
+    LocalizationValue, MissingMessage, MissingMessages,
+};
+
+#[derive(Debug, Clone)]
+enum Source {
+    Text(String),
+    File(PathBuf),
+}
+
+/// Used to build a new `FluentLocalization` and configure the `.ftl` resources, the initial
+/// language and the fallback languages.
+#[derive(Debug, Default, Clone)]
+pub struct FluentLocalizationBuilder {
+    language: String,
+    fallback_languages: Vec<String>,
+    resources: Vec<(String, Source)>,
+}
+
+impl FluentLocalizationBuilder {
+    /// Adds a Fluent resource of the given language. A language could have multiple resources,
+    /// messages of later resources replace messages with the same key.
+    pub fn resource(mut self, language: impl Into<String>, resource: &str) -> Self {
+        self.resources
+            .push((language.into(), Source::Text(resource.to_string())));
+        self
+    }
+
+    /// Adds a `.ftl` file of the given language. The file is read on build.
+    pub fn file(mut self, language: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        self.resources
+            .push((language.into(), Source::File(path.into())));
+        self
+    }
+
+    /// Sets the initial language.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = language.into();
+        self
+    }
+
+    /// Adds a fallback language. Messages that are not translated in the current language are
+    /// looked up in the fallback languages in the order they are added.
+    pub fn fallback_language(mut self, language: impl Into<String>) -> Self {
+        self.fallback_languages.push(language.into());
+        self
+    }
+
+    /// Builds a new fluent localization service. Returns an error if a file could not be read
+    /// or a resource could not be parsed.
+    pub fn build(self) -> Result<FluentLocalization, LocalizationError> {
+        let mut bundles: HashMap<String, FluentBundle<FluentResource>> = HashMap::new();
+
+        for (language, source) in self.resources {
+            let source = match source {
+                Source::Text(text) => text,
+                Source::File(path) => {
+                    fs::read_to_string(&path).map_err(|e| LocalizationError::Io {
+                        path: path.clone(),
+                        message: e.to_string(),
+                    })?
+                }
+            };
+
+            let resource = FluentResource::try_new(source.clone()).map_err(|(_, errors)| {
+                let error = &errors[0];
+                let (line, column) = position(&source, error.pos.start);
+
+                LocalizationError::Parse {
+                    language: language.clone(),
+                    line,
+                    column,
+                    message: error.to_string(),
+                }
+            })?;
+
+            bundles
+                .entry(language.clone())
+                .or_insert_with(|| {
+                    let id: LanguageIdentifier = language.parse().unwrap_or_default();
+                    let mut bundle = FluentBundle::new(vec![id]);
+
+                    // unicode isolation marks around placeholders are not drawn by the text renderer
+                    bundle.set_use_isolating(false);
+                    bundle
+                })
+                .add_resource_overriding(resource);
+        }
+
+        let mut localization = FluentLocalization {
+            language: String::new(),
+            fallback_languages: self.fallback_languages,
+            bundles,
+            chain: vec![],
+            missing: MissingMessages::new(),
+        };
+
+        localization.set_language(&self.language);
+
+        Ok(localization)
+    }
+}
+
+/// `FluentLocalization` is a localization service based on [Project Fluent](https://projectfluent.org)
+/// resources. It supports the variables, plurals, selectors and terms of Fluent.
+///
+/// Messages are looked up in the languages negotiated from the current language and the
+/// fallback languages, e.g. `de_AT` → `de_DE` → `en_US`. Attributes of messages are
+/// addressed with `message.attribute`. Messages that are missing in a language are collected and
+/// could be read with `missing_messages`.
+///
+/// `DateTime` arguments are always formatted as short date of the current language e.g.
+/// `1/31/2024`, the `DATETIME` function of Fluent and its `dateStyle` and `timeStyle` options are
+/// not supported. For other styles pass the date as string formatted with
+/// `Locale::format_date` or `Locale::format_time`.
+///
+/// Requires the `fluent` feature.
+///
+/// # Example
+///
+/// ```rust
+/// pub const EN_US: &str = include_str!("../assets/en_US.ftl");
+/// pub const DE_DE: &str = include_str!("../assets/de_DE.ftl");
+///
+/// let localization = FluentLocalization::create()
+///     .language("de_AT")
+///     .fallback_language("en_US")
+///     .resource("en_US", EN_US)
+///     .resource("de_DE", DE_DE)
+///     .build()?;
+///
+/// let text = localization.text_with_args("files".to_string(), &LocalizationArgs::new().arg("count", 3));
+/// ```
+pub struct FluentLocalization {
+    language: String,
+    fallback_languages: Vec<String>,
+    bundles: HashMap<String, FluentBundle<FluentResource>>,
+    chain: Vec<String>,
+    missing: MissingMessages,
+}
+
+impl FluentLocalization {
+    /// Creates a new `FluentLocalizationBuilder` to configure the localization service.
+    pub fn create() -> FluentLocalizationBuilder {
+        FluentLocalizationBuilder::default()
+    }
+
+    /// Returns the negotiated languages that are used to look up messages.
+    pub fn languages(&self) -> &[String] {
+        &self.chain
+    }
+
+    /// Returns the messages that were requested but are not translated in one of the negotiated
+    /// languages, sorted by language and key.
+    pub fn missing_messages(&self) -> Vec<MissingMessage> {
+        self.missing.messages()
+    }
+
+    /// Returns the collector of the missing messages e.g. to save them as RON file.
+    pub fn missing(&self) -> &MissingMessages {
+        &self.missing
+    }
+}
+
+impl Localization for FluentLocalization {
+    fn language(&self) -> &String {
+        &self.language
+    }
+
+    fn set_language(&mut self, key: &str) {
+        let mut available: Vec<String> = self.bundles.keys().cloned().collect();
+        available.sort();
+
+        self.language = key.to_string();
+        self.chain = negotiate_languages(key, &available, &self.fallback_languages);
+    }
+
+    fn text(&self, key: String) -> String {
+        self.text_with_args(key, &LocalizationArgs::default())
+    }
+
+    fn text_with_args(&self, key: String, args: &LocalizationArgs) -> String {
+        let mut fluent_args = FluentArgs::new();
+
+        for (name, value) in args.iter() {
+            match value {
+                LocalizationValue::Number(number) => fluent_args.set(name.as_str(), *number),
+                LocalizationValue::String(string) => {
+                    fluent_args.set(name.as_str(), string.as_str())
+                }
+                // fluent-bundle has no `DATETIME` function, dates are passed as short date
+                LocalizationValue::DateTime(date) => fluent_args.set(
+                    name.as_str(),
+                    self.locale().format_date(date, DateFormat::Short),
+                ),
+            }
+        }
+
+        let (id, attribute) = match key.split_once('.') {
+            Some((id, attribute)) => (id, Some(attribute)),
+            None => (key.as_str(), None),
+        };
+
+        if self.chain.is_empty() {
+            self.missing.report(&self.language, &key);
+        }
+
+        for language in &self.chain {
+            let bundle = &self.bundles[language];
+
+            let pattern = bundle.get_message(id).and_then(|message| match attribute {
+                Some(attribute) => message
+                    .get_attribute(attribute)
+                    .map(|attribute| attribute.value()),
+                None => message.value(),
+            });
+
+            match pattern {
+                Some(pattern) => {
+                    let mut errors = vec![];
+                    return bundle
+                        .format_pattern(pattern, Some(&fluent_args), &mut errors)
+                        .to_string();
+                }
+                None => self.missing.report(language, &key),
+            }
+        }
+
+        key
+    }
+}
+
+// converts a byte position of the source to line and column starting with 1
+fn position(source: &str, pos: usize) -> (usize, usize) {
+    let before = &source[..pos.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map_or(0, |line| line.chars().count())
+        + 1;
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EN_US: &str = r#"
+-brand = OrbTk
+hello = Hello from { -brand }!
+files = { $count ->
+    [one] { $count } file
+   *[other] { $count } files
+}
+save = Save
+    .tooltip = Saves the document
+"#;
+
+    const DE_DE: &str = r#"
+-brand = OrbTk
+hello = Hallo von { -brand }!
+files = { $count ->
+    [one] { $count } Datei
+   *[other] { $count } Dateien
+}
+"#;
+
+    #[test]
+    fn test_text() {
+        let localization = FluentLocalization::create()
+            .language("de_AT")
+            .fallback_language("en_US")
+            .resource("en_US", EN_US)
+            .resource("de_DE", DE_DE)
+            .build()
+            .unwrap();
+
+        assert_eq!(localization.languages(), &["de_DE", "en_US"]);
+        assert_eq!(localization.text("hello".to_string()), "Hallo von OrbTk!");
+        assert_eq!(
+            localization.text_with_args(
+                "files".to_string(),
+                &LocalizationArgs::new().arg("count", 1)
+            ),
+            "1 Datei"
+        );
+        assert_eq!(
+            localization.text_with_args(
+                "files".to_string(),
+                &LocalizationArgs::new().arg("count", 4)
+            ),
+            "4 Dateien"
+        );
+        assert_eq!(localization.text("save".to_string()), "Save");
+        assert_eq!(
+            localization.text("save.tooltip".to_string()),
+            "Saves the document"
+        );
+        assert_eq!(localization.text("test".to_string()), "test");
+
+        assert_eq!(
+            localization.missing_messages(),
+            vec![
+                MissingMessage {
+                    language: "de_DE".to_string(),
+                    key: "save".to_string()
+                },
+                MissingMessage {
+                    language: "de_DE".to_string(),
+                    key: "save.tooltip".to_string()
+                },
+                MissingMessage {
+                    language: "de_DE".to_string(),
+                    key: "test".to_string()
+                },
+                MissingMessage {
+                    language: "en_US".to_string(),
+                    key: "test".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = FluentLocalization::create()
+            .resource("en_US", "hello = Hello\n= broken")
+            .build()
+            .err()
+            .unwrap();
+
+        match error {
+            LocalizationError::Parse { language, line, .. } => {
+                assert_eq!(language, "en_US");
+                assert_eq!(line, 2);
+            }
+            _ => panic!("expected parse error"),
+        }
+    }
+}
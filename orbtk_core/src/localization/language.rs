@@ -0,0 +1,106 @@
+// normalizes a language key e.g. `de-at` to `de_AT`
+fn normalize(language: &str) -> String {
+    let mut parts = language.split(['_', '-']);
+    let mut key = parts.next().unwrap_or_default().to_lowercase();
+
+    for part in parts {
+        key.push('_');
+        key.push_str(&part.to_uppercase());
+    }
+
+    key
+}
+
+fn primary(language: &str) -> String {
+    normalize(language)
+        .split('_')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+// pushes the best matches of the requested language from the available languages: the exact
+// language, the language without region, the main region of the language e.g. `de_DE` and
+// the other regions in alphabetical order
+fn push_matches(requested: &str, available: &[String], chain: &mut Vec<String>) {
+    let requested_key = normalize(requested);
+    let primary_key = primary(requested);
+    let main_key = format!("{}_{}", primary_key, primary_key.to_uppercase());
+
+    let mut matches: Vec<(u8, String, &String)> = available
+        .iter()
+        .filter_map(|language| {
+            let key = normalize(language);
+
+            let rank = if key == requested_key {
+                0
+            } else if key == primary_key {
+                1
+            } else if key == main_key {
+                2
+            } else if primary(language) == primary_key {
+                3
+            } else {
+                return None;
+            };
+
+            Some((rank, key, language))
+        })
+        .collect();
+
+    matches.sort();
+
+    for (_, _, language) in matches {
+        if !chain.contains(language) {
+            chain.push(language.clone());
+        }
+    }
+}
+
+/// Negotiates the languages used to translate texts for the requested language. The chain starts
+/// with the available languages that match the requested language followed by the matches of
+/// the fallback languages, e.g. `de_AT` → `de_DE` → `en_US`. Language keys could be written with
+/// `_` or `-`.
+pub fn negotiate_languages(
+    requested: &str,
+    available: &[String],
+    fallbacks: &[String],
+) -> Vec<String> {
+    let mut chain = vec![];
+
+    push_matches(requested, available, &mut chain);
+
+    for fallback in fallbacks {
+        push_matches(fallback, available, &mut chain);
+    }
+
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_negotiate_languages() {
+        let available = strings(&["en_US", "de_CH", "de_DE", "fr"]);
+
+        assert_eq!(
+            negotiate_languages("de_AT", &available, &strings(&["en_US"])),
+            strings(&["de_DE", "de_CH", "en_US"])
+        );
+        assert_eq!(
+            negotiate_languages("de-ch", &available, &strings(&["en"])),
+            strings(&["de_CH", "de_DE", "en_US"])
+        );
+        assert_eq!(
+            negotiate_languages("fr_CA", &available, &[]),
+            strings(&["fr"])
+        );
+        assert!(negotiate_languages("ja", &available, &[]).is_empty());
+    }
+}
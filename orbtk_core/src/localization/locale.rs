@@ -0,0 +1,918 @@
+use serde_derive::Deserialize;
+
+use crate::{
+    localization::{DateFormat, DateTime},
+    utils::FlowDirection,
+};
+
+const MONTHS_EN: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const MONTHS_DE: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+const MONTHS_FR: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+const MONTHS_ES: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+const MONTHS_IT: [&str; 12] = [
+    "gennaio",
+    "febbraio",
+    "marzo",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "agosto",
+    "settembre",
+    "ottobre",
+    "novembre",
+    "dicembre",
+];
+
+const MONTHS_PT: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+
+const MONTHS_NL: [&str; 12] = [
+    "januari",
+    "februari",
+    "maart",
+    "april",
+    "mei",
+    "juni",
+    "juli",
+    "augustus",
+    "september",
+    "oktober",
+    "november",
+    "december",
+];
+
+const MONTHS_SV: [&str; 12] = [
+    "januari",
+    "februari",
+    "mars",
+    "april",
+    "maj",
+    "juni",
+    "juli",
+    "augusti",
+    "september",
+    "oktober",
+    "november",
+    "december",
+];
+
+// genitive month names, used after the day
+const MONTHS_RU: [&str; 12] = [
+    "января",
+    "февраля",
+    "марта",
+    "апреля",
+    "мая",
+    "июня",
+    "июля",
+    "августа",
+    "сентября",
+    "октября",
+    "ноября",
+    "декабря",
+];
+
+const MONTHS_PL: [&str; 12] = [
+    "stycznia",
+    "lutego",
+    "marca",
+    "kwietnia",
+    "maja",
+    "czerwca",
+    "lipca",
+    "sierpnia",
+    "września",
+    "października",
+    "listopada",
+    "grudnia",
+];
+
+const NBSP: char = '\u{a0}';
+const NARROW_NBSP: char = '\u{202f}';
+
+/// Conventions of a language to format and parse numbers, currencies and dates. Values that
+/// are `None` keep the built-in conventions of the `Locale`.
+///
+/// A ron dictionary provides the locale data of its language in the optional `locale` field:
+///
+/// ```ron
+/// Dictionary(
+///     locale: (
+///         decimal_separator: Some(','),
+///         group_separator: Some(' '),
+///         short_date: Some("dd.MM.y"),
+///     ),
+///     words: {
+///         "hello": "Tere",
+///     }
+/// )
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct LocaleData {
+    /// Separates the integer part from the fraction e.g. `.` in `1.5`.
+    pub decimal_separator: Option<char>,
+
+    /// Separates the groups of thousands e.g. `,` in `1,000`.
+    pub group_separator: Option<char>,
+
+    /// Written after a percentage e.g. `"\u{a0}%"`.
+    pub percent_suffix: Option<String>,
+
+    /// `true` if the currency symbol is written before the amount.
+    pub currency_prefix: Option<bool>,
+
+    /// `true` if the currency symbol is separated from the amount by a space.
+    pub currency_space: Option<bool>,
+
+    /// Pattern of short dates e.g. `"M/d/y"`. `y` is the year, `M` and `MM` the month, `MMMM`
+    /// the month name, `d` and `dd` the day and text in single quotes is written as it is.
+    pub short_date: Option<String>,
+
+    /// Pattern of long dates e.g. `"MMMM d, y"`.
+    pub long_date: Option<String>,
+
+    /// The twelve month names starting with january.
+    pub months: Option<Vec<String>>,
+
+    /// `true` if times are written with `AM` and `PM`.
+    pub twelve_hour: Option<bool>,
+}
+
+/// Formats and parses numbers, percentages, currencies, dates and times by the conventions of a
+/// language e.g. `en_US` or `de_DE`.
+///
+/// The locale of the current language is provided by `Localization::locale` and
+/// `Context::locale`. Languages without built-in conventions use the conventions of `en_US`.
+/// The built-in conventions are overridden by `LocaleData` e.g. from the `locale` field of a
+/// ron dictionary.
+///
+/// # Example
+///
+/// ```rust
+/// let locale = Locale::new("de_DE");
+///
+/// assert_eq!(locale.format_number(1234.5), "1.234,5");
+/// assert_eq!(locale.format_currency(1234.5, "EUR"), "1.234,50 €");
+/// assert_eq!(locale.format_date(&DateTime::date(2024, 1, 31), DateFormat::Long), "31. Januar 2024");
+/// assert_eq!(locale.parse_number("1.234,5"), Some(1234.5));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Locale {
+    language: String,
+    decimal_separator: char,
+    group_separator: char,
+    percent_suffix: String,
+    currency_prefix: bool,
+    currency_space: bool,
+    short_date: String,
+    long_date: String,
+    months: Vec<String>,
+    twelve_hour: bool,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::new("en_US")
+    }
+}
+
+impl Locale {
+    /// Creates the locale of the given language e.g. `en_US`, `de-CH` or `fr`.
+    pub fn new(language: impl Into<String>) -> Self {
+        let language = language.into();
+        let mut parts = language.split(['_', '-']);
+        let primary = parts.next().unwrap_or_default().to_lowercase();
+        let region = parts.next().unwrap_or_default().to_uppercase();
+
+        let mut locale = Locale {
+            language: language.clone(),
+            decimal_separator: ',',
+            group_separator: '.',
+            percent_suffix: "\u{a0}%".into(),
+            currency_prefix: false,
+            currency_space: true,
+            short_date: "dd.MM.y".into(),
+            long_date: "d MMMM y".into(),
+            months: month_names(&MONTHS_EN),
+            twelve_hour: false,
+        };
+
+        match primary.as_str() {
+            "de" => {
+                locale.long_date = "d. MMMM y".into();
+                locale.months = month_names(&MONTHS_DE);
+
+                if region == "CH" || region == "LI" {
+                    locale.decimal_separator = '.';
+                    locale.group_separator = '’';
+                    locale.percent_suffix = "%".into();
+                    locale.currency_prefix = true;
+                }
+            }
+            "fr" => {
+                locale.group_separator = NARROW_NBSP;
+                locale.percent_suffix = "\u{202f}%".into();
+                locale.short_date = "dd/MM/y".into();
+                locale.months = month_names(&MONTHS_FR);
+            }
+            "es" => {
+                locale.short_date = "d/M/y".into();
+                locale.long_date = "d 'de' MMMM 'de' y".into();
+                locale.months = month_names(&MONTHS_ES);
+            }
+            "it" => {
+                locale.percent_suffix = "%".into();
+                locale.short_date = "dd/MM/y".into();
+                locale.months = month_names(&MONTHS_IT);
+            }
+            "pt" => {
+                locale.percent_suffix = "%".into();
+                locale.short_date = "dd/MM/y".into();
+                locale.long_date = "d 'de' MMMM 'de' y".into();
+                locale.months = month_names(&MONTHS_PT);
+
+                if region == "PT" {
+                    locale.group_separator = NBSP;
+                } else {
+                    locale.currency_prefix = true;
+                }
+            }
+            "nl" => {
+                locale.percent_suffix = "%".into();
+                locale.currency_prefix = true;
+                locale.short_date = "dd-MM-y".into();
+                locale.months = month_names(&MONTHS_NL);
+            }
+            "sv" => {
+                locale.group_separator = NBSP;
+                locale.short_date = "y-MM-dd".into();
+                locale.months = month_names(&MONTHS_SV);
+            }
+            "ru" => {
+                locale.group_separator = NBSP;
+                locale.long_date = "d MMMM y 'г'.".into();
+                locale.months = month_names(&MONTHS_RU);
+            }
+            "pl" => {
+                locale.group_separator = NBSP;
+                locale.percent_suffix = "%".into();
+                locale.months = month_names(&MONTHS_PL);
+            }
+            "ja" | "zh" | "ko" => {
+                locale.decimal_separator = '.';
+                locale.group_separator = ',';
+                locale.percent_suffix = "%".into();
+                locale.currency_prefix = true;
+                locale.currency_space = false;
+
+                match primary.as_str() {
+                    "ja" => {
+                        locale.short_date = "y/MM/dd".into();
+                        locale.long_date = "y年M月d日".into();
+                    }
+                    "zh" => {
+                        locale.short_date = "y/M/d".into();
+                        locale.long_date = "y年M月d日".into();
+                    }
+                    _ => {
+                        locale.short_date = "y. M. d.".into();
+                        locale.long_date = "y년 M월 d일".into();
+                    }
+                }
+            }
+            _ => {
+                // english conventions
+                locale.decimal_separator = '.';
+                locale.group_separator = ',';
+                locale.percent_suffix = "%".into();
+                locale.currency_prefix = true;
+                locale.currency_space = false;
+
+                if primary == "en" && region != "US" && !region.is_empty() {
+                    locale.short_date = "dd/MM/y".into();
+                    locale.long_date = "d MMMM y".into();
+                    locale.twelve_hour = region != "GB" && region != "IE";
+                } else {
+                    locale.short_date = "M/d/y".into();
+                    locale.long_date = "MMMM d, y".into();
+                    locale.twelve_hour = true;
+                }
+            }
+        }
+
+        locale
+    }
+
+    /// Builder method that overrides the built-in conventions with the given locale data.
+    pub fn with_data(mut self, data: &LocaleData) -> Self {
+        if let Some(decimal_separator) = data.decimal_separator {
+            self.decimal_separator = decimal_separator;
+        }
+
+        if let Some(group_separator) = data.group_separator {
+            self.group_separator = group_separator;
+        }
+
+        if let Some(percent_suffix) = &data.percent_suffix {
+            self.percent_suffix = percent_suffix.clone();
+        }
+
+        if let Some(currency_prefix) = data.currency_prefix {
+            self.currency_prefix = currency_prefix;
+        }
+
+        if let Some(currency_space) = data.currency_space {
+            self.currency_space = currency_space;
+        }
+
+        if let Some(short_date) = &data.short_date {
+            self.short_date = short_date.clone();
+        }
+
+        if let Some(long_date) = &data.long_date {
+            self.long_date = long_date.clone();
+        }
+
+        // a list of month names must contain all months
+        if let Some(months) = data.months.as_ref().filter(|months| months.len() == 12) {
+            self.months = months.clone();
+        }
+
+        if let Some(twelve_hour) = data.twelve_hour {
+            self.twelve_hour = twelve_hour;
+        }
+
+        self
+    }
+
+    /// Gets the language of the locale.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Returns the flow direction of the script of the language e.g. `RightToLeft` for arabic
+    /// and hebrew.
+    pub fn flow_direction(&self) -> FlowDirection {
+        let primary = self.language.split(['_', '-']).next().unwrap_or_default();
+
+        match primary.to_lowercase().as_str() {
+            "ar" | "he" | "iw" | "fa" | "ur" | "yi" | "ps" | "sd" | "ug" | "dv" | "ckb" => {
+                FlowDirection::RightToLeft
+            }
+            _ => FlowDirection::LeftToRight,
+        }
+    }
+
+    /// Formats the number with up to three fraction digits e.g. `1,234.5`.
+    pub fn format_number(&self, value: f64) -> String {
+        let text = self.format_fixed(value, 3);
+
+        if !text.contains(self.decimal_separator) {
+            return text;
+        }
+
+        text.trim_end_matches('0')
+            .trim_end_matches(self.decimal_separator)
+            .to_string()
+    }
+
+    /// Formats the number with the given count of fraction digits e.g. `1,234.50`.
+    pub fn format_fixed(&self, value: f64, fraction_digits: usize) -> String {
+        let text = format!("{:.*}", fraction_digits, value.abs());
+        let mut parts = text.split('.');
+        let integer = parts.next().unwrap_or_default();
+        let fraction = parts.next();
+
+        let mut result = String::new();
+
+        // `-0` is written as `0`
+        if value < 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            result.push('-');
+        }
+
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                result.push(self.group_separator);
+            }
+            result.push(digit);
+        }
+
+        if let Some(fraction) = fraction {
+            result.push(self.decimal_separator);
+            result.push_str(fraction);
+        }
+
+        result
+    }
+
+    /// Formats the ratio as percentage e.g. `0.25` as `25%`.
+    pub fn format_percent(&self, value: f64) -> String {
+        format!(
+            "{}{}",
+            self.format_number(value * 100.0),
+            self.percent_suffix
+        )
+    }
+
+    /// Formats the amount with the symbol of the given ISO 4217 currency code e.g. `EUR` or
+    /// `USD`. Unknown currencies are written with their code.
+    pub fn format_currency(&self, value: f64, currency: &str) -> String {
+        let (symbol, fraction_digits) = currency_symbol(currency);
+
+        let amount = self.format_fixed(value.abs(), fraction_digits);
+        let sign = if value < 0.0 && amount.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            "-"
+        } else {
+            ""
+        };
+
+        // codes are always separated by a space
+        let space = if self.currency_space || symbol.len() == 3 && symbol == currency {
+            "\u{a0}"
+        } else {
+            ""
+        };
+
+        if self.currency_prefix {
+            format!("{}{}{}{}", sign, symbol, space, amount)
+        } else {
+            format!("{}{}{}{}", sign, amount, space, symbol)
+        }
+    }
+
+    /// Formats the date e.g. `1/31/2024` or `January 31, 2024`.
+    pub fn format_date(&self, date: &DateTime, format: DateFormat) -> String {
+        let pattern = self.date_pattern(format);
+
+        let month_name = &self.months[(date.month.clamp(1, 12) - 1) as usize];
+        let mut result = String::new();
+        let mut chars = pattern.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let mut count = 1;
+
+            while chars.peek() == Some(&c) && c.is_ascii_alphabetic() {
+                chars.next();
+                count += 1;
+            }
+
+            match (c, count) {
+                ('y', _) => result.push_str(&date.year.to_string()),
+                ('M', 1) => result.push_str(&date.month.to_string()),
+                ('M', 2) => result.push_str(&format!("{:02}", date.month)),
+                ('M', _) => result.push_str(month_name),
+                ('d', 1) => result.push_str(&date.day.to_string()),
+                ('d', _) => result.push_str(&format!("{:02}", date.day)),
+                // quoted literal text
+                ('\'', _) => {
+                    for c in chars.by_ref() {
+                        if c == '\'' {
+                            break;
+                        }
+                        result.push(c);
+                    }
+                }
+                _ => result.push(c),
+            }
+        }
+
+        result
+    }
+
+    /// Formats the time e.g. `3:05 PM` or `15:05:09`.
+    pub fn format_time(&self, time: &DateTime, format: DateFormat) -> String {
+        let mut result = if self.twelve_hour {
+            let hour = match time.hour % 12 {
+                0 => 12,
+                hour => hour,
+            };
+            format!("{}:{:02}", hour, time.minute)
+        } else {
+            format!("{:02}:{:02}", time.hour, time.minute)
+        };
+
+        if format == DateFormat::Long {
+            result.push_str(&format!(":{:02}", time.second));
+        }
+
+        if self.twelve_hour {
+            result.push_str(if time.hour < 12 { " AM" } else { " PM" });
+        }
+
+        result
+    }
+
+    /// Parses a number written with the separators of the locale e.g. `1.234,5` in german.
+    /// Returns `None` if the text is not a number.
+    pub fn parse_number(&self, text: &str) -> Option<f64> {
+        let mut number = String::new();
+
+        for c in text.trim().chars() {
+            match c {
+                c if c == self.decimal_separator => number.push('.'),
+                c if c == self.group_separator => {}
+                ' ' | NBSP | NARROW_NBSP => {}
+                '0'..='9' | '-' | '+' => number.push(c),
+                // a minus sign
+                '\u{2212}' => number.push('-'),
+                _ => return None,
+            }
+        }
+
+        number.parse().ok()
+    }
+
+    /// Parses a percentage e.g. `25 %` as ratio `0.25`.
+    pub fn parse_percent(&self, text: &str) -> Option<f64> {
+        let text = text.trim().trim_end_matches('%');
+        self.parse_number(text).map(|value| value / 100.0)
+    }
+
+    /// Parses an amount of the given ISO 4217 currency code, written with its symbol or its code
+    /// e.g. `-$1,234.50` or `1.234,50 €`.
+    pub fn parse_currency(&self, text: &str, currency: &str) -> Option<f64> {
+        let (symbol, _) = currency_symbol(currency);
+        let text = text.replace(currency, "").replace(symbol, "");
+
+        self.parse_number(&text)
+    }
+
+    /// Parses a date written in the given format e.g. `1/31/2024` or `January 31, 2024`. The
+    /// time of the result is midnight. Returns `None` if the text is not a valid date.
+    pub fn parse_date(&self, text: &str, format: DateFormat) -> Option<DateTime> {
+        let mut date = DateTime::default();
+        let mut rest = text.trim();
+        let mut chars = self.date_pattern(format).chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let mut count = 1;
+
+            while chars.peek() == Some(&c) && c.is_ascii_alphabetic() {
+                chars.next();
+                count += 1;
+            }
+
+            match (c, count) {
+                ('y', _) => date.year = take_number(&mut rest)? as i32,
+                ('M', 1) | ('M', 2) => date.month = take_number(&mut rest)?,
+                ('M', _) => {
+                    let index = self.months.iter().position(|month| {
+                        rest.get(..month.len())
+                            .map_or(false, |name| name.to_lowercase() == month.to_lowercase())
+                    })?;
+
+                    date.month = index as u32 + 1;
+                    rest = &rest[self.months[index].len()..];
+                }
+                ('d', _) => date.day = take_number(&mut rest)?,
+                // quoted literal text
+                ('\'', _) => {
+                    for c in chars.by_ref() {
+                        if c == '\'' {
+                            break;
+                        }
+                        rest = rest.strip_prefix(c).map(str::trim_start)?;
+                    }
+                }
+                (c, _) if c.is_whitespace() => rest = rest.trim_start(),
+                _ => rest = rest.strip_prefix(c)?,
+            }
+        }
+
+        if !rest.trim().is_empty()
+            || !(1..=12).contains(&date.month)
+            || !(1..=31).contains(&date.day)
+        {
+            return None;
+        }
+
+        Some(date)
+    }
+
+    /// Parses a time with or without seconds e.g. `3:05 PM` or `15:05:09`. The date of the
+    /// result is zero. Returns `None` if the text is not a valid time.
+    pub fn parse_time(&self, text: &str) -> Option<DateTime> {
+        let text = text.trim().to_uppercase();
+        let (text, pm) = match (text.strip_suffix("AM"), text.strip_suffix("PM")) {
+            (Some(text), _) => (text, Some(false)),
+            (_, Some(text)) => (text, Some(true)),
+            _ => (text.as_str(), None),
+        };
+
+        let mut parts = text
+            .trim()
+            .split(':')
+            .map(|part| part.trim().parse::<u32>());
+        let mut hour = parts.next()?.ok()?;
+        let minute = parts.next()?.ok()?;
+        let second = parts.next().unwrap_or(Ok(0)).ok()?;
+
+        if parts.next().is_some() || minute > 59 || second > 59 {
+            return None;
+        }
+
+        if let Some(pm) = pm {
+            if hour == 0 || hour > 12 {
+                return None;
+            }
+
+            hour = hour % 12 + if pm { 12 } else { 0 };
+        }
+
+        if hour > 23 {
+            return None;
+        }
+
+        Some(DateTime::default().time(hour, minute, second))
+    }
+
+    // Gets the date pattern of the given format.
+    fn date_pattern(&self, format: DateFormat) -> &str {
+        match format {
+            DateFormat::Short => &self.short_date,
+            DateFormat::Long => &self.long_date,
+        }
+    }
+}
+
+// Gets the month names as owned strings.
+fn month_names(months: &[&str; 12]) -> Vec<String> {
+    months.iter().map(|month| month.to_string()).collect()
+}
+
+// Gets the symbol and the count of fraction digits of the given currency code.
+fn currency_symbol(currency: &str) -> (&str, usize) {
+    match currency {
+        "EUR" => ("€", 2),
+        "USD" => ("$", 2),
+        "GBP" => ("£", 2),
+        "JPY" => ("¥", 0),
+        "CNY" => ("¥", 2),
+        "KRW" => ("₩", 0),
+        "INR" => ("₹", 2),
+        "BRL" => ("R$", 2),
+        "RUB" => ("₽", 2),
+        "PLN" => ("zł", 2),
+        "SEK" => ("kr", 2),
+        _ => (currency, 2),
+    }
+}
+
+// Takes the leading digits of the text as number.
+fn take_number(text: &mut &str) -> Option<u32> {
+    let len = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    let number = text[..len].parse().ok()?;
+    *text = &text[len..];
+
+    Some(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number() {
+        let en = Locale::new("en_US");
+        let de = Locale::new("de_DE");
+        let fr = Locale::new("fr-FR");
+
+        assert_eq!(en.format_number(1234567.891), "1,234,567.891");
+        assert_eq!(en.format_number(-0.5), "-0.5");
+        assert_eq!(en.format_number(12.0), "12");
+        assert_eq!(de.format_number(1234.5), "1.234,5");
+        assert_eq!(fr.format_number(1234.5), "1\u{202f}234,5");
+        assert_eq!(Locale::new("de_CH").format_fixed(1234.5, 2), "1’234.50");
+        assert_eq!(en.format_fixed(-0.001, 2), "0.00");
+
+        assert_eq!(en.format_percent(0.25), "25%");
+        assert_eq!(de.format_percent(0.125), "12,5\u{a0}%");
+    }
+
+    #[test]
+    fn test_format_currency() {
+        assert_eq!(
+            Locale::new("en_US").format_currency(-1234.5, "USD"),
+            "-$1,234.50"
+        );
+        assert_eq!(
+            Locale::new("de_DE").format_currency(1234.5, "EUR"),
+            "1.234,50\u{a0}€"
+        );
+        assert_eq!(
+            Locale::new("ja_JP").format_currency(1234.5, "JPY"),
+            "¥1,234"
+        );
+        assert_eq!(
+            Locale::new("en_US").format_currency(3.0, "CHF"),
+            "CHF\u{a0}3.00"
+        );
+    }
+
+    #[test]
+    fn test_format_date() {
+        let date = DateTime::date(2024, 1, 5).time(15, 5, 9);
+
+        let en = Locale::new("en_US");
+        assert_eq!(en.format_date(&date, DateFormat::Short), "1/5/2024");
+        assert_eq!(en.format_date(&date, DateFormat::Long), "January 5, 2024");
+        assert_eq!(en.format_time(&date, DateFormat::Short), "3:05 PM");
+
+        let de = Locale::new("de_DE");
+        assert_eq!(de.format_date(&date, DateFormat::Short), "05.01.2024");
+        assert_eq!(de.format_date(&date, DateFormat::Long), "5. Januar 2024");
+        assert_eq!(de.format_time(&date, DateFormat::Long), "15:05:09");
+
+        assert_eq!(
+            Locale::new("es").format_date(&date, DateFormat::Long),
+            "5 de enero de 2024"
+        );
+        assert_eq!(
+            Locale::new("ja").format_date(&date, DateFormat::Long),
+            "2024年1月5日"
+        );
+        assert_eq!(
+            Locale::new("en_GB").format_date(&date, DateFormat::Short),
+            "05/01/2024"
+        );
+    }
+
+    #[test]
+    fn test_with_data() {
+        let data = LocaleData {
+            decimal_separator: Some(','),
+            group_separator: Some(' '),
+            short_date: Some("d.M.y".to_string()),
+            months: Some(vec!["x".to_string()]),
+            ..Default::default()
+        };
+        let locale = Locale::new("et").with_data(&data);
+        let date = DateTime::date(2024, 1, 5);
+
+        assert_eq!(locale.format_number(1234.5), "1 234,5");
+        assert_eq!(locale.parse_number("1 234,5"), Some(1234.5));
+        assert_eq!(locale.format_date(&date, DateFormat::Short), "5.1.2024");
+        assert_eq!(
+            locale.format_date(&date, DateFormat::Long),
+            "January 5, 2024"
+        );
+    }
+
+    #[test]
+    fn test_flow_direction() {
+        assert_eq!(
+            Locale::new("ar_EG").flow_direction(),
+            FlowDirection::RightToLeft
+        );
+        assert_eq!(
+            Locale::new("he").flow_direction(),
+            FlowDirection::RightToLeft
+        );
+        assert_eq!(
+            Locale::new("en_US").flow_direction(),
+            FlowDirection::LeftToRight
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Locale::new("de_DE").parse_number("1.234,5"), Some(1234.5));
+        assert_eq!(Locale::new("en_US").parse_number("-1,234.5"), Some(-1234.5));
+        assert_eq!(Locale::new("fr").parse_number("1 234,5"), Some(1234.5));
+        assert_eq!(Locale::new("en_US").parse_number("12a"), None);
+        assert_eq!(Locale::new("de_DE").parse_percent("12,5 %"), Some(0.125));
+    }
+
+    #[test]
+    fn test_parse_currency() {
+        let en = Locale::new("en_US");
+        let de = Locale::new("de_DE");
+
+        assert_eq!(en.parse_currency("-$1,234.50", "USD"), Some(-1234.5));
+        assert_eq!(en.parse_currency("CHF\u{a0}3.00", "CHF"), Some(3.0));
+        assert_eq!(de.parse_currency("1.234,50\u{a0}€", "EUR"), Some(1234.5));
+        assert_eq!(de.parse_currency("1.234,50 EUR", "EUR"), Some(1234.5));
+        assert_eq!(en.parse_currency("$1,234.50", "EUR"), None);
+
+        let value = -1234.5;
+        assert_eq!(
+            de.parse_currency(&de.format_currency(value, "EUR"), "EUR"),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let date = DateTime::date(2024, 1, 5);
+
+        for language in &["en_US", "en_GB", "de_DE", "es", "ja", "ko", "ru", "fr"] {
+            let locale = Locale::new(*language);
+
+            for format in &[DateFormat::Short, DateFormat::Long] {
+                let text = locale.format_date(&date, *format);
+                assert_eq!(locale.parse_date(&text, *format), Some(date), "{}", text);
+            }
+        }
+
+        let en = Locale::new("en_US");
+        assert_eq!(
+            en.parse_date("january 31, 2024", DateFormat::Long),
+            Some(DateTime::date(2024, 1, 31))
+        );
+        assert_eq!(en.parse_date("13/1/2024", DateFormat::Short), None);
+        assert_eq!(en.parse_date("1/5/2024 x", DateFormat::Short), None);
+    }
+
+    #[test]
+    fn test_parse_time() {
+        let en = Locale::new("en_US");
+        let de = Locale::new("de_DE");
+
+        assert_eq!(
+            en.parse_time("3:05 PM"),
+            Some(DateTime::default().time(15, 5, 0))
+        );
+        assert_eq!(
+            en.parse_time("12:30:09 am"),
+            Some(DateTime::default().time(0, 30, 9))
+        );
+        assert_eq!(
+            de.parse_time("15:05:09"),
+            Some(DateTime::default().time(15, 5, 9))
+        );
+        assert_eq!(de.parse_time("24:00"), None);
+        assert_eq!(en.parse_time("13:00 PM"), None);
+    }
+}
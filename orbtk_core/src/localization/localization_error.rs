@@ -0,0 +1,37 @@
+use std::{fmt, path::PathBuf};
+
+/// Describes why the resources of a localization could not be loaded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocalizationError {
+    /// The file could not be read.
+    Io { path: PathBuf, message: String },
+
+    /// The resource of the language could not be parsed. Line and column start with 1.
+    Parse {
+        language: String,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for LocalizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalizationError::Io { path, message } => write!(
+                f,
+                "{}: could not read localization: {}",
+                path.display(),
+                message
+            ),
+            LocalizationError::Parse {
+                language,
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{}:{}: {}", language, line, column, message),
+        }
+    }
+}
+
+impl std::error::Error for LocalizationError {}
@@ -0,0 +1,135 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::Path,
+};
+
+use ron::ser::{PrettyConfig, Serializer};
+use serde_derive::Serialize;
+
+/// A message that is not translated in a language. If the message is translated in one of the
+/// fallback languages, the text of the fallback language is shown.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MissingMessage {
+    /// The language without translation.
+    pub language: String,
+
+    /// The key of the message.
+    pub key: String,
+}
+
+// dictionary of the missing words of a language, written like the dictionaries of `RonLocalization`
+#[derive(Serialize)]
+#[serde(rename = "Dictionary")]
+struct MissingDictionary {
+    words: BTreeMap<String, String>,
+}
+
+/// Collects the messages that are requested at runtime but are not translated.
+///
+/// The collected messages could be saved as RON file with a dictionary with empty words for each
+/// language, that could be used as to-do list for translators:
+///
+/// ```ron
+/// {
+///     "de_DE": Dictionary(
+///         words: {
+///             "save": "",
+///         },
+///     ),
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MissingMessages {
+    messages: RefCell<BTreeSet<MissingMessage>>,
+}
+
+impl MissingMessages {
+    /// Creates a new empty collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the message with the given key as missing in the given language.
+    pub fn report(&self, language: &str, key: &str) {
+        self.messages.borrow_mut().insert(MissingMessage {
+            language: language.to_string(),
+            key: key.to_string(),
+        });
+    }
+
+    /// Returns the collected messages sorted by language and key.
+    pub fn messages(&self) -> Vec<MissingMessage> {
+        self.messages.borrow().iter().cloned().collect()
+    }
+
+    /// Returns `true` if no message is missing.
+    pub fn is_empty(&self) -> bool {
+        self.messages.borrow().is_empty()
+    }
+
+    /// Clears the collected messages.
+    pub fn clear(&self) {
+        self.messages.borrow_mut().clear();
+    }
+
+    /// Returns the collected messages as RON map of dictionaries by language.
+    pub fn to_ron(&self) -> String {
+        let mut dictionaries: BTreeMap<String, MissingDictionary> = BTreeMap::new();
+
+        for message in self.messages.borrow().iter() {
+            dictionaries
+                .entry(message.language.clone())
+                .or_insert_with(|| MissingDictionary {
+                    words: BTreeMap::new(),
+                })
+                .words
+                .insert(message.key.clone(), String::new());
+        }
+
+        // struct names are written to load the dictionaries with `RonLocalization`
+        let mut ron = vec![];
+
+        if let Ok(mut serializer) = Serializer::new(&mut ron, Some(PrettyConfig::new()), true) {
+            let _ = serde::Serialize::serialize(&dictionaries, &mut serializer);
+        }
+
+        String::from_utf8(ron).unwrap_or_default()
+    }
+
+    /// Writes the collected messages as RON file to the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_ron())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ron() {
+        let missing = MissingMessages::new();
+        missing.report("de_DE", "save");
+        missing.report("de_DE", "open");
+        missing.report("de_DE", "save");
+        missing.report("fr_FR", "save");
+
+        assert_eq!(missing.messages().len(), 3);
+
+        #[derive(serde_derive::Deserialize)]
+        struct Dictionary {
+            words: BTreeMap<String, String>,
+        }
+
+        let dictionaries: BTreeMap<String, Dictionary> =
+            ron::de::from_str(&missing.to_ron()).unwrap();
+
+        assert_eq!(dictionaries["de_DE"].words.len(), 2);
+        assert_eq!(dictionaries["fr_FR"].words["save"], "");
+
+        missing.clear();
+        assert!(missing.is_empty());
+    }
+}
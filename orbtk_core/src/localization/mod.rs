@@ -1,9 +1,27 @@
 //! This module contains the localization methods, that handle runtime based adaption of involved OrbTk entities.
 
-mod ron_localization;
-
+pub use self::args::*;
+pub use self::date_time::*;
+#[cfg(feature = "fluent")]
+pub use self::fluent_localization::*;
+pub use self::language::*;
+pub use self::locale::*;
+pub use self::localization_error::*;
+pub use self::missing_messages::*;
+pub use self::plural::*;
 pub use self::ron_localization::*;
 
+mod args;
+mod date_time;
+#[cfg(feature = "fluent")]
+mod fluent_localization;
+mod language;
+mod locale;
+mod localization_error;
+mod missing_messages;
+mod plural;
+mod ron_localization;
+
 pub trait Localization {
     /// Gets the current language by language key e.g. `en_US`,
     /// `de_DE` or `fr_FR`.
@@ -15,4 +33,16 @@ pub trait Localization {
     /// Gets the translated text for the given key. If there is no
     /// given translation, the `key` will be returned as the result.
     fn text(&self, key: String) -> String;
+
+    /// Gets the translated text for the given key and replaces its placeholders `{name}` with
+    /// the given arguments. Implementations that support plural and select expressions choose
+    /// the text by the values of the arguments.
+    fn text_with_args(&self, key: String, args: &LocalizationArgs) -> String {
+        args.format(&self.text(key), &self.locale())
+    }
+
+    /// Gets the locale of the current language, that formats numbers, currencies and dates.
+    fn locale(&self) -> Locale {
+        Locale::new(self.language().as_str())
+    }
 }
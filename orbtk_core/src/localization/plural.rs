@@ -0,0 +1,160 @@
+/// Describes the CLDR plural category of a number e.g. `one` for `1 file` and `other` for
+/// `2 files` in english.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Gets the cardinal plural category of the given number in the given language e.g. `en_US`,
+    /// `de-DE` or `ru`. Languages without known rules use the english rules.
+    pub fn from_number(language: &str, number: f64) -> Self {
+        let language = language
+            .split(['_', '-'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let n = number.abs();
+        // integer digits and count of visible fraction digits
+        let i = n.trunc() as u64;
+        let v = n
+            .to_string()
+            .split('.')
+            .nth(1)
+            .map_or(0, |fraction| fraction.len());
+
+        match language.as_str() {
+            "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" | "lo" | "my" => PluralCategory::Other,
+            "fr" | "pt" => {
+                if i <= 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            "ru" | "uk" | "be" => {
+                if v != 0 {
+                    PluralCategory::Other
+                } else if i % 10 == 1 && i % 100 != 11 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            "pl" => {
+                if v != 0 {
+                    PluralCategory::Other
+                } else if i == 1 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            "cs" | "sk" => {
+                if v != 0 {
+                    PluralCategory::Many
+                } else if i == 1 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&i) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            "ar" => {
+                if v != 0 {
+                    PluralCategory::Other
+                } else if i == 0 {
+                    PluralCategory::Zero
+                } else if i == 1 {
+                    PluralCategory::One
+                } else if i == 2 {
+                    PluralCategory::Two
+                } else if (3..=10).contains(&(i % 100)) {
+                    PluralCategory::Few
+                } else if (11..=99).contains(&(i % 100)) {
+                    PluralCategory::Many
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            "he" => {
+                if v == 0 && i == 1 {
+                    PluralCategory::One
+                } else if v == 0 && i == 2 {
+                    PluralCategory::Two
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            _ => {
+                if v == 0 && i == 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_number() {
+        assert_eq!(
+            PluralCategory::from_number("en_US", 1.0),
+            PluralCategory::One
+        );
+        assert_eq!(
+            PluralCategory::from_number("en_US", 0.0),
+            PluralCategory::Other
+        );
+        assert_eq!(
+            PluralCategory::from_number("en_US", 1.5),
+            PluralCategory::Other
+        );
+        assert_eq!(
+            PluralCategory::from_number("fr-FR", 0.0),
+            PluralCategory::One
+        );
+        assert_eq!(PluralCategory::from_number("ru", 21.0), PluralCategory::One);
+        assert_eq!(PluralCategory::from_number("ru", 3.0), PluralCategory::Few);
+        assert_eq!(
+            PluralCategory::from_number("ru", 12.0),
+            PluralCategory::Many
+        );
+        assert_eq!(PluralCategory::from_number("pl", 22.0), PluralCategory::Few);
+        assert_eq!(
+            PluralCategory::from_number("pl", 21.0),
+            PluralCategory::Many
+        );
+        assert_eq!(PluralCategory::from_number("cs", 4.0), PluralCategory::Few);
+        assert_eq!(PluralCategory::from_number("ar", 0.0), PluralCategory::Zero);
+        assert_eq!(PluralCategory::from_number("ar", 2.0), PluralCategory::Two);
+        assert_eq!(
+            PluralCategory::from_number("ar", 105.0),
+            PluralCategory::Few
+        );
+        assert_eq!(
+            PluralCategory::from_number("ar", 111.0),
+            PluralCategory::Many
+        );
+        assert_eq!(
+            PluralCategory::from_number("ja", 1.0),
+            PluralCategory::Other
+        );
+    }
+}
@@ -3,14 +3,117 @@ use std::collections::HashMap;
 use ron::de::from_str;
 use serde_derive::Deserialize;
 
+use crate::localization::{
+    Locale, LocaleData, LocalizationArgs, LocalizationError, LocalizationValue, PluralCategory,
+};
+
 /// Internal struct used by the `RonLocalization` to parse as language file.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Dictionary {
-    pub words: HashMap<String, String>,
+    #[serde(default)]
+    pub locale: LocaleData,
+    pub words: HashMap<String, Word>,
+}
+
+impl Dictionary {
+    /// Parses the dictionary of the given language. On error the line and column of the error
+    /// are returned.
+    pub fn parse(language: &str, s: &str) -> Result<Self, LocalizationError> {
+        from_str(s).map_err(|e| LocalizationError::Parse {
+            language: language.to_string(),
+            line: e.position.line,
+            column: e.position.col,
+            message: e.code.to_string(),
+        })
+    }
+}
+
+/// A translation of a dictionary. Either a text or a plural or select expression, that chooses
+/// the text by the value of an argument.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Word {
+    Text(String),
+    Plural(Plural),
+    Select(Select),
+}
+
+/// Chooses the text by the plural category of a number argument e.g.
+/// `Plural(arg: "count", one: "{count} file", other: "{count} files")`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Plural {
+    pub arg: String,
+    #[serde(default)]
+    pub exact: HashMap<i64, Word>,
+    #[serde(default)]
+    pub zero: Option<Box<Word>>,
+    #[serde(default)]
+    pub one: Option<Box<Word>>,
+    #[serde(default)]
+    pub two: Option<Box<Word>>,
+    #[serde(default)]
+    pub few: Option<Box<Word>>,
+    #[serde(default)]
+    pub many: Option<Box<Word>>,
+    pub other: Box<Word>,
+}
+
+/// Chooses the text by the value of a text argument e.g.
+/// `Select(arg: "gender", cases: { "female": "her", "male": "his" }, other: "their")`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Select {
+    pub arg: String,
+    pub cases: HashMap<String, Word>,
+    pub other: Box<Word>,
+}
+
+impl Word {
+    /// Resolves plural and select expressions and replaces the placeholders of the text.
+    pub fn text(&self, locale: &Locale, args: &LocalizationArgs) -> String {
+        match self {
+            Word::Text(text) => args.format(text, locale),
+            Word::Plural(plural) => plural.word(locale.language(), args).text(locale, args),
+            Word::Select(select) => select.word(args).text(locale, args),
+        }
+    }
+}
+
+impl Plural {
+    fn word(&self, language: &str, args: &LocalizationArgs) -> &Word {
+        let number = match args.get(&self.arg) {
+            Some(LocalizationValue::Number(number)) => *number,
+            Some(LocalizationValue::String(string)) => match string.parse::<f64>() {
+                Ok(number) => number,
+                Err(_) => return &self.other,
+            },
+            _ => return &self.other,
+        };
+
+        if number.fract() == 0.0 {
+            if let Some(word) = self.exact.get(&(number as i64)) {
+                return word;
+            }
+        }
+
+        let word = match PluralCategory::from_number(language, number) {
+            PluralCategory::Zero => &self.zero,
+            PluralCategory::One => &self.one,
+            PluralCategory::Two => &self.two,
+            PluralCategory::Few => &self.few,
+            PluralCategory::Many => &self.many,
+            PluralCategory::Other => return &self.other,
+        };
+
+        word.as_deref().unwrap_or(&self.other)
+    }
 }
 
-impl From<&str> for Dictionary {
-    fn from(s: &str) -> Self {
-        from_str(s).unwrap()
+impl Select {
+    fn word(&self, args: &LocalizationArgs) -> &Word {
+        args.get(&self.arg)
+            .and_then(|value| self.cases.get(&value.to_string()))
+            .unwrap_or(&self.other)
     }
 }
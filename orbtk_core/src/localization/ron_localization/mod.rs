@@ -1,24 +1,57 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fs, path::PathBuf};
 
-use crate::localization::Localization;
+use crate::localization::{
+    negotiate_languages, Locale, Localization, LocalizationArgs, LocalizationError, MissingMessage,
+    MissingMessages,
+};
 
 use dictionary::Dictionary;
 
 mod dictionary;
 
+#[derive(Debug, Clone)]
+enum Source {
+    Text(String),
+    File(PathBuf),
+}
+
+impl Source {
+    fn load(&self, language: &str) -> Result<Dictionary, LocalizationError> {
+        match self {
+            Source::Text(text) => Dictionary::parse(language, text),
+            Source::File(path) => {
+                let text = fs::read_to_string(path).map_err(|e| LocalizationError::Io {
+                    path: path.clone(),
+                    message: e.to_string(),
+                })?;
+
+                Dictionary::parse(language, &text)
+            }
+        }
+    }
+}
+
 /// Used to build a new `RonLocalization` and configure language file
-/// path and initial language.
+/// path, initial language and fallback languages.
 #[derive(Debug, Default, Clone)]
 pub struct RonLocalizationBuilder {
     language: String,
-    dictionaries: HashMap<String, Dictionary>,
+    fallback_languages: Vec<String>,
+    dictionaries: Vec<(String, Source)>,
 }
 
 impl RonLocalizationBuilder {
     /// Adds a new dictionary.
     pub fn dictionary(mut self, key: impl Into<String>, dictionary: &str) -> Self {
         self.dictionaries
-            .insert(key.into(), Dictionary::from(dictionary));
+            .push((key.into(), Source::Text(dictionary.to_string())));
+        self
+    }
+
+    /// Adds a dictionary file. The file is read on build.
+    pub fn file(mut self, key: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        self.dictionaries
+            .push((key.into(), Source::File(path.into())));
         self
     }
 
@@ -28,32 +61,94 @@ impl RonLocalizationBuilder {
         self
     }
 
-    /// Builds a new ron localization service.
+    /// Adds a fallback language. Words that are not translated in the current language are
+    /// looked up in the fallback languages in the order they are added.
+    pub fn fallback_language(mut self, language: impl Into<String>) -> Self {
+        self.fallback_languages.push(language.into());
+        self
+    }
+
+    /// Builds a new ron localization service. Returns an error with line and column if a
+    /// dictionary could not be parsed or an error if a file could not be read.
+    pub fn try_build(self) -> Result<RonLocalization, LocalizationError> {
+        let mut dictionaries = HashMap::new();
+
+        for (language, source) in &self.dictionaries {
+            dictionaries.insert(language.clone(), source.load(language)?);
+        }
+
+        Ok(self.finish(dictionaries))
+    }
+
+    /// Builds a new ron localization service. Dictionaries that could not be loaded are skipped,
+    /// their errors could be read with `RonLocalization::errors`. Use `try_build` to fail on the
+    /// first error.
     pub fn build(self) -> RonLocalization {
-        RonLocalization {
-            language: self.language,
-            dictionaries: self.dictionaries,
+        let mut dictionaries = HashMap::new();
+        let mut errors = vec![];
+
+        for (language, source) in &self.dictionaries {
+            match source.load(language) {
+                Ok(dictionary) => {
+                    dictionaries.insert(language.clone(), dictionary);
+                }
+                Err(error) => errors.push(error),
+            }
         }
+
+        let mut localization = self.finish(dictionaries);
+        localization.errors = errors;
+        localization
+    }
+
+    fn finish(self, dictionaries: HashMap<String, Dictionary>) -> RonLocalization {
+        let mut localization = RonLocalization {
+            language: String::new(),
+            fallback_languages: self.fallback_languages,
+            dictionaries,
+            chain: vec![],
+            missing: MissingMessages::new(),
+            errors: vec![],
+        };
+
+        localization.set_language(&self.language);
+        localization
     }
 }
 
 /// `RonLocalization` represents the default implementation of a
 /// localization service based on `ron`.
 ///
+/// Words are looked up in the languages negotiated from the current language and the fallback
+/// languages, e.g. `de_AT` → `de_DE` → `en_US`. Words that are missing in a language are
+/// collected and could be saved as RON file with `missing().save(path)`.
+///
 /// # Example
 ///
 /// ```rust
 /// pub const EN_US: &str = include_str!("../assets/dictionary_en_US.ron");
+/// pub const DE_DE: &str = include_str!("../assets/dictionary_de_DE.ron");
+///
+/// let localization = RonLocalization::create()
+///     .language("de_AT")
+///     .fallback_language("en_US")
+///     .dictionary("en_US", EN_US)
+///     .dictionary("de_DE", DE_DE)
+///     .build();
+///
+/// println!("{}", localization.text("hello".to_string()));
 ///
-/// let localization = RonLocalization::create().language("en_US").dictionary("en_US", EN_US).build();
-/// if let Some(text) = localization.text("hello") {
-///     println!("{}", text);
-/// }
+/// // to-do list for the translators
+/// localization.missing().save("missing_words.ron")?;
 /// ```
 #[derive(Debug, Default, Clone)]
 pub struct RonLocalization {
     language: String,
+    fallback_languages: Vec<String>,
     dictionaries: HashMap<String, Dictionary>,
+    chain: Vec<String>,
+    missing: MissingMessages,
+    errors: Vec<LocalizationError>,
 }
 
 impl RonLocalization {
@@ -61,6 +156,38 @@ impl RonLocalization {
     pub fn create() -> RonLocalizationBuilder {
         RonLocalizationBuilder::default()
     }
+
+    /// Returns the negotiated languages that are used to look up words.
+    pub fn languages(&self) -> &[String] {
+        &self.chain
+    }
+
+    /// Returns the words that were requested but are not translated in one of the negotiated
+    /// languages, sorted by language and key.
+    pub fn missing_messages(&self) -> Vec<MissingMessage> {
+        self.missing.messages()
+    }
+
+    /// Returns the errors of the dictionaries that were skipped by `RonLocalizationBuilder::build`
+    /// because they could not be read or parsed.
+    pub fn errors(&self) -> &[LocalizationError] {
+        &self.errors
+    }
+
+    /// Returns the collector of the missing words e.g. to save them as RON file.
+    pub fn missing(&self) -> &MissingMessages {
+        &self.missing
+    }
+
+    // Gets the locale of the given language with the locale data of its dictionary.
+    fn locale_of(&self, language: &str, dictionary: &str) -> Locale {
+        let locale = Locale::new(language);
+
+        match self.dictionaries.get(dictionary) {
+            Some(dictionary) => locale.with_data(&dictionary.locale),
+            None => locale,
+        }
+    }
 }
 
 impl Localization for RonLocalization {
@@ -69,17 +196,50 @@ impl Localization for RonLocalization {
     }
 
     fn set_language(&mut self, key: &str) {
+        let mut available: Vec<String> = self.dictionaries.keys().cloned().collect();
+        available.sort();
+
         self.language = key.to_string();
+        self.chain = negotiate_languages(key, &available, &self.fallback_languages);
     }
 
     fn text(&self, key: String) -> String {
-        if let Some(dictionary) = self.dictionaries.get(&self.language) {
-            if let Some(word) = dictionary.words.get(&key) {
-                return word.clone();
+        self.text_with_args(key, &LocalizationArgs::default())
+    }
+
+    fn text_with_args(&self, key: String, args: &LocalizationArgs) -> String {
+        if self.chain.is_empty() {
+            self.missing.report(&self.language, &key);
+        }
+
+        for language in &self.chain {
+            match self.dictionaries[language].words.get(&key) {
+                Some(word) => return word.text(&self.locale_of(language, language), args),
+                None => self.missing.report(language, &key),
             }
         }
 
-        key
+        args.format(&key, &self.locale())
+    }
+
+    fn locale(&self) -> Locale {
+        let primary = |language: &str| {
+            language
+                .split(['_', '-'])
+                .next()
+                .unwrap_or_default()
+                .to_lowercase()
+        };
+
+        // the best matching dictionary of the same language provides the locale data
+        match self
+            .chain
+            .iter()
+            .find(|language| primary(language) == primary(&self.language))
+        {
+            Some(dictionary) => self.locale_of(&self.language, dictionary),
+            None => Locale::new(self.language.as_str()),
+        }
     }
 }
 
@@ -107,4 +267,258 @@ mod tests {
         assert_eq!(localization.text("world".to_string()), "Welt".to_string());
         assert_eq!(localization.text("test".to_string()), "test".to_string());
     }
+
+    #[test]
+    fn test_text_with_args() {
+        let en_us = r#"
+        Dictionary(
+            words: {
+                "welcome": "Welcome back, {name}!",
+                "files": Plural(
+                    arg: "count",
+                    exact: { 0: "No files" },
+                    one: "{count} file",
+                    other: "{count} files",
+                ),
+                "shared": Select(
+                    arg: "gender",
+                    cases: {
+                        "female": Plural(arg: "count", one: "She shared a file", other: "She shared {count} files"),
+                        "male": "He shared files",
+                    },
+                    other: "They shared files",
+                ),
+            }
+        )
+        "#;
+
+        let ru = r#"
+        Dictionary(
+            words: {
+                "files": Plural(
+                    arg: "count",
+                    one: "{count} файл",
+                    few: "{count} файла",
+                    many: "{count} файлов",
+                    other: "{count} файла",
+                ),
+            }
+        )
+        "#;
+
+        let mut localization = RonLocalization::create()
+            .language("en_US")
+            .dictionary("en_US", en_us)
+            .dictionary("ru_RU", ru)
+            .build();
+
+        let text = |localization: &RonLocalization, key: &str, args: LocalizationArgs| {
+            localization.text_with_args(key.to_string(), &args)
+        };
+
+        assert_eq!(
+            text(
+                &localization,
+                "welcome",
+                LocalizationArgs::new().arg("name", "Anna")
+            ),
+            "Welcome back, Anna!"
+        );
+        assert_eq!(
+            text(
+                &localization,
+                "files",
+                LocalizationArgs::new().arg("count", 0)
+            ),
+            "No files"
+        );
+        assert_eq!(
+            text(
+                &localization,
+                "files",
+                LocalizationArgs::new().arg("count", 1)
+            ),
+            "1 file"
+        );
+        assert_eq!(
+            text(
+                &localization,
+                "files",
+                LocalizationArgs::new().arg("count", 5)
+            ),
+            "5 files"
+        );
+        assert_eq!(localization.text("files".to_string()), "{count} files");
+        assert_eq!(
+            text(
+                &localization,
+                "shared",
+                LocalizationArgs::new()
+                    .arg("gender", "female")
+                    .arg("count", 3)
+            ),
+            "She shared 3 files"
+        );
+        assert_eq!(
+            text(
+                &localization,
+                "shared",
+                LocalizationArgs::new().arg("gender", "male")
+            ),
+            "He shared files"
+        );
+        assert_eq!(
+            text(&localization, "shared", LocalizationArgs::new()),
+            "They shared files"
+        );
+
+        localization.set_language("ru_RU");
+
+        assert_eq!(
+            text(
+                &localization,
+                "files",
+                LocalizationArgs::new().arg("count", 22)
+            ),
+            "22 файла"
+        );
+        assert_eq!(
+            text(
+                &localization,
+                "files",
+                LocalizationArgs::new().arg("count", 11)
+            ),
+            "11 файлов"
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        let en_us = r#"
+        Dictionary(
+            words: {
+                "hello": "Hello",
+                "save": "Save",
+            }
+        )
+        "#;
+
+        let de_de = r#"
+        Dictionary(
+            words: {
+                "hello": "Hallo",
+            }
+        )
+        "#;
+
+        let localization = RonLocalization::create()
+            .language("de_AT")
+            .fallback_language("en_US")
+            .dictionary("en_US", en_us)
+            .dictionary("de_DE", de_de)
+            .build();
+
+        assert_eq!(localization.languages(), &["de_DE", "en_US"]);
+        assert_eq!(localization.text("hello".to_string()), "Hallo");
+        assert_eq!(localization.text("save".to_string()), "Save");
+        assert_eq!(localization.text("open".to_string()), "open");
+
+        assert_eq!(
+            localization.missing_messages(),
+            vec![
+                MissingMessage {
+                    language: "de_DE".to_string(),
+                    key: "open".to_string()
+                },
+                MissingMessage {
+                    language: "de_DE".to_string(),
+                    key: "save".to_string()
+                },
+                MissingMessage {
+                    language: "en_US".to_string(),
+                    key: "open".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_build() {
+        let error = RonLocalization::create()
+            .dictionary(
+                "de_DE",
+                "Dictionary(\n    words: {\n        \"hello\" \"Hallo\",\n",
+            )
+            .try_build()
+            .unwrap_err();
+
+        match error {
+            LocalizationError::Parse { language, line, .. } => {
+                assert_eq!(language, "de_DE");
+                assert_eq!(line, 3);
+            }
+            _ => panic!("expected parse error"),
+        }
+
+        let error = RonLocalization::create()
+            .file("de_DE", "does/not/exist.ron")
+            .try_build()
+            .unwrap_err();
+
+        assert!(matches!(error, LocalizationError::Io { .. }));
+
+        // invalid dictionaries are skipped, their errors are kept
+        let localization = RonLocalization::create()
+            .language("de_DE")
+            .dictionary("de_DE", "Dictionary(")
+            .dictionary("en_US", "Dictionary(words: {})")
+            .file("fr_FR", "does/not/exist.ron")
+            .build();
+
+        assert_eq!(localization.text("hello".to_string()), "hello");
+        assert_eq!(localization.errors().len(), 2);
+        assert!(matches!(
+            &localization.errors()[0],
+            LocalizationError::Parse { language, .. } if language == "de_DE"
+        ));
+        assert!(matches!(
+            localization.errors()[1],
+            LocalizationError::Io { .. }
+        ));
+    }
+
+    #[test]
+    fn test_locale_data() {
+        let et = r#"
+        Dictionary(
+            locale: (
+                decimal_separator: Some(','),
+                group_separator: Some(' '),
+            ),
+            words: {
+                "price": "Hind: {price}",
+            }
+        )
+        "#;
+
+        let mut localization = RonLocalization::create()
+            .language("et_EE")
+            .fallback_language("en_US")
+            .dictionary("et", et)
+            .dictionary("en_US", "Dictionary(words: {})")
+            .build();
+
+        assert_eq!(localization.locale().format_number(1234.5), "1 234,5");
+        assert_eq!(
+            localization.text_with_args(
+                "price".to_string(),
+                &LocalizationArgs::new().arg("price", 1234.5)
+            ),
+            "Hind: 1 234,5"
+        );
+
+        // the locale data of the fallback language is not used for other languages
+        localization.set_language("de_DE");
+        assert_eq!(localization.locale(), Locale::new("de_DE"));
+    }
 }
@@ -149,6 +149,12 @@ macro_rules! widget {
 		self.set_property("enabled", enabled)
 	    }
 
+	    /// Sets or shares the flow direction property. If not set, the flow direction of the
+	    /// parent is used. The window defaults to the flow direction of the current language.
+	    pub fn flow_direction(self, flow_direction: impl IntoPropertySource<FlowDirection>) -> Self {
+		self.set_property("flow_direction", flow_direction)
+	    }
+
 	    /// Inserts a new height.
 	    pub fn height(mut self, height: impl Into<f64>) -> Self {
 		if !self.height.is_none() {
@@ -480,8 +486,8 @@ macro_rules! widget {
 		    )*
 		)*
 
-		if let Some(id) = this.id {
-		    ctx.register_property("id", entity, id);
+		if let Some(id) = &this.id {
+		    ctx.register_property("id", entity, id.clone());
 		}
 
 		let mut selector = if let Some(style) = this.style {
@@ -489,6 +495,7 @@ macro_rules! widget {
 		} else {
 		    this.selector
 		};
+		selector.id = this.id;
 
 		// initial set disabled
 		if ctx.get_widget(entity).has::<bool>("enabled") && !*ctx.get_widget(entity).get::<bool>("enabled") {
@@ -6,7 +6,7 @@ use dces::prelude::{Component, ComponentStore, Entity};
 
 pub use self::layout::*;
 pub use self::widget::*;
-use crate::{into_property_source, render, theming, utils};
+use crate::{into_property_source, localization, render, theming, utils};
 
 mod layout;
 mod widget;
@@ -87,9 +87,23 @@ into_property_source!(PathBuf);
 
 // Implementation of PropertySource for utils types
 into_property_source!(utils::Alignment: &str);
+into_property_source!(
+    utils::BorderBrushes: (utils::Brush, utils::Brush, utils::Brush, utils::Brush),
+    (&str, &str, &str, &str),
+    utils::Value
+);
+into_property_source!(utils::BoxShadow: &str, String, utils::Shadow, utils::Value);
 into_property_source!(utils::Brush: &str, utils::Color, utils::Value);
 into_property_source!(utils::Constraint: utils::ConstraintBuilder);
+into_property_source!(
+    utils::CornerRadius: i32,
+    f64,
+    (i32, i32, i32, i32),
+    (f64, f64, f64, f64),
+    utils::Value
+);
 into_property_source!(utils::Filter: &str, String, Vec<String>, Vec<&str>);
+into_property_source!(utils::FlowDirection: &str);
 into_property_source!(utils::Orientation: &str);
 into_property_source!(utils::Point: f64, i32, (i32, i32), (f64, f64));
 into_property_source!(utils::SelectionMode: &str);
@@ -115,6 +129,9 @@ into_property_source!(Vec<String>);
 into_property_source!(theming::Selector: &str, String);
 into_property_source!(theming::Theme);
 
+// Implementation of localization property types
+into_property_source!(localization::LocalizationArgs);
+
 // Implementation of render property types
 into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
 
@@ -123,7 +140,10 @@ into_property_source!(Blocks: BlocksBuilder, &str, String);
 into_property_source!(DefaultRenderPipeline);
 into_property_source!(FocusState);
 into_property_source!(KeyboardState);
+into_property_source!(RichText: &str, String, Vec<TextSpan>);
 into_property_source!(ScrollViewerMode: (&str, &str));
 into_property_source!(SelectedEntities: HashSet<Entity>);
 into_property_source!(SelectedIndices: HashSet<usize>);
 into_property_source!(TextSelection: (usize, usize));
+into_property_source!(TextTrimming: &str);
+into_property_source!(TextWrap: &str);
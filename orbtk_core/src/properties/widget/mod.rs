@@ -2,13 +2,17 @@
 pub use self::focus_state::*;
 pub use self::keyboard_state::*;
 pub use self::render_pipeline::*;
+pub use self::rich_text::*;
 pub use self::selected_entities::*;
 pub use self::selected_indices::*;
 pub use self::text_selection::*;
+pub use self::text_wrap::*;
 
 mod focus_state;
 mod keyboard_state;
 mod render_pipeline;
+mod rich_text;
 mod selected_entities;
 mod selected_indices;
 mod text_selection;
+mod text_wrap;
@@ -0,0 +1,619 @@
+use std::slice::Iter;
+
+use crate::utils::Brush;
+
+// Escaped characters of the markup, ordered as `<`, `>`, `&`.
+static ENTITIES: [&str; 3] = ["&lt;", "&gt;", "&amp;"];
+
+// Escaped characters of html and their replacement.
+static HTML_ENTITIES: [(&str, char); 6] = [
+    ("&lt;", '<'),
+    ("&gt;", '>'),
+    ("&amp;", '&'),
+    ("&quot;", '"'),
+    ("&#39;", '\''),
+    ("&nbsp;", ' '),
+];
+
+/// Describes the weight (thickness) of a font.
+///
+/// Font families are registered by name with the weight as suffix,
+/// e.g. `Roboto-Regular` or `Roboto-Medium`. A font weight selects
+/// the registered family with the matching suffix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FontWeight {
+    Thin,
+    Light,
+    Regular,
+    Medium,
+    SemiBold,
+    Bold,
+    Black,
+}
+
+impl FontWeight {
+    /// Gets the name of the weight, how it is used as suffix of a font family.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FontWeight::Thin => "Thin",
+            FontWeight::Light => "Light",
+            FontWeight::Regular => "Regular",
+            FontWeight::Medium => "Medium",
+            FontWeight::SemiBold => "SemiBold",
+            FontWeight::Bold => "Bold",
+            FontWeight::Black => "Black",
+        }
+    }
+
+    /// Replaces the weight suffix of the given font family, e.g.
+    /// `Roboto-Regular` with `FontWeight::Medium` results in `Roboto-Medium`.
+    pub fn apply(&self, family: &str) -> String {
+        let name = match family.rfind('-') {
+            Some(index) => &family[..index],
+            None => family,
+        };
+
+        format!("{}-{}", name, self.name())
+    }
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        FontWeight::Regular
+    }
+}
+
+impl From<&str> for FontWeight {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "thin" | "100" | "200" => FontWeight::Thin,
+            "light" | "300" => FontWeight::Light,
+            "medium" | "500" => FontWeight::Medium,
+            "semibold" | "600" => FontWeight::SemiBold,
+            "bold" | "700" => FontWeight::Bold,
+            "black" | "800" | "900" => FontWeight::Black,
+            _ => FontWeight::Regular,
+        }
+    }
+}
+
+/// Used to build a text span, specifying additional details.
+#[derive(Default)]
+pub struct TextSpanBuilder {
+    span: TextSpan,
+}
+
+impl TextSpanBuilder {
+    /// Creates a new `TextSpanBuilder` with default values.
+    pub fn new() -> Self {
+        TextSpanBuilder::default()
+    }
+
+    /// Inserts the text.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.span.text = text.into();
+        self
+    }
+
+    /// Inserts the font family.
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.span.font = Some(font.into());
+        self
+    }
+
+    /// Inserts the font weight.
+    pub fn font_weight(mut self, font_weight: impl Into<FontWeight>) -> Self {
+        self.span.font_weight = Some(font_weight.into());
+        self
+    }
+
+    /// Inserts the font size.
+    pub fn font_size(mut self, font_size: f64) -> Self {
+        self.span.font_size = Some(font_size);
+        self
+    }
+
+    /// Inserts the foreground brush.
+    pub fn foreground(mut self, foreground: impl Into<Brush>) -> Self {
+        self.span.foreground = Some(foreground.into());
+        self
+    }
+
+    /// Inserts the background (highlight) brush.
+    pub fn background(mut self, background: impl Into<Brush>) -> Self {
+        self.span.background = Some(background.into());
+        self
+    }
+
+    /// Inserts the underline flag.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.span.underline = underline;
+        self
+    }
+
+    /// Inserts the strikethrough flag.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.span.strikethrough = strikethrough;
+        self
+    }
+
+    /// Inserts the link target.
+    pub fn link(mut self, href: impl Into<String>) -> Self {
+        self.span.link = Some(href.into());
+        self
+    }
+
+    /// Builds the text span.
+    pub fn build(self) -> TextSpan {
+        self.span
+    }
+}
+
+/// Describes a piece of text with its own style inside of a `RichText`.
+///
+/// Style values that are `None` are taken from the widget that draws the span.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextSpan {
+    pub text: String,
+    pub font: Option<String>,
+    pub font_weight: Option<FontWeight>,
+    pub font_size: Option<f64>,
+    pub foreground: Option<Brush>,
+    pub background: Option<Brush>,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub link: Option<String>,
+}
+
+impl TextSpan {
+    /// Creates a new `TextSpanBuilder` object with default values.
+    #[inline]
+    pub fn create() -> TextSpanBuilder {
+        TextSpanBuilder::new()
+    }
+
+    /// Creates a copy of the span style with the given text.
+    fn with_text(&self, text: String) -> Self {
+        TextSpan {
+            text,
+            ..self.clone()
+        }
+    }
+}
+
+impl From<&str> for TextSpan {
+    fn from(s: &str) -> Self {
+        TextSpan::create().text(s).build()
+    }
+}
+
+impl From<String> for TextSpan {
+    fn from(s: String) -> Self {
+        TextSpan::create().text(s).build()
+    }
+}
+
+/// Rich text is a list of text spans, each with its own style.
+///
+/// A rich text can be build from code or parsed from a simple markup:
+///
+/// | Tag | Description |
+/// |-----|-------------|
+/// | `<b>..</b>` | Bold text |
+/// | `<weight=medium>..</weight>` | Text with the given font weight |
+/// | `<u>..</u>` | Underlined text |
+/// | `<s>..</s>` | Strikethrough text |
+/// | `<font=Roboto-Medium>..</font>` | Text with the given font family |
+/// | `<size=16>..</size>` | Text with the given font size |
+/// | `<color=#ff0000>..</color>` | Text with the given foreground |
+/// | `<bg=#ffff00>..</bg>` | Text with the given background highlight |
+/// | `<a href="https://redox-os.org">..</a>` | Link that raises a `LinkClickedEvent` |
+///
+/// Tags can be nested. `&lt;`, `&gt;` and `&amp;` are used to escape
+/// `<`, `>` and `&`. Unknown tags are drawn as text.
+///
+/// # Examples
+///
+/// ```rust
+/// use orbtk_core::prelude::*;
+///
+/// let text = RichText::from("Hello <b>bold</b> <a href=\"https://redox-os.org\">link</a>");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RichText {
+    spans: Vec<TextSpan>,
+}
+
+impl RichText {
+    /// Creates a new empty rich text.
+    pub fn new() -> Self {
+        RichText::default()
+    }
+
+    /// Parses the given markup to a rich text.
+    pub fn from_markup(markup: &str) -> Self {
+        let mut rich_text = RichText::new();
+
+        // stack of opened tags with the span style that is valid inside of the tag
+        let mut styles: Vec<(String, TextSpan)> = vec![(String::default(), TextSpan::default())];
+        let mut text = String::new();
+        let mut rest = markup;
+
+        while !rest.is_empty() {
+            let next = rest
+                .find(|c: char| c == '<' || c == '&')
+                .unwrap_or(rest.len());
+            text.push_str(&rest[..next]);
+            rest = &rest[next..];
+
+            if rest.starts_with('&') {
+                let entity = ENTITIES.iter().position(|entity| rest.starts_with(entity));
+
+                text.push(match entity {
+                    Some(0) => '<',
+                    Some(1) => '>',
+                    _ => '&',
+                });
+
+                let len = entity.map_or(1, |entity| ENTITIES[entity].len());
+                rest = &rest[len..];
+                continue;
+            }
+
+            if rest.is_empty() {
+                break;
+            }
+
+            // rest starts with '<'
+            let end = match rest.find('>') {
+                Some(end) => end,
+                None => {
+                    text.push_str(rest);
+                    break;
+                }
+            };
+
+            let tag = &rest[1..end];
+            let current = styles.last().unwrap().1.clone();
+
+            let applied = if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim();
+                match styles.iter().rposition(|(open, _)| open == name) {
+                    Some(index) if index > 0 => {
+                        rich_text.push_text(&current, &mut text);
+                        styles.truncate(index);
+                        true
+                    }
+                    _ => false,
+                }
+            } else {
+                let (name, value) = split_tag(tag);
+                match apply_tag(&current, name, value) {
+                    Some(style) => {
+                        rich_text.push_text(&current, &mut text);
+                        styles.push((name.to_string(), style));
+                        true
+                    }
+                    None => false,
+                }
+            };
+
+            if !applied {
+                text.push_str(&rest[..=end]);
+            }
+
+            rest = &rest[end + 1..];
+        }
+
+        let current = styles.last().unwrap().1.clone();
+        rich_text.push_text(&current, &mut text);
+
+        rich_text
+    }
+
+    /// Converts the given html e.g. pasted from the clipboard to a rich text.
+    ///
+    /// Bold (`<b>`, `<strong>`), underlined (`<u>`, `<ins>`), strikethrough (`<s>`, `<strike>`,
+    /// `<del>`) text and links (`<a href="..">`) keep their style. Line breaks and the ends of
+    /// paragraphs, divs, list items and headings start a new line. All other tags are dropped,
+    /// the content of `<style>` and `<script>` is skipped.
+    pub fn from_html(html: &str) -> Self {
+        let mut rich_text = RichText::new();
+
+        // stack of opened tags with the span style that is valid inside of the tag
+        let mut styles: Vec<(String, TextSpan)> = vec![(String::default(), TextSpan::default())];
+        let mut text = String::new();
+        let mut rest = html;
+
+        while !rest.is_empty() {
+            let next = rest
+                .find(|c: char| c == '<' || c == '&')
+                .unwrap_or(rest.len());
+            rich_text.push_html_text(&mut text, &rest[..next]);
+            rest = &rest[next..];
+
+            if rest.starts_with('&') {
+                let entity = HTML_ENTITIES
+                    .iter()
+                    .find(|(entity, _)| rest.starts_with(entity));
+
+                text.push(entity.map_or('&', |(_, c)| *c));
+                rest = &rest[entity.map_or(1, |(entity, _)| entity.len())..];
+                continue;
+            }
+
+            if rest.is_empty() {
+                break;
+            }
+
+            // rest starts with '<'
+            let end = match rest.find('>') {
+                Some(end) => end,
+                None => break,
+            };
+
+            let tag = rest[1..end].trim_end_matches('/');
+            rest = &rest[end + 1..];
+            let current = styles.last().unwrap().1.clone();
+
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim().to_ascii_lowercase();
+
+                if is_html_block(&name) {
+                    let len = text.trim_end_matches(' ').len();
+                    text.truncate(len);
+                    text.push('\n');
+                }
+
+                let name = html_tag(&name);
+                if let Some(index) = styles.iter().rposition(|(open, _)| open == name) {
+                    if index > 0 {
+                        rich_text.push_text(&current, &mut text);
+                        styles.truncate(index);
+                    }
+                }
+
+                continue;
+            }
+
+            let (name, value) = split_tag(tag);
+            let name = name.to_ascii_lowercase();
+
+            match name.as_str() {
+                "br" => text.push('\n'),
+                "style" | "script" => {
+                    let close = format!("</{}", name);
+                    rest = rest
+                        .to_ascii_lowercase()
+                        .find(&close)
+                        .map_or("", |index| &rest[index..]);
+                }
+                _ => {
+                    let name = html_tag(&name);
+                    if let Some(style) = apply_tag(&current, name, value.filter(|_| name == "a")) {
+                        rich_text.push_text(&current, &mut text);
+                        styles.push((name.to_string(), style));
+                    }
+                }
+            }
+        }
+
+        let current = styles.last().unwrap().1.clone();
+        let len = text.trim_end().len();
+        text.truncate(len);
+        rich_text.push_text(&current, &mut text);
+
+        rich_text
+    }
+
+    /// Adds a span to the end of the rich text.
+    pub fn push(&mut self, span: impl Into<TextSpan>) {
+        self.spans.push(span.into());
+    }
+
+    /// Gets the spans of the rich text.
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// Gets an iterator over the spans.
+    pub fn iter(&self) -> Iter<TextSpan> {
+        self.spans.iter()
+    }
+
+    /// Gets the number of spans.
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns `true` if the rich text contains no text.
+    pub fn is_empty(&self) -> bool {
+        self.spans.iter().all(|span| span.text.is_empty())
+    }
+
+    /// Gets the text of all spans without styling.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    // Adds html text to the given text, white space is collapsed to a single space.
+    fn push_html_text(&self, text: &mut String, html: &str) {
+        let mut last = text
+            .chars()
+            .last()
+            .or_else(|| self.spans.last().and_then(|span| span.text.chars().last()));
+
+        for c in html.chars() {
+            if !c.is_whitespace() {
+                text.push(c);
+                last = Some(c);
+            } else if last.map_or(false, |last| !last.is_whitespace()) {
+                text.push(' ');
+                last = Some(' ');
+            }
+        }
+    }
+
+    // Moves the given text with the given style as new span to the rich text.
+    fn push_text(&mut self, style: &TextSpan, text: &mut String) {
+        if text.is_empty() {
+            return;
+        }
+
+        self.spans.push(style.with_text(text.clone()));
+        text.clear();
+    }
+}
+
+// Splits a tag like `color=#ff0000` or `a href="url"` in name and value.
+fn split_tag(tag: &str) -> (&str, Option<&str>) {
+    let tag = tag.trim();
+
+    match tag.find(|c: char| c == '=' || c == ' ') {
+        Some(index) => {
+            let value = tag[index + 1..].trim();
+            let value = value.strip_prefix("href=").unwrap_or(value);
+            (
+                &tag[..index],
+                Some(value.trim_matches(|c: char| c == '"' || c == '\'')),
+            )
+        }
+        None => (tag, None),
+    }
+}
+
+// Returns the style inside of the given tag or `None` if the tag is not supported.
+fn apply_tag(style: &TextSpan, name: &str, value: Option<&str>) -> Option<TextSpan> {
+    let mut style = style.clone();
+
+    match (name, value) {
+        ("b", None) => style.font_weight = Some(FontWeight::Bold),
+        ("u", None) => style.underline = true,
+        ("s", None) => style.strikethrough = true,
+        ("weight", Some(value)) => style.font_weight = Some(FontWeight::from(value)),
+        ("font", Some(value)) => style.font = Some(value.to_string()),
+        ("size", Some(value)) => style.font_size = Some(value.parse().ok()?),
+        ("color", Some(value)) => style.foreground = Some(Brush::from(value)),
+        ("bg", Some(value)) => style.background = Some(Brush::from(value)),
+        ("a", Some(value)) => style.link = Some(value.to_string()),
+        _ => return None,
+    }
+
+    Some(style)
+}
+
+// Maps a html tag to the markup tag with the same style.
+fn html_tag(name: &str) -> &str {
+    match name {
+        "strong" => "b",
+        "ins" => "u",
+        "strike" | "del" => "s",
+        _ => name,
+    }
+}
+
+// Returns `true` if the content after the end of the given html tag starts on a new line.
+fn is_html_block(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "div" | "li" | "tr" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6"
+    )
+}
+
+impl From<&str> for RichText {
+    fn from(s: &str) -> Self {
+        RichText::from_markup(s)
+    }
+}
+
+impl From<String> for RichText {
+    fn from(s: String) -> Self {
+        RichText::from_markup(&s)
+    }
+}
+
+impl From<Vec<TextSpan>> for RichText {
+    fn from(spans: Vec<TextSpan>) -> Self {
+        RichText { spans }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_weight_apply() {
+        assert_eq!(FontWeight::Medium.apply("Roboto-Regular"), "Roboto-Medium");
+        assert_eq!(FontWeight::Bold.apply("Roboto"), "Roboto-Bold");
+    }
+
+    #[test]
+    fn test_plain_markup() {
+        let rich_text = RichText::from("Hello world");
+        assert_eq!(rich_text.len(), 1);
+        assert_eq!(rich_text.spans()[0], TextSpan::from("Hello world"));
+    }
+
+    #[test]
+    fn test_nested_markup() {
+        let rich_text = RichText::from("a<b>b<color=#ff0000>c</color></b><u>d</u>");
+
+        assert_eq!(rich_text.len(), 4);
+        assert_eq!(rich_text.text(), "abcd");
+        assert_eq!(rich_text.spans()[1].font_weight, Some(FontWeight::Bold));
+        assert_eq!(rich_text.spans()[1].foreground, None);
+        assert_eq!(rich_text.spans()[2].font_weight, Some(FontWeight::Bold));
+        assert_eq!(
+            rich_text.spans()[2].foreground,
+            Some(Brush::from("#ff0000"))
+        );
+        assert!(rich_text.spans()[3].underline);
+        assert_eq!(rich_text.spans()[3].font_weight, None);
+    }
+
+    #[test]
+    fn test_link_markup() {
+        let rich_text = RichText::from("see <a href=\"https://redox-os.org\">redox</a>!");
+
+        assert_eq!(rich_text.len(), 3);
+        assert_eq!(
+            rich_text.spans()[1].link,
+            Some("https://redox-os.org".to_string())
+        );
+        assert_eq!(rich_text.spans()[2].link, None);
+    }
+
+    #[test]
+    fn test_unknown_and_escaped_markup() {
+        let rich_text = RichText::from("<x>1 &lt; 2</y> &amp; <size=big>");
+
+        assert_eq!(rich_text.len(), 1);
+        assert_eq!(rich_text.text(), "<x>1 < 2</y> & <size=big>");
+    }
+
+    #[test]
+    fn test_from_html() {
+        let rich_text = RichText::from_html(
+            "<html><style>p { color: red; }</style><body>\n<p>Hello  <strong>bold</strong> \
+             &amp; <a href=\"https://redox-os.org\">link</a></p><p><span>next</span><br/>line</p>\
+             </body></html>",
+        );
+
+        assert_eq!(rich_text.text(), "Hello bold & link\nnext\nline");
+        assert_eq!(rich_text.len(), 5);
+        assert_eq!(rich_text.spans()[1].font_weight, Some(FontWeight::Bold));
+        assert_eq!(
+            rich_text.spans()[3].link,
+            Some("https://redox-os.org".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unclosed_markup() {
+        let rich_text = RichText::from("<s>strike");
+
+        assert_eq!(rich_text.len(), 1);
+        assert!(rich_text.spans()[0].strikethrough);
+    }
+}
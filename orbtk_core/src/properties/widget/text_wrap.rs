@@ -0,0 +1,90 @@
+/// Describes how a text is broken into lines, if it is wider than the available space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextWrap {
+    /// Rich text is broken between words, plain text only on line breaks. This is the default.
+    Auto,
+
+    /// The text is only broken on line breaks.
+    None,
+
+    /// The text is broken between words. Words that are wider than
+    /// a line are broken between characters.
+    Word,
+
+    /// The text is broken between any characters.
+    Character,
+}
+
+impl Default for TextWrap {
+    fn default() -> Self {
+        TextWrap::Auto
+    }
+}
+
+impl From<&str> for TextWrap {
+    fn from(s: &str) -> TextWrap {
+        match s {
+            "Auto" | "auto" => TextWrap::Auto,
+            "Word" | "word" => TextWrap::Word,
+            "Character" | "character" => TextWrap::Character,
+            _ => TextWrap::None,
+        }
+    }
+}
+
+/// Describes how a line of text is trimmed, if it does not fit into
+/// the available space or if it is the last visible line of a text with
+/// limited lines.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextTrimming {
+    /// The text is not trimmed.
+    None,
+
+    /// The text is trimmed after the last character that fits and
+    /// an ellipsis is appended.
+    CharacterEllipsis,
+
+    /// The text is trimmed after the last word that fits and an
+    /// ellipsis is appended.
+    WordEllipsis,
+}
+
+impl Default for TextTrimming {
+    fn default() -> Self {
+        TextTrimming::None
+    }
+}
+
+impl From<&str> for TextTrimming {
+    fn from(s: &str) -> TextTrimming {
+        match s {
+            "CharacterEllipsis" | "character_ellipsis" | "character-ellipsis" => {
+                TextTrimming::CharacterEllipsis
+            }
+            "WordEllipsis" | "word_ellipsis" | "word-ellipsis" => TextTrimming::WordEllipsis,
+            _ => TextTrimming::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(TextWrap::from("auto"), TextWrap::Auto);
+        assert_eq!(TextWrap::from("word"), TextWrap::Word);
+        assert_eq!(TextWrap::from("Character"), TextWrap::Character);
+        assert_eq!(TextWrap::from("unknown"), TextWrap::None);
+        assert_eq!(
+            TextTrimming::from("character-ellipsis"),
+            TextTrimming::CharacterEllipsis
+        );
+        assert_eq!(
+            TextTrimming::from("WordEllipsis"),
+            TextTrimming::WordEllipsis
+        );
+        assert_eq!(TextTrimming::from("none"), TextTrimming::None);
+    }
+}
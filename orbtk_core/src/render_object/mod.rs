@@ -16,6 +16,7 @@ pub use self::image::*;
 pub use self::pipeline::*;
 pub use self::rectangle::*;
 pub use self::text::*;
+pub use self::text_layout::*;
 
 mod cursor;
 mod default;
@@ -24,6 +25,7 @@ mod image;
 mod pipeline;
 mod rectangle;
 mod text;
+mod text_layout;
 
 /// The `RenderObject` trait defines rendering of 2D objects.
 ///
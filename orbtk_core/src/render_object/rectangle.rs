@@ -5,7 +5,7 @@ use crate::{
     render::RenderContext2D,
     render_object::*,
     utils,
-    utils::{Brush, Point, Rectangle, Thickness},
+    utils::{BorderBrushes, BoxShadow, Brush, CornerRadius, Point, Rectangle, Thickness},
 };
 
 /// Structure that defines a rectangle for a render object.
@@ -131,26 +131,89 @@ impl RectangleRenderObject {
     }
 }
 
+impl RectangleRenderObject {
+    // Renders rectangle with different corner radii, border widths or border brushes.
+    fn render_border_box(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        rect: Rectangle,
+        radius: CornerRadius,
+        brush: Brush,
+        border_brushes: [&Brush; 4],
+        border_thickness: Thickness,
+    ) {
+        if !brush.is_transparent() {
+            render_context_2_d.begin_path();
+            render_context_2_d.round_rect(rect.x(), rect.y(), rect.width(), rect.height(), radius);
+            render_context_2_d.set_fill_style(brush);
+            render_context_2_d.fill();
+        }
+
+        render_context_2_d.draw_border(rect, radius, border_thickness, border_brushes);
+    }
+
+    // Inset shadows are drawn above the shape, the first shadow on top.
+    fn render_inset_shadows(
+        &self,
+        ctx: &mut Context,
+        rect: Rectangle,
+        radius: CornerRadius,
+        box_shadow: &BoxShadow,
+    ) {
+        for shadow in box_shadow.shadows().iter().rev().filter(|s| s.inset) {
+            ctx.render_context_2_d().draw_box_shadow(
+                rect.x(),
+                rect.y(),
+                rect.width(),
+                rect.height(),
+                radius,
+                shadow,
+            );
+        }
+    }
+}
+
 impl RenderObject for RectangleRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, background, border_radius, border_thickness, border_brush) = {
+        let (bounds, background, corner_radius, border_thickness, border_brush, box_shadow) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
                 widget.get::<Brush>("background").clone(),
-                widget.clone_or_default::<f64>("border_radius"),
+                widget.clone_or_default::<CornerRadius>("border_radius"),
                 widget.clone_or_default::<Thickness>("border_width"),
                 widget.clone_or_default::<Brush>("border_brush"),
+                widget.clone_or_default::<BoxShadow>("box_shadow"),
             )
         };
+        let border_brushes = ctx
+            .widget()
+            .clone_or_default::<BorderBrushes>("border_brushes");
+
+        let shadow_bounds = Rectangle::new(*global_position + bounds.position(), bounds.size());
+
+        // outer shadows are drawn below the shape, the first shadow on top
+        for shadow in box_shadow.shadows().iter().rev().filter(|s| !s.inset) {
+            ctx.render_context_2_d().draw_box_shadow(
+                shadow_bounds.x(),
+                shadow_bounds.y(),
+                shadow_bounds.width(),
+                shadow_bounds.height(),
+                corner_radius,
+                shadow,
+            );
+        }
 
         if (bounds.width() == 0.0
             || bounds.height() == 0.0
-            || (background.is_transparent() && border_brush.is_transparent()))
+            || (background.is_transparent()
+                && border_brush.is_transparent()
+                && border_brushes.is_empty()))
             && (border_thickness.left == 0.0
                 && border_thickness.top == 0.0
                 && border_thickness.right == 0.0
                 && border_thickness.bottom == 0.0)
+            && box_shadow.is_empty()
         {
             return;
         }
@@ -160,6 +223,30 @@ impl RenderObject for RectangleRenderObject {
             || border_thickness.right > 0.0
             || border_thickness.bottom > 0.0;
 
+        // different corners or sides could not be drawn with one stroke
+        let border_radius = match corner_radius.uniform() {
+            Some(border_radius)
+                if border_brushes.is_empty()
+                    && border_thickness.left == border_thickness.top
+                    && border_thickness.left == border_thickness.right
+                    && border_thickness.left == border_thickness.bottom =>
+            {
+                border_radius
+            }
+            _ => {
+                self.render_border_box(
+                    ctx.render_context_2_d(),
+                    shadow_bounds,
+                    corner_radius,
+                    background,
+                    border_brushes.resolve(&border_brush),
+                    border_thickness,
+                );
+                self.render_inset_shadows(ctx, shadow_bounds, corner_radius, &box_shadow);
+                return;
+            }
+        };
+
         ctx.render_context_2_d().begin_path();
 
         if (bounds.width() - bounds.height()).abs() < f64::EPSILON
@@ -227,5 +314,7 @@ impl RenderObject for RectangleRenderObject {
                 bounds.height(),
             );
         }
+
+        self.render_inset_shadows(ctx, shadow_bounds, corner_radius, &box_shadow);
     }
 }
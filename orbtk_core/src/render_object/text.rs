@@ -1,5 +1,6 @@
 use crate::{
     proc_macros::IntoRenderObject,
+    properties::{RichText, TextSpan},
     render_object::*,
     utils::{Brush, Point, Rectangle},
 };
@@ -10,8 +11,113 @@ use std::iter;
 #[derive(Debug, IntoRenderObject)]
 pub struct TextRenderObject;
 
+impl TextRenderObject {
+    // Draws the spans of a rich text, wrapped and trimmed to the width of the widget.
+    fn render_rich_text(
+        &self,
+        ctx: &mut Context,
+        global_position: &Point,
+        rich_text: RichText,
+        format: &TextFormat,
+    ) {
+        let (bounds, style, offset) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                TextStyle {
+                    font: widget.clone::<String>("font"),
+                    font_size: *widget.get::<f64>("font_size"),
+                    foreground: widget.clone::<Brush>("foreground"),
+                },
+                *widget.get::<f64>("offset"),
+            )
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 {
+            return;
+        }
+
+        let layout = TextLayout::new(
+            ctx.render_context_2_d(),
+            &rich_text,
+            &style,
+            format,
+            Some(bounds.width()),
+        );
+
+        let x = global_position.x() + bounds.x() + offset;
+        let y = global_position.y() + bounds.y();
+
+        for run in layout.runs() {
+            let span = &rich_text.spans()[run.span];
+            let run_x = x + run.bounds.x();
+            let run_y = y + run.bounds.y();
+            let thickness = (run.font_size / 14.0).max(1.0).round();
+            let rtx = ctx.render_context_2_d();
+
+            if let Some(background) = &span.background {
+                rtx.begin_path();
+                rtx.set_fill_style(background.clone());
+                rtx.fill_rect(run_x, run_y, run.bounds.width(), run.bounds.height());
+            }
+
+            rtx.begin_path();
+            rtx.set_font_family(run.font.as_str());
+            rtx.set_font_size(run.font_size);
+            rtx.set_fill_style(run.foreground.clone());
+            rtx.fill_text(&run.text, run_x, run_y);
+
+            if span.underline || span.link.is_some() {
+                rtx.fill_rect(
+                    run_x,
+                    run_y + run.bounds.height() * 0.92,
+                    run.bounds.width(),
+                    thickness,
+                );
+            }
+
+            if span.strikethrough {
+                rtx.fill_rect(
+                    run_x,
+                    run_y + run.bounds.height() * 0.6,
+                    run.bounds.width(),
+                    thickness,
+                );
+            }
+        }
+
+        ctx.render_context_2_d().close_path();
+    }
+}
+
 impl RenderObject for TextRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (rich_text, format) = {
+            let widget = ctx.widget();
+            (
+                widget
+                    .try_clone::<RichText>("rich_text")
+                    .filter(|rich_text| !rich_text.is_empty()),
+                TextFormat::from_widget(&widget),
+            )
+        };
+
+        if let Some(rich_text) = rich_text {
+            self.render_rich_text(ctx, global_position, rich_text, &format);
+            return;
+        }
+
+        // wrapped or trimmed text is drawn as rich text with a single span
+        if !format.is_single_line() {
+            let text = text(&ctx.widget());
+
+            if !text.is_empty() {
+                let rich_text = RichText::from(vec![TextSpan::from(text)]);
+                self.render_rich_text(ctx, global_position, rich_text, &format);
+                return;
+            }
+        }
+
         let (bounds, text, foreground, font, font_size, offset) = {
             let widget = ctx.widget();
             let text = text(&widget);
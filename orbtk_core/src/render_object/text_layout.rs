@@ -0,0 +1,534 @@
+use crate::{
+    properties::{FontWeight, RichText, TextSpan, TextTrimming, TextWrap},
+    render::RenderContext2D,
+    utils::{Brush, Point, Rectangle, Size},
+    widget_base::WidgetContainer,
+};
+
+/// Factor of the line height that is used as distance between the top of two lines.
+pub const LINE_SPACING: f64 = 1.15;
+
+/// Text that is appended to trimmed lines.
+pub const ELLIPSIS: &str = "\u{2026}";
+
+/// Describes the text style that is used for all values, a span does not define by itself.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextStyle {
+    pub font: String,
+    pub font_size: f64,
+    pub foreground: Brush,
+}
+
+/// Describes how a text is broken into lines and trimmed.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TextFormat {
+    /// Defines how the text is broken into lines.
+    pub text_wrap: TextWrap,
+
+    /// Defines how lines are trimmed, that do not fit.
+    pub text_trimming: TextTrimming,
+
+    /// Maximum number of lines. `0` means unlimited.
+    pub max_lines: usize,
+}
+
+impl TextFormat {
+    /// Reads the `text_wrap`, `text_trimming` and `max_lines` properties of the given widget.
+    /// `TextWrap::Auto` is resolved to `Word` if the widget has rich text, otherwise to `None`.
+    pub fn from_widget(widget: &WidgetContainer) -> Self {
+        let has_rich_text = widget
+            .try_get::<RichText>("rich_text")
+            .map_or(false, |rich_text| !rich_text.is_empty());
+
+        TextFormat {
+            text_wrap: match widget
+                .try_get::<TextWrap>("text_wrap")
+                .copied()
+                .unwrap_or_default()
+            {
+                TextWrap::Auto if has_rich_text => TextWrap::Word,
+                TextWrap::Auto => TextWrap::None,
+                text_wrap => text_wrap,
+            },
+            text_trimming: widget
+                .try_get::<TextTrimming>("text_trimming")
+                .copied()
+                .unwrap_or_default(),
+            max_lines: widget
+                .try_get::<usize>("max_lines")
+                .copied()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns `true` if the text is neither wrapped, trimmed nor limited in lines.
+    pub fn is_single_line(&self) -> bool {
+        self.text_wrap == TextWrap::None
+            && self.text_trimming == TextTrimming::None
+            && self.max_lines == 0
+    }
+}
+
+/// A piece of text of one span, that is placed inside of a `TextLayout`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextRun {
+    /// The text of the run.
+    pub text: String,
+
+    /// Index of the span inside of the rich text.
+    pub span: usize,
+
+    /// The resolved font family.
+    pub font: String,
+
+    /// The resolved font size.
+    pub font_size: f64,
+
+    /// The resolved foreground brush.
+    pub foreground: Brush,
+
+    /// Bounds of the run relative to the origin of the layout.
+    pub bounds: Rectangle,
+}
+
+/// A line of a `TextLayout`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLine {
+    pub runs: Vec<TextRun>,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Breaks a rich text into lines and positions the text runs of its spans.
+///
+/// If a max width is given, lines are broken as defined by the `TextWrap`
+/// of the format. Lines that are wider than the max width and the last line
+/// of a text, that has more lines than allowed, are trimmed as defined by
+/// the `TextTrimming` of the format.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLayout {
+    lines: Vec<TextLine>,
+    size: Size,
+}
+
+impl TextLayout {
+    /// Creates the layout of the given rich text.
+    pub fn new(
+        render_context_2_d: &mut RenderContext2D,
+        rich_text: &RichText,
+        style: &TextStyle,
+        format: &TextFormat,
+        max_width: Option<f64>,
+    ) -> Self {
+        let mut builder = LayoutBuilder::new(format.text_wrap, max_width);
+
+        for (index, span) in rich_text.iter().enumerate() {
+            let (font, font_size, foreground) = resolve_style(render_context_2_d, span, style);
+            let height = render_context_2_d.measure("X", font_size, &font).height;
+            builder.line_height = height;
+
+            let run = TextRun {
+                text: String::new(),
+                span: index,
+                font,
+                font_size,
+                foreground,
+                bounds: Rectangle::new((0.0, 0.0), (0.0, height)),
+            };
+
+            for (line_index, line) in span.text.split('\n').enumerate() {
+                if line_index > 0 {
+                    builder.break_line(false);
+                }
+
+                for word in split_words(line) {
+                    builder.push_word(render_context_2_d, &run, word);
+                }
+            }
+        }
+
+        builder.finish(render_context_2_d, format)
+    }
+
+    /// Gets the lines of the layout.
+    pub fn lines(&self) -> &[TextLine] {
+        &self.lines
+    }
+
+    /// Gets the size of the layout.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Gets an iterator over all runs of the layout.
+    pub fn runs(&self) -> impl Iterator<Item = &TextRun> {
+        self.lines.iter().flat_map(|line| line.runs.iter())
+    }
+
+    /// Gets the run at the given position (relative to the origin of the layout).
+    pub fn run_at(&self, position: Point) -> Option<&TextRun> {
+        self.runs().find(|run| run.bounds.contains(position))
+    }
+}
+
+// Resolves font, font size and foreground of the given span.
+fn resolve_style(
+    render_context_2_d: &mut RenderContext2D,
+    span: &TextSpan,
+    style: &TextStyle,
+) -> (String, f64, Brush) {
+    let mut font = span.font.clone().unwrap_or_else(|| style.font.clone());
+
+    if let Some(weight) = span.font_weight {
+        // heavy weights fall back to the medium weight, if they are not registered
+        let fallback = match weight {
+            FontWeight::SemiBold | FontWeight::Bold | FontWeight::Black => Some(FontWeight::Medium),
+            _ => None,
+        };
+
+        if let Some(weighted) = Some(weight)
+            .into_iter()
+            .chain(fallback)
+            .map(|weight| weight.apply(&font))
+            .find(|weighted| render_context_2_d.has_font(weighted))
+        {
+            font = weighted;
+        }
+    }
+
+    (
+        font,
+        span.font_size.unwrap_or(style.font_size),
+        span.foreground
+            .clone()
+            .unwrap_or_else(|| style.foreground.clone()),
+    )
+}
+
+// Splits the given text into words and the whitespace between them.
+fn split_words(text: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let mut start = 0;
+    let mut is_space = None;
+
+    for (index, c) in text.char_indices() {
+        let space = c.is_whitespace();
+
+        if is_space.map_or(false, |is_space| is_space != space) {
+            words.push(&text[start..index]);
+            start = index;
+        }
+
+        is_space = Some(space);
+    }
+
+    if start < text.len() {
+        words.push(&text[start..]);
+    }
+
+    words
+}
+
+// Helper to fill the lines of a layout.
+struct LayoutBuilder {
+    lines: Vec<TextLine>,
+    line: TextLine,
+    line_height: f64,
+    text_wrap: TextWrap,
+    max_width: Option<f64>,
+    // whitespace that is only placed, if a word follows on the same line
+    pending_space: Option<(TextRun, f64)>,
+}
+
+impl LayoutBuilder {
+    fn new(text_wrap: TextWrap, max_width: Option<f64>) -> Self {
+        LayoutBuilder {
+            lines: vec![],
+            line: TextLine::default(),
+            line_height: 0.0,
+            text_wrap,
+            max_width,
+            pending_space: None,
+        }
+    }
+
+    fn fits(&self, width: f64) -> bool {
+        let pending = self.pending_space.as_ref().map_or(0.0, |space| space.1);
+        self.max_width.map_or(true, |max_width| {
+            self.line.width + pending + width <= max_width
+        })
+    }
+
+    fn push_word(&mut self, render_context_2_d: &mut RenderContext2D, run: &TextRun, word: &str) {
+        let width = render_context_2_d
+            .measure(word, run.font_size, &run.font)
+            .width;
+
+        if word.chars().all(char::is_whitespace) {
+            if let Some(space) = &mut self.pending_space {
+                space.0.text.push_str(word);
+                space.1 += width;
+            } else {
+                let mut space = run.clone();
+                space.text = word.to_string();
+                self.pending_space = Some((space, width));
+            }
+            return;
+        }
+
+        match self.text_wrap {
+            TextWrap::None => self.push(run, word, width),
+            // rich text is wrapped between words by default
+            TextWrap::Word | TextWrap::Auto => {
+                if self.fits(width) {
+                    self.push(run, word, width);
+                    return;
+                }
+
+                if !self.line.runs.is_empty() {
+                    self.break_line(true);
+                }
+
+                if self.fits(width) {
+                    self.push(run, word, width);
+                    return;
+                }
+
+                // the word is wider than the line, break it between characters
+                self.push_characters(render_context_2_d, run, word);
+            }
+            TextWrap::Character => self.push_characters(render_context_2_d, run, word),
+        }
+    }
+
+    // Pushes the word character by character and breaks the line if the next character does not fit.
+    fn push_characters(
+        &mut self,
+        render_context_2_d: &mut RenderContext2D,
+        run: &TextRun,
+        word: &str,
+    ) {
+        let mut chunk = String::new();
+        let mut chunk_width = 0.0;
+
+        for c in word.chars() {
+            chunk.push(c);
+            let width = render_context_2_d
+                .measure(&chunk, run.font_size, &run.font)
+                .width;
+
+            if self.fits(width) {
+                chunk_width = width;
+                continue;
+            }
+
+            chunk.pop();
+
+            if !chunk.is_empty() {
+                self.push(run, &chunk, chunk_width);
+                self.break_line(true);
+            } else if !self.line.runs.is_empty() {
+                self.break_line(true);
+            }
+
+            chunk.clear();
+            chunk.push(c);
+            chunk_width = render_context_2_d
+                .measure(&chunk, run.font_size, &run.font)
+                .width;
+        }
+
+        if !chunk.is_empty() {
+            self.push(run, &chunk, chunk_width);
+        }
+    }
+
+    fn push(&mut self, run: &TextRun, text: &str, width: f64) {
+        if let Some((space, space_width)) = self.pending_space.take() {
+            self.push_run(&space, &space.text, space_width);
+        }
+
+        self.push_run(run, text, width);
+    }
+
+    fn push_run(&mut self, run: &TextRun, text: &str, width: f64) {
+        let x = self.line.width;
+        self.line.width += width;
+        self.line.height = self.line.height.max(run.bounds.height());
+
+        // extends the last run, if it belongs to the same span
+        if let Some(last) = self.line.runs.last_mut() {
+            if last.span == run.span {
+                last.text.push_str(text);
+                last.bounds.set_width(last.bounds.width() + width);
+                return;
+            }
+        }
+
+        let mut run = run.clone();
+        run.text = text.to_string();
+        run.bounds.set_x(x);
+        run.bounds.set_width(width);
+        self.line.runs.push(run);
+    }
+
+    // Moves the current line to the list of lines. Whitespace at the end of a wrapped
+    // line is dropped.
+    fn break_line(&mut self, wrapped: bool) {
+        if !wrapped {
+            if let Some((space, space_width)) = self.pending_space.take() {
+                self.push_run(&space, &space.text, space_width);
+            }
+        }
+
+        self.pending_space = None;
+
+        let mut line = std::mem::take(&mut self.line);
+        if line.runs.is_empty() {
+            line.height = self.line_height;
+        }
+
+        line.y = self
+            .lines
+            .last()
+            .map_or(0.0, |last| last.y + last.height * LINE_SPACING);
+
+        for run in &mut line.runs {
+            run.bounds.set_y(line.y + line.height - run.bounds.height());
+        }
+
+        self.lines.push(line);
+    }
+
+    fn finish(
+        mut self,
+        render_context_2_d: &mut RenderContext2D,
+        format: &TextFormat,
+    ) -> TextLayout {
+        if !self.line.runs.is_empty() || self.pending_space.is_some() || !self.lines.is_empty() {
+            self.break_line(false);
+        }
+
+        let truncated = format.max_lines > 0 && self.lines.len() > format.max_lines;
+
+        if truncated {
+            self.lines.truncate(format.max_lines);
+        }
+
+        if format.text_trimming != TextTrimming::None {
+            let last = self.lines.len().saturating_sub(1);
+            let max_width = self.max_width.unwrap_or(f64::MAX);
+
+            for (index, line) in self.lines.iter_mut().enumerate() {
+                if line.width > max_width || (truncated && index == last) {
+                    trim_line(render_context_2_d, line, format.text_trimming, max_width);
+                }
+            }
+        }
+
+        let width = self.lines.iter().map(|line| line.width).fold(0.0, f64::max);
+        let height = self.lines.last().map_or(0.0, |line| line.y + line.height);
+
+        TextLayout {
+            lines: self.lines,
+            size: Size::new(width, height),
+        }
+    }
+}
+
+// Removes text from the end of the line, until the line and an appended ellipsis fit into the max width.
+fn trim_line(
+    render_context_2_d: &mut RenderContext2D,
+    line: &mut TextLine,
+    text_trimming: TextTrimming,
+    max_width: f64,
+) {
+    let mut first = match line.runs.first() {
+        Some(first) => first.clone(),
+        None => return,
+    };
+
+    trim_end(render_context_2_d, line, |text| {
+        let len = text.trim_end().len();
+        text.truncate(len);
+    });
+
+    while let Some(last) = line.runs.last() {
+        let ellipsis_width = render_context_2_d
+            .measure(ELLIPSIS, last.font_size, &last.font)
+            .width;
+
+        if line.width + ellipsis_width <= max_width {
+            break;
+        }
+
+        // the last word of a line is trimmed between characters
+        let is_last_word = line.runs.len() == 1 && !last.text.contains(char::is_whitespace);
+
+        trim_end(render_context_2_d, line, |text| {
+            if text_trimming == TextTrimming::WordEllipsis && !is_last_word {
+                let len = text.rfind(char::is_whitespace).unwrap_or(0);
+                text.truncate(len);
+            } else {
+                text.pop();
+            }
+
+            let len = text.trim_end().len();
+            text.truncate(len);
+        });
+    }
+
+    // the ellipsis is appended to the last run or replaces the whole line
+    let mut ellipsis = line.runs.pop().unwrap_or_else(|| {
+        first.text.clear();
+        first.bounds.set_x(0.0);
+        first
+    });
+
+    ellipsis.text.push_str(ELLIPSIS);
+    let width = render_context_2_d
+        .measure(&ellipsis.text, ellipsis.font_size, &ellipsis.font)
+        .width;
+
+    ellipsis.bounds.set_width(width);
+    line.width = ellipsis.bounds.x() + width;
+    line.runs.push(ellipsis);
+}
+
+// Applies the trim function to the text of the last run of the line and updates the widths.
+fn trim_end<F: Fn(&mut String)>(
+    render_context_2_d: &mut RenderContext2D,
+    line: &mut TextLine,
+    trim: F,
+) {
+    if let Some(last) = line.runs.last_mut() {
+        trim(&mut last.text);
+
+        if last.text.is_empty() {
+            line.runs.pop();
+        } else {
+            let width = render_context_2_d
+                .measure(&last.text, last.font_size, &last.font)
+                .width;
+            last.bounds.set_width(width);
+        }
+    }
+
+    line.width = line
+        .runs
+        .last()
+        .map_or(0.0, |last| last.bounds.x() + last.bounds.width());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(split_words("ab  cd e"), vec!["ab", "  ", "cd", " ", "e"]);
+        assert_eq!(split_words(" a"), vec![" ", "a"]);
+        assert!(split_words("").is_empty());
+    }
+}
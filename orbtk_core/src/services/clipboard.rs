@@ -1,7 +1,22 @@
+use std::collections::HashMap;
+
+pub use crate::shell::{ClipboardContent, ClipboardFormat, ClipboardImage, ClipboardSelection};
+
+use crate::properties::{RichText, TextSpan};
+
 /// Clipboard leads you read and store a value.
 ///
-/// To access the value of systems clipboard it must be used in
-/// combination with a window shell.
+/// The value could be offered in multiple formats: plain text, html,
+/// a list of uris and RGBA images. To access the value of systems
+/// clipboard it must be used in combination with a window shell. Formats
+/// the shell could not exchange with the system stay available inside of
+/// the application. Pasted html is converted with `get_rich_text` to a
+/// `RichText` e.g. for a `TextBlock`.
+///
+/// The primary selection contains the last selected text and is pasted
+/// with a middle click in X11 style environments. Each change of the
+/// clipboard or the primary selection raises a `ClipboardChangedEvent`
+/// on the window.
 ///
 /// # Examples
 /// ```
@@ -10,12 +25,15 @@
 ///         let mut clipboard = registry.get_mut;:<Clipboard>("clipboard");
 ///         println!("{:?}", clipboard.get());
 ///         clipboard.set("paste");
+///         clipboard.set_html("<b>paste</b>", "paste");
 ///     }
 /// }
 /// ```
 #[derive(Clone, Default, Debug)]
 pub struct Clipboard {
-    value: Option<String>,
+    content: Option<ClipboardContent>,
+    primary: Option<ClipboardContent>,
+    changed: Vec<ClipboardSelection>,
 }
 
 impl Clipboard {
@@ -24,21 +42,217 @@ impl Clipboard {
         Clipboard::default()
     }
 
-    /// Return the latest value of the clipboard.
+    /// Return the latest value of the clipboard as plain text.
     /// If there is no value present on the clipboard it will return `None`.
     pub fn get(&self) -> Option<String> {
-        self.value.clone()
+        self.content
+            .as_ref()
+            .and_then(|content| content.plain_text())
     }
 
     /// Sets the value of the clipboard.
     pub fn set(&mut self, value: impl Into<String>) {
-        self.value = Some(value.into());
+        self.set_content(ClipboardContent::from_text(value));
+    }
+
+    /// Returns the content of the clipboard with all offered formats.
+    pub fn content(&self) -> Option<&ClipboardContent> {
+        self.content_of(ClipboardSelection::Clipboard)
+    }
+
+    /// Sets the content of the clipboard e.g. html with a plain text alternative.
+    pub fn set_content(&mut self, content: impl Into<ClipboardContent>) {
+        self.set_content_of(ClipboardSelection::Clipboard, content);
+    }
+
+    /// Returns the formats in which the value of the clipboard is offered.
+    pub fn formats(&self) -> Vec<ClipboardFormat> {
+        self.content
+            .as_ref()
+            .map(|content| content.formats())
+            .unwrap_or_default()
+    }
+
+    /// Returns the html of the clipboard if it is offered.
+    pub fn get_html(&self) -> Option<String> {
+        self.content
+            .as_ref()
+            .and_then(|content| content.html())
+            .map(String::from)
+    }
+
+    /// Returns the value of the clipboard as rich text. Html keeps its style, plain text is
+    /// taken as it is.
+    pub fn get_rich_text(&self) -> Option<RichText> {
+        self.get_html()
+            .map(|html| RichText::from_html(&html))
+            .or_else(|| {
+                self.get()
+                    .map(|text| RichText::from(vec![TextSpan::from(text)]))
+            })
+    }
+
+    /// Sets html together with its plain text alternative as value of the clipboard.
+    pub fn set_html(&mut self, html: impl Into<String>, text: impl Into<String>) {
+        self.set_content(ClipboardContent::from_html(html).with_text(text));
+    }
+
+    /// Returns the list of uris of the clipboard e.g. copied files. The list is empty if it is not offered.
+    pub fn get_uri_list(&self) -> Vec<String> {
+        self.content
+            .as_ref()
+            .map(|content| content.uri_list().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Sets the list of uris as value of the clipboard.
+    pub fn set_uri_list(&mut self, uri_list: Vec<String>) {
+        self.set_content(ClipboardContent::from_uri_list(uri_list));
+    }
+
+    /// Returns the image of the clipboard if it is offered.
+    pub fn get_image(&self) -> Option<ClipboardImage> {
+        self.content
+            .as_ref()
+            .and_then(|content| content.image())
+            .cloned()
+    }
+
+    /// Sets the image as value of the clipboard e.g. a region of an `ImageWidget` or
+    /// of a `Canvas` (see `Context::frame_image`).
+    pub fn set_image(&mut self, image: impl Into<ClipboardImage>) {
+        self.set_content(ClipboardContent::from_image(image.into()));
+    }
+
+    /// Removes the value of the clipboard.
+    pub fn clear(&mut self) {
+        self.content = None;
+        self.mark_changed(ClipboardSelection::Clipboard);
+    }
+
+    /// Returns the latest value of the primary selection as plain text.
+    pub fn get_primary(&self) -> Option<String> {
+        self.primary
+            .as_ref()
+            .and_then(|content| content.plain_text())
+    }
+
+    /// Sets the value of the primary selection e.g. the selected text of a text box.
+    pub fn set_primary(&mut self, value: impl Into<String>) {
+        self.set_content_of(
+            ClipboardSelection::Primary,
+            ClipboardContent::from_text(value),
+        );
+    }
+
+    /// Returns the content of the given clipboard with all offered formats.
+    pub fn content_of(&self, selection: ClipboardSelection) -> Option<&ClipboardContent> {
+        match selection {
+            ClipboardSelection::Clipboard => self.content.as_ref(),
+            ClipboardSelection::Primary => self.primary.as_ref(),
+        }
+    }
+
+    /// Sets the content of the given clipboard.
+    pub fn set_content_of(
+        &mut self,
+        selection: ClipboardSelection,
+        content: impl Into<ClipboardContent>,
+    ) {
+        self.set_system_content(selection, content.into());
+        self.mark_changed(selection);
+    }
+
+    // Sets content that was read from the clipboard of the system, it is not written back.
+    pub(crate) fn set_system_content(
+        &mut self,
+        selection: ClipboardSelection,
+        content: ClipboardContent,
+    ) {
+        match selection {
+            ClipboardSelection::Clipboard => self.content = Some(content),
+            ClipboardSelection::Primary => self.primary = Some(content),
+        }
+    }
+
+    // Returns `true` if the given clipboard is changed by the application since the last call.
+    pub(crate) fn take_changed(&mut self, selection: ClipboardSelection) -> bool {
+        let len = self.changed.len();
+        self.changed.retain(|changed| *changed != selection);
+        self.changed.len() != len
+    }
+
+    fn mark_changed(&mut self, selection: ClipboardSelection) {
+        if !self.changed.contains(&selection) {
+            self.changed.push(selection);
+        }
+    }
+}
+
+// Synchronizes the clipboard with the clipboard of the system and tells which selections are
+// changed to raise a `ClipboardChangedEvent`.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct ClipboardSync {
+    // the content that was last exchanged with the system
+    system_values: HashMap<ClipboardSelection, ClipboardContent>,
+}
+
+impl ClipboardSync {
+    // Writes the new content of the clipboard to the system in the given formats or reads the
+    // content of the system. Returns `true` if the clipboard is changed.
+    pub(crate) fn update(
+        &mut self,
+        clipboard: &mut Clipboard,
+        selection: ClipboardSelection,
+        formats: &[ClipboardFormat],
+        content: &mut Option<ClipboardContent>,
+    ) -> bool {
+        // internal clipboard value is new => update system clipboard value with the formats the shell supports.
+        if clipboard.take_changed(selection) {
+            *content = clipboard
+                .content_of(selection)
+                .map(|clipboard_content| clipboard_content.filter(formats))
+                .filter(|system_content| !system_content.is_empty());
+
+            // e.g. an image could not be written => the old system value must not replace it.
+            if let Some(system_content) = content.clone() {
+                self.system_values.insert(selection, system_content);
+            }
+
+            return true;
+        }
+
+        //  system clipboard value is newer => update internal clipboard. If the system clipboard
+        //  still contains the value that was written by the window the internal value with all formats is kept.
+        if let Some(system_content) = content.take() {
+            if self.system_values.get(&selection) == Some(&system_content) {
+                return false;
+            }
+
+            clipboard.set_system_content(selection, system_content.clone());
+            self.system_values.insert(selection, system_content);
+
+            return true;
+        }
+
+        false
+    }
+
+    // Returns the selections that are changed by the application but not exchanged with the
+    // system by the shell e.g. the primary selection if the shell does not support it.
+    pub(crate) fn changed(&self, clipboard: &mut Clipboard) -> Vec<ClipboardSelection> {
+        [ClipboardSelection::Clipboard, ClipboardSelection::Primary]
+            .iter()
+            .copied()
+            .filter(|selection| clipboard.take_changed(*selection))
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::properties::FontWeight;
 
     #[test]
     /// A quick test to ensure that the items are properly set.
@@ -48,4 +262,156 @@ mod tests {
         clipboard.set(test.clone());
         assert_eq!(test, clipboard.get().unwrap());
     }
+
+    #[test]
+    fn get_set_formats() {
+        let mut clipboard = Clipboard::new();
+        assert!(clipboard.formats().is_empty());
+
+        clipboard.set_html("<b>test</b>", "test");
+        assert_eq!(clipboard.get_html(), Some("<b>test</b>".to_string()));
+        assert_eq!(clipboard.get(), Some("test".to_string()));
+
+        clipboard.set_uri_list(vec!["file:///test".to_string()]);
+        assert_eq!(clipboard.get_html(), None);
+        assert_eq!(clipboard.get_uri_list(), vec!["file:///test".to_string()]);
+
+        let image = ClipboardImage::new(1, 1, vec![1, 2, 3, 4]).unwrap();
+        clipboard.set_image(image.clone());
+        assert_eq!(clipboard.get_image(), Some(image));
+        assert_eq!(clipboard.get(), None);
+        assert_eq!(clipboard.formats(), vec![ClipboardFormat::Image]);
+
+        clipboard.clear();
+        assert!(clipboard.content().is_none());
+    }
+
+    #[test]
+    fn get_rich_text() {
+        let mut clipboard = Clipboard::new();
+        assert_eq!(clipboard.get_rich_text(), None);
+
+        clipboard.set("<b>text</b>");
+        let rich_text = clipboard.get_rich_text().unwrap();
+        assert_eq!(rich_text.text(), "<b>text</b>");
+
+        clipboard.set_html("<p>a <strong>b</strong></p>", "a b");
+        let rich_text = clipboard.get_rich_text().unwrap();
+        assert_eq!(rich_text.text(), "a b");
+        assert_eq!(rich_text.spans()[1].font_weight, Some(FontWeight::Bold));
+    }
+
+    #[test]
+    fn primary_selection() {
+        let mut clipboard = Clipboard::new();
+        clipboard.set("clipboard");
+        clipboard.set_primary("primary");
+
+        assert_eq!(clipboard.get(), Some("clipboard".to_string()));
+        assert_eq!(clipboard.get_primary(), Some("primary".to_string()));
+    }
+
+    #[test]
+    fn take_changed() {
+        let mut clipboard = Clipboard::new();
+        assert!(!clipboard.take_changed(ClipboardSelection::Clipboard));
+
+        clipboard.set("test");
+        clipboard.set_html("<b>test</b>", "test");
+        assert!(!clipboard.take_changed(ClipboardSelection::Primary));
+        assert!(clipboard.take_changed(ClipboardSelection::Clipboard));
+        assert!(!clipboard.take_changed(ClipboardSelection::Clipboard));
+
+        // content of the system is not written back
+        clipboard.set_system_content(
+            ClipboardSelection::Primary,
+            ClipboardContent::from_text("system"),
+        );
+        assert!(!clipboard.take_changed(ClipboardSelection::Primary));
+        assert_eq!(clipboard.get_primary(), Some("system".to_string()));
+    }
+
+    #[test]
+    fn sync_writes_changed_content() {
+        let mut clipboard = Clipboard::new();
+        let mut sync = ClipboardSync::default();
+        let mut content = None;
+
+        clipboard.set_html("<b>test</b>", "test");
+        assert!(sync.update(
+            &mut clipboard,
+            ClipboardSelection::Clipboard,
+            &[ClipboardFormat::Text],
+            &mut content
+        ));
+        assert_eq!(content, Some(ClipboardContent::from_text("test")));
+
+        // nothing changed
+        let mut content = None;
+        assert!(!sync.update(
+            &mut clipboard,
+            ClipboardSelection::Clipboard,
+            &[ClipboardFormat::Text],
+            &mut content
+        ));
+        assert_eq!(content, None);
+    }
+
+    #[test]
+    fn sync_reads_system_content() {
+        let mut clipboard = Clipboard::new();
+        let mut sync = ClipboardSync::default();
+
+        clipboard.set_html("<b>test</b>", "test");
+        let mut content = None;
+        sync.update(
+            &mut clipboard,
+            ClipboardSelection::Clipboard,
+            &[ClipboardFormat::Text],
+            &mut content,
+        );
+
+        // system content equals what was written => no event and the html is kept
+        assert!(!sync.update(
+            &mut clipboard,
+            ClipboardSelection::Clipboard,
+            &[ClipboardFormat::Text],
+            &mut content
+        ));
+        assert_eq!(clipboard.get_html(), Some("<b>test</b>".to_string()));
+
+        // another application changed the system clipboard
+        let mut content = Some(ClipboardContent::from_text("other"));
+        assert!(sync.update(
+            &mut clipboard,
+            ClipboardSelection::Clipboard,
+            &[ClipboardFormat::Text],
+            &mut content
+        ));
+        assert_eq!(clipboard.get(), Some("other".to_string()));
+        assert_eq!(clipboard.get_html(), None);
+        assert!(!clipboard.take_changed(ClipboardSelection::Clipboard));
+
+        let mut content = Some(ClipboardContent::from_text("other"));
+        assert!(!sync.update(
+            &mut clipboard,
+            ClipboardSelection::Clipboard,
+            &[ClipboardFormat::Text],
+            &mut content
+        ));
+    }
+
+    #[test]
+    fn sync_changed() {
+        let mut clipboard = Clipboard::new();
+        let sync = ClipboardSync::default();
+        assert!(sync.changed(&mut clipboard).is_empty());
+
+        clipboard.set_primary("primary");
+        assert_eq!(
+            sync.changed(&mut clipboard),
+            vec![ClipboardSelection::Primary]
+        );
+        assert!(sync.changed(&mut clipboard).is_empty());
+    }
 }
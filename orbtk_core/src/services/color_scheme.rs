@@ -0,0 +1,264 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Environment variable to override the detected color scheme with `light` or `dark`.
+pub static COLOR_SCHEME_ENV: &str = "ORBTK_COLOR_SCHEME";
+
+/// Describes the preferred color scheme of the desktop.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        ColorScheme::Light
+    }
+}
+
+impl From<&str> for ColorScheme {
+    fn from(s: &str) -> ColorScheme {
+        match s.trim().to_lowercase().as_str() {
+            "dark" | "prefer-dark" => ColorScheme::Dark,
+            _ => ColorScheme::Light,
+        }
+    }
+}
+
+impl ColorScheme {
+    /// Detects the preferred color scheme of the desktop. The sources are checked in the following order:
+    ///
+    /// * the `ORBTK_COLOR_SCHEME` environment variable (`light` or `dark`).
+    /// * the `color-scheme` of the XDG desktop portal `settings.ini` in the user config directory.
+    /// * the `GTK_THEME` environment variable, e.g. `Adwaita:dark`.
+    /// * the `gtk-application-prefer-dark-theme` and `gtk-theme-name` of the GTK 4 and GTK 3 `settings.ini`.
+    ///
+    /// Returns `ColorScheme::Light` if no preference is found.
+    pub fn detect() -> Self {
+        ColorScheme::detect_from(
+            |key| env::var(key).ok(),
+            |path| fs::read_to_string(path).ok(),
+            config_dir(),
+        )
+    }
+
+    // detects the color scheme with the given environment and file access
+    fn detect_from(
+        var: impl Fn(&str) -> Option<String>,
+        read: impl Fn(&Path) -> Option<String>,
+        config_dir: Option<PathBuf>,
+    ) -> Self {
+        if let Some(scheme) = var(COLOR_SCHEME_ENV) {
+            return ColorScheme::from(scheme.as_str());
+        }
+
+        let settings = |file: &str| {
+            config_dir
+                .as_ref()
+                .and_then(|dir| read(dir.join(file).as_path()))
+        };
+
+        if let Some(scheme) = settings("xdg-desktop-portal/settings.ini")
+            .and_then(|ini| ini_value(&ini, "color-scheme"))
+        {
+            return ColorScheme::from(scheme.as_str());
+        }
+
+        if let Some(theme) = var("GTK_THEME") {
+            return ColorScheme::from_theme_name(&theme);
+        }
+
+        for file in &["gtk-4.0/settings.ini", "gtk-3.0/settings.ini"] {
+            if let Some(ini) = settings(file) {
+                if let Some(prefer_dark) = ini_value(&ini, "gtk-application-prefer-dark-theme") {
+                    if prefer_dark == "1" || prefer_dark == "true" {
+                        return ColorScheme::Dark;
+                    }
+                }
+
+                if let Some(theme) = ini_value(&ini, "gtk-theme-name") {
+                    return ColorScheme::from_theme_name(&theme);
+                }
+            }
+        }
+
+        ColorScheme::Light
+    }
+
+    // a theme name like `Adwaita-dark` or `Adwaita:dark` indicates a dark color scheme
+    fn from_theme_name(name: &str) -> Self {
+        if name.to_lowercase().contains("dark") {
+            ColorScheme::Dark
+        } else {
+            ColorScheme::Light
+        }
+    }
+}
+
+fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+}
+
+// reads the value of the given key from an ini file, sections are ignored
+fn ini_value(ini: &str, key: &str) -> Option<String> {
+    ini.lines().find_map(|line| {
+        let mut parts = line.splitn(2, '=');
+        let line_key = parts.next()?.trim();
+
+        if line_key != key {
+            return None;
+        }
+
+        Some(
+            parts
+                .next()?
+                .trim()
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string(),
+        )
+    })
+}
+
+/// The `ColorSchemeService` provides the preferred color scheme of the desktop and checks
+/// it for changes. It is registered as `color_scheme` in the `Registry` of each window.
+///
+/// If the scheme changes, a `ColorSchemeChangedEvent` is sent to the window and the
+/// theme is switched if the `Application` is configured with a light and a dark theme.
+///
+/// # Example
+///
+/// ```rust
+/// impl State for MyState {
+///     fn init(&mut self, registry: &mut Registry, _: &mut Context) {
+///         if registry.get::<ColorSchemeService>("color_scheme").scheme() == ColorScheme::Dark {
+///             println!("dark mode");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ColorSchemeService {
+    scheme: ColorScheme,
+    poll_interval: Duration,
+    last_poll: Instant,
+}
+
+impl Default for ColorSchemeService {
+    fn default() -> Self {
+        ColorSchemeService {
+            scheme: ColorScheme::detect(),
+            poll_interval: Duration::from_secs(2),
+            last_poll: Instant::now(),
+        }
+    }
+}
+
+impl ColorSchemeService {
+    /// Creates a new service with the currently detected color scheme.
+    pub fn new() -> Self {
+        ColorSchemeService::default()
+    }
+
+    /// Gets the current color scheme.
+    pub fn scheme(&self) -> ColorScheme {
+        self.scheme
+    }
+
+    /// Detects the color scheme again after the poll interval. Returns the new scheme if it has changed.
+    pub fn poll(&mut self) -> Option<ColorScheme> {
+        if self.last_poll.elapsed() < self.poll_interval {
+            return None;
+        }
+
+        self.last_poll = Instant::now();
+
+        let scheme = ColorScheme::detect();
+
+        if scheme == self.scheme {
+            return None;
+        }
+
+        self.scheme = scheme;
+        Some(scheme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(vars: &[(&str, &str)], files: &[(&str, &str)]) -> ColorScheme {
+        ColorScheme::detect_from(
+            |key| {
+                vars.iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| v.to_string())
+            },
+            |path| {
+                files
+                    .iter()
+                    .find(|(file, _)| Path::new("/config").join(file) == path)
+                    .map(|(_, content)| content.to_string())
+            },
+            Some(PathBuf::from("/config")),
+        )
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(detect(&[], &[]), ColorScheme::Light);
+        assert_eq!(
+            detect(&[("GTK_THEME", "Adwaita:dark")], &[]),
+            ColorScheme::Dark
+        );
+        assert_eq!(
+            detect(
+                &[(COLOR_SCHEME_ENV, "light"), ("GTK_THEME", "Adwaita:dark")],
+                &[]
+            ),
+            ColorScheme::Light
+        );
+        assert_eq!(
+            detect(
+                &[("GTK_THEME", "Adwaita")],
+                &[(
+                    "xdg-desktop-portal/settings.ini",
+                    "[org.freedesktop.appearance]\ncolor-scheme = prefer-dark\n"
+                )]
+            ),
+            ColorScheme::Dark
+        );
+        assert_eq!(
+            detect(
+                &[],
+                &[(
+                    "gtk-3.0/settings.ini",
+                    "[Settings]\ngtk-application-prefer-dark-theme=1\n"
+                )]
+            ),
+            ColorScheme::Dark
+        );
+        assert_eq!(
+            detect(
+                &[],
+                &[
+                    (
+                        "gtk-4.0/settings.ini",
+                        "[Settings]\ngtk-theme-name=Adwaita\n"
+                    ),
+                    (
+                        "gtk-3.0/settings.ini",
+                        "[Settings]\ngtk-theme-name=Adwaita-dark\n"
+                    )
+                ]
+            ),
+            ColorScheme::Light
+        );
+    }
+}
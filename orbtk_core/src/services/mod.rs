@@ -1,7 +1,9 @@
 //! This module contains global services.
 //!
 pub use self::clipboard::*;
+pub use self::color_scheme::*;
 pub use self::settings::*;
 
 mod clipboard;
+mod color_scheme;
 mod settings;
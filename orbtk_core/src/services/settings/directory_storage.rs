@@ -0,0 +1,100 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use super::{write_atomic, SettingsError, SettingsFormat, SettingsResult, SettingsStorage};
+
+/// Stores the settings of each key in its own file of a directory e.g. `<dir>/<key>.ron`.
+///
+/// This is the default storage of the `Settings` service, that stores the settings as `ron`
+/// files in a folder with the application name in the user's config dir (depending on the
+/// operating system).
+///
+/// Files are written to a temporary file first, that replaces the file, so a crash while saving
+/// doesn't corrupt the settings.
+#[derive(Debug, Clone)]
+pub struct DirectoryStorage {
+    path: PathBuf,
+    format: SettingsFormat,
+    backup: bool,
+}
+
+impl DirectoryStorage {
+    /// Creates a new storage for the given directory and format.
+    pub fn new(path: impl Into<PathBuf>, format: SettingsFormat) -> Self {
+        DirectoryStorage {
+            path: path.into(),
+            format,
+            backup: false,
+        }
+    }
+
+    /// If set to `true` the previous file is kept as backup e.g. `<key>.ron.bak` on save.
+    pub fn backup(mut self, backup: bool) -> Self {
+        self.backup = backup;
+        self
+    }
+
+    /// Creates a new storage that stores `ron` files in the user's config dir under a folder
+    /// with the given application name.
+    pub fn from_name(app_name: &str) -> Self {
+        let mut path = dirs_next::config_dir().unwrap_or_default();
+        path.push(app_name);
+
+        DirectoryStorage::new(path, SettingsFormat::Ron)
+    }
+
+    /// Gets the path of the directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Gets the path of the file of the given key.
+    pub fn file(&self, key: &str) -> PathBuf {
+        self.path
+            .join(format!("{}.{}", key, self.format.extension()))
+    }
+}
+
+impl SettingsStorage for DirectoryStorage {
+    fn format(&self) -> SettingsFormat {
+        self.format
+    }
+
+    fn read(&self, key: &str) -> SettingsResult<String> {
+        let file = self.file(key);
+
+        fs::read_to_string(&file).map_err(|_| {
+            SettingsError::Loaded(format!(
+                "Settings.load: Could not open config file {:?}",
+                file
+            ))
+        })
+    }
+
+    fn write(&self, key: &str, content: &str) -> SettingsResult<()> {
+        if !self.path.exists() && fs::create_dir_all(&self.path).is_err() {
+            return Err(SettingsError::Saved(format!(
+                "Settings.save: Could not create settings dir {:?}",
+                self.path
+            )));
+        }
+
+        let file = self.file(key);
+
+        write_atomic(&file, content, self.backup).map_err(|_| {
+            SettingsError::Saved(format!(
+                "Settings.save: Could not write to config file {:?}",
+                file
+            ))
+        })
+    }
+
+    fn modified(&self, key: &str) -> Option<SystemTime> {
+        fs::metadata(self.file(key))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+}
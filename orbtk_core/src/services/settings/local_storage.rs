@@ -0,0 +1,38 @@
+use stdweb::web::window;
+
+use super::{SettingsError, SettingsFormat, SettingsResult, SettingsStorage};
+
+/// Stores the settings as `ron` in the local storage of the browser.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct LocalStorage;
+
+impl LocalStorage {
+    /// Creates a new local storage.
+    pub fn new() -> Self {
+        LocalStorage
+    }
+}
+
+impl SettingsStorage for LocalStorage {
+    fn format(&self) -> SettingsFormat {
+        SettingsFormat::Ron
+    }
+
+    fn read(&self, key: &str) -> SettingsResult<String> {
+        window().local_storage().get(key).ok_or_else(|| {
+            SettingsError::Loaded(format!(
+                "Settings.load: Could not read data from local browser storage with key: {}",
+                key
+            ))
+        })
+    }
+
+    fn write(&self, key: &str, content: &str) -> SettingsResult<()> {
+        window().local_storage().insert(key, content).map_err(|_| {
+            SettingsError::Saved(format!(
+                "Settings.save: Could not write settings with key {} to local browser storage.",
+                key
+            ))
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use super::{SettingsError, SettingsFormat, SettingsResult, SettingsStorage};
+
+/// Keeps the serialized settings in memory, nothing is written to the file system. Could be used
+/// for tests. Clones of the storage share the same settings, writes of a clone are noticed like
+/// changes of a file outside of the application.
+///
+/// # Example
+///
+/// ```rust
+/// let storage = MemoryStorage::new();
+///
+/// Application::new()
+///     .settings_storage(storage.clone())
+///     .window(|ctx| Window::new().build(ctx));
+///
+/// assert!(storage.get("settings_data").is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    format: SettingsFormat,
+    entries: Arc<Mutex<BTreeMap<String, (String, SystemTime)>>>,
+}
+
+impl MemoryStorage {
+    /// Creates a new empty storage, the settings are serialized as `ron`.
+    pub fn new() -> Self {
+        MemoryStorage::default()
+    }
+
+    /// Creates a new empty storage, the settings are serialized in the given format.
+    pub fn from_format(format: SettingsFormat) -> Self {
+        MemoryStorage {
+            format,
+            ..Default::default()
+        }
+    }
+
+    /// Gets the serialized settings with the given key.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.entries
+            .lock()
+            .ok()?
+            .get(key)
+            .map(|(content, _)| content.clone())
+    }
+
+    /// Gets the keys of the stored settings.
+    pub fn keys(&self) -> Vec<String> {
+        self.entries
+            .lock()
+            .map(|entries| entries.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Removes all settings.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+impl SettingsStorage for MemoryStorage {
+    fn format(&self) -> SettingsFormat {
+        self.format
+    }
+
+    fn read(&self, key: &str) -> SettingsResult<String> {
+        self.get(key).ok_or_else(|| {
+            SettingsError::Loaded(format!(
+                "Settings.load: Could not load settings with key: {}",
+                key
+            ))
+        })
+    }
+
+    fn write(&self, key: &str, content: &str) -> SettingsResult<()> {
+        self.entries
+            .lock()
+            .map(|mut entries| {
+                // each write changes the modification time, even in the same instant
+                let modified = match entries.get(key) {
+                    Some((_, modified)) => {
+                        SystemTime::now().max(*modified + Duration::from_nanos(1))
+                    }
+                    None => SystemTime::now(),
+                };
+
+                entries.insert(key.to_string(), (content.to_string(), modified));
+            })
+            .map_err(|_| {
+                SettingsError::Saved(format!(
+                    "Settings.save: Could not write settings with key: {}",
+                    key
+                ))
+            })
+    }
+
+    fn modified(&self, key: &str) -> Option<SystemTime> {
+        self.entries
+            .lock()
+            .ok()?
+            .get(key)
+            .map(|(_, modified)| *modified)
+    }
+}
@@ -0,0 +1,281 @@
+use std::fmt;
+
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Serialize,
+};
+
+use super::{SettingsError, SettingsFormat, SettingsResult};
+
+// migrates the serialized settings of one version to the next version, unversioned settings are
+// read as settings of the first version
+type Migration =
+    Box<dyn Fn(SettingsFormat, &str, bool) -> SettingsResult<String> + Send + Sync + 'static>;
+
+// settings that are saved with the version of their migrations
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+struct Versioned<D> {
+    version: u32,
+    data: D,
+}
+
+/// Describes the versions of settings and how to migrate old settings to the current version.
+///
+/// The first version is `1`, each migration increases the version by one. Settings with
+/// registered migrations are saved together with their version. On load old settings are
+/// migrated step by step to the current version and saved again. Settings that were saved
+/// without version are migrated from version `1`.
+///
+/// # Example
+///
+/// ```rust
+/// let migrations = Migrations::new()
+///     // v1 -> v2
+///     .migration(|v1: ConfigV1| ConfigV2 { width: v1.size, height: v1.size })
+///     // v2 -> v3
+///     .migration(|v2: ConfigV2| ConfigV3 { size: (v2.width, v2.height), maximized: false });
+///
+/// settings.register_migrations("config", migrations);
+///
+/// let config: ConfigV3 = settings.load("config")?;
+/// ```
+#[derive(Default)]
+pub struct Migrations {
+    migrations: Vec<Migration>,
+}
+
+impl Migrations {
+    /// Creates migrations of settings without migration steps, the version is `1`.
+    pub fn new() -> Self {
+        Migrations::default()
+    }
+
+    /// Adds a migration from the settings type of the current version to the settings type of
+    /// the next version.
+    pub fn migration<F, T, M>(mut self, migrate: M) -> Self
+    where
+        F: DeserializeOwned,
+        T: Serialize,
+        M: Fn(F) -> T + Send + Sync + 'static,
+    {
+        let version = self.version();
+
+        self.migrations
+            .push(Box::new(move |format, content, unversioned| {
+                let data = if unversioned {
+                    format.deserialize::<F>(content)
+                } else {
+                    format
+                        .deserialize::<Versioned<F>>(content)
+                        .map(|versioned| versioned.data)
+                };
+
+                data.and_then(|data| {
+                    format.serialize(&Versioned {
+                        version: version + 1,
+                        data: migrate(data),
+                    })
+                })
+                .map_err(|e| {
+                    SettingsError::Loaded(format!(
+                        "Settings.load: Could not migrate settings from version {} to {}: {:?}",
+                        version,
+                        version + 1,
+                        e
+                    ))
+                })
+            }));
+
+        self
+    }
+
+    /// Gets the current version.
+    pub fn version(&self) -> u32 {
+        self.migrations.len() as u32 + 1
+    }
+
+    /// Serializes the given data object together with the current version.
+    pub fn serialize<S: Serialize>(
+        &self,
+        format: SettingsFormat,
+        data: &S,
+    ) -> SettingsResult<String> {
+        format.serialize(&Versioned {
+            version: self.version(),
+            data,
+        })
+    }
+
+    /// Migrates the given serialized settings to the current version. Returns `None` if the
+    /// settings have already the current version.
+    pub fn migrate(&self, format: SettingsFormat, content: &str) -> SettingsResult<Option<String>> {
+        let (version, mut unversioned) = match stored_version(format, content) {
+            Some(version) => (version.max(1), false),
+            None => (1, true),
+        };
+
+        if version > self.version() {
+            return Err(SettingsError::Loaded(format!(
+                "Settings.load: Settings version {} is newer than the supported version {}",
+                version,
+                self.version()
+            )));
+        }
+
+        if version == self.version() {
+            return Ok(None);
+        }
+
+        let mut content = content.to_string();
+
+        for migration in self.migrations.iter().skip(version as usize - 1) {
+            content = migration(format, content.as_str(), unversioned)?;
+            unversioned = false;
+        }
+
+        Ok(Some(content))
+    }
+
+    /// Deserializes the data object from the given serialized settings of the current version.
+    /// Settings that were saved without version are deserialized as they are.
+    pub fn deserialize<D: DeserializeOwned>(
+        &self,
+        format: SettingsFormat,
+        content: &str,
+    ) -> SettingsResult<D> {
+        if stored_version(format, content).is_none() {
+            return format.deserialize(content);
+        }
+
+        format
+            .deserialize::<Versioned<D>>(content)
+            .map(|versioned| versioned.data)
+    }
+}
+
+impl fmt::Debug for Migrations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migrations")
+            .field("version", &self.version())
+            .finish()
+    }
+}
+
+// --- Helper --
+
+// reads the version of the serialized settings, `None` if the settings are not versioned
+fn stored_version(format: SettingsFormat, content: &str) -> Option<u32> {
+    format
+        .deserialize::<Versioned<IgnoredAny>>(content)
+        .ok()
+        .map(|versioned| versioned.version)
+}
+
+// --- Helper --
+
+#[cfg(test)]
+mod tests {
+    use serde_derive::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ConfigV1 {
+        size: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ConfigV2 {
+        width: u32,
+        height: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ConfigV3 {
+        size: (u32, u32),
+        maximized: bool,
+    }
+
+    fn migrations() -> Migrations {
+        Migrations::new()
+            .migration(|v1: ConfigV1| ConfigV2 {
+                width: v1.size,
+                height: v1.size,
+            })
+            .migration(|v2: ConfigV2| ConfigV3 {
+                size: (v2.width, v2.height),
+                maximized: false,
+            })
+    }
+
+    #[test]
+    fn test_migrate() {
+        let format = SettingsFormat::Ron;
+        let migrations = migrations();
+        let expected = ConfigV3 {
+            size: (4, 4),
+            maximized: false,
+        };
+
+        assert_eq!(migrations.version(), 3);
+
+        // unversioned settings are migrated from version 1
+        let unversioned = format.serialize(&ConfigV1 { size: 4 }).unwrap();
+        let migrated = migrations.migrate(format, &unversioned).unwrap().unwrap();
+        assert_eq!(
+            migrations
+                .deserialize::<ConfigV3>(format, &migrated)
+                .unwrap(),
+            expected
+        );
+
+        let v2 = format
+            .serialize(&Versioned {
+                version: 2,
+                data: ConfigV2 {
+                    width: 4,
+                    height: 4,
+                },
+            })
+            .unwrap();
+        let migrated = migrations.migrate(format, &v2).unwrap().unwrap();
+        assert_eq!(
+            migrations
+                .deserialize::<ConfigV3>(format, &migrated)
+                .unwrap(),
+            expected
+        );
+
+        // current version
+        let v3 = migrations.serialize(format, &expected).unwrap();
+        assert!(migrations.migrate(format, &v3).unwrap().is_none());
+        assert_eq!(
+            migrations.deserialize::<ConfigV3>(format, &v3).unwrap(),
+            expected
+        );
+
+        // newer version
+        let v4 = Migrations::new()
+            .migration(|v: ConfigV3| v)
+            .migration(|v: ConfigV3| v)
+            .migration(|v: ConfigV3| v)
+            .serialize(format, &expected)
+            .unwrap();
+        assert!(migrations.migrate(format, &v4).is_err());
+    }
+
+    #[test]
+    fn test_unversioned_without_migrations() {
+        let format = SettingsFormat::Ron;
+        let migrations = Migrations::new();
+        let content = format.serialize(&ConfigV1 { size: 4 }).unwrap();
+
+        assert!(migrations.migrate(format, &content).unwrap().is_none());
+        assert_eq!(
+            migrations
+                .deserialize::<ConfigV1>(format, &content)
+                .unwrap(),
+            ConfigV1 { size: 4 }
+        );
+    }
+}
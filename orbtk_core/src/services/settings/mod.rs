@@ -0,0 +1,436 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use threadpool::*;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use dces::entity::Entity;
+
+use crate::widget_base::MessageAdapter;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::directory_storage::*;
+#[cfg(target_arch = "wasm32")]
+pub use self::local_storage::*;
+pub use self::memory_storage::*;
+pub use self::migrations::*;
+pub use self::settings_format::*;
+pub use self::settings_observer::*;
+pub use self::settings_storage::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::single_file_storage::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod directory_storage;
+#[cfg(target_arch = "wasm32")]
+mod local_storage;
+mod memory_storage;
+mod migrations;
+mod settings_format;
+mod settings_observer;
+mod settings_storage;
+#[cfg(not(target_arch = "wasm32"))]
+mod single_file_storage;
+
+/// Used to report meaningful error messages when handling `Settings`.
+#[derive(Debug)]
+pub enum SettingsError {
+    Saved(String),
+    Loaded(String),
+}
+
+/// Returns a value or the error message.
+pub type SettingsResult<T> = Result<T, SettingsError>;
+
+/// `Settings` represents a global settings service that could be use
+/// to serialize and deserialize data. Where and in which format the
+/// data is stored is defined by its `SettingsStorage`. By default
+/// settings are stored as `ron` files in the user settings directory
+/// (depending on the operating system) under the a folder with the
+/// given application name.
+///
+/// Settings with registered `Migrations` are saved with their version
+/// and migrated to the current version on load.
+///
+/// States could subscribe to settings with `subscribe` to receive a
+/// `SettingsChanged` message if the settings are saved by any window of
+/// the application or are changed outside of the application.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    app_name: String,
+    storage: Arc<dyn SettingsStorage>,
+    migrations: Arc<RwLock<HashMap<String, Arc<Migrations>>>>,
+    observer: SettingsObserver,
+    message_adapter: MessageAdapter,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pool: ThreadPool,
+}
+
+impl Settings {
+    /// Creates a new `Settings` service with an default name.
+    pub fn new(message_adapter: MessageAdapter) -> Self {
+        Settings::from_name("orbtk_app".into(), message_adapter)
+    }
+
+    /// Creates a new `Settings` service with the given app name.
+    pub fn from_name(app_name: String, message_adapter: MessageAdapter) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let storage = Arc::new(DirectoryStorage::from_name(app_name.as_str()));
+
+        #[cfg(target_arch = "wasm32")]
+        let storage = Arc::new(LocalStorage::new());
+
+        Settings::from_storage(app_name, storage, message_adapter)
+    }
+
+    /// Creates a new `Settings` service with the given app name, that reads and writes the
+    /// data with the given storage.
+    pub fn from_storage(
+        app_name: String,
+        storage: Arc<dyn SettingsStorage>,
+        message_adapter: MessageAdapter,
+    ) -> Self {
+        Settings {
+            app_name,
+            storage,
+            migrations: Arc::new(RwLock::new(HashMap::new())),
+            observer: SettingsObserver::new(),
+            message_adapter,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            pool: ThreadPool::new(4),
+        }
+    }
+
+    /// Builder method that sets the observer of the settings changes. Share the observer
+    /// with the settings services of other windows to notify their subscribers.
+    pub fn with_observer(mut self, observer: SettingsObserver) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    /// Gets the app name of the setting service.
+    pub fn app_name(&self) -> &str {
+        &*self.app_name
+    }
+
+    /// Gets the storage of the settings service.
+    pub fn storage(&self) -> &dyn SettingsStorage {
+        &*self.storage
+    }
+
+    /// Registers the migrations of the settings with the given key. The settings are saved
+    /// with the version of the migrations and old settings are migrated on load.
+    pub fn register_migrations(&self, key: impl Into<String>, migrations: Migrations) {
+        if let Ok(mut registered) = self.migrations.write() {
+            registered.insert(key.into(), Arc::new(migrations));
+        }
+    }
+
+    // gets the registered migrations of the given key
+    fn migrations(&self, key: &str) -> Option<Arc<Migrations>> {
+        self.migrations
+            .read()
+            .ok()
+            .and_then(|registered| registered.get(key).cloned())
+    }
+
+    /// Subscribes the given entity to the settings with the given key. The entity receives
+    /// a `SettingsChanged` message if the settings are saved by any window of the application
+    /// or are changed outside of the application.
+    pub fn subscribe(&self, key: impl Into<String>, entity: Entity) {
+        self.observer
+            .subscribe(&*self.storage, key, entity, self.message_adapter.clone());
+    }
+
+    /// Removes the subscription of the given entity to the settings with the given key.
+    pub fn unsubscribe(&self, key: &str, entity: Entity) {
+        self.observer.unsubscribe(key, entity);
+    }
+
+    /// Checks if subscribed settings are changed outside of the application. Called
+    /// periodically by the window.
+    pub fn poll(&self) {
+        self.observer.poll(&*self.storage);
+    }
+
+    /// Serialize the given data object to the storage. Sends
+    /// the result `Result<(), String>` as message to the given
+    /// entity.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_async<S: Serialize + Send + Sync + 'static>(
+        &self,
+        key: String,
+        data: S,
+        entity: Entity,
+    ) {
+        let storage = self.storage.clone();
+        let migrations = self.migrations(key.as_str());
+        let observer = self.observer.clone();
+        let message_adapter = self.message_adapter.clone();
+
+        self.pool.execute(move || {
+            let result = observer.write(&*storage, key.as_str(), || {
+                save(&*storage, migrations.as_deref(), key.as_str(), &data)
+            });
+
+            message_adapter.send_message(result, entity);
+        })
+    }
+
+    /// Loads and deserialize data from the storage. Send the
+    /// result `Result<D, String>` as message to the given entity.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_async<D: DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        key: String,
+        entity: Entity,
+    ) {
+        let storage = self.storage.clone();
+        let migrations = self.migrations(key.as_str());
+        let message_adapter = self.message_adapter.clone();
+
+        self.pool.execute(move || {
+            message_adapter.send_message(
+                load::<D>(&*storage, migrations.as_deref(), key.as_str()),
+                entity,
+            );
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn save_async<S: Serialize + Send + Sync + 'static>(
+        &self,
+        key: String,
+        data: S,
+        entity: Entity,
+    ) {
+        self.message_adapter
+            .send_message(self.save(key.as_str(), &data), entity);
+    }
+
+    /// Loads and deserialize data from the storage. Send the
+    /// result `Result<D, String>` as message to the given entity.
+    #[cfg(target_arch = "wasm32")]
+    pub fn load_async<D: DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        key: String,
+        entity: Entity,
+    ) {
+        self.message_adapter.send_message(
+            load::<D>(
+                &*self.storage,
+                self.migrations(key.as_str()).as_deref(),
+                key.as_str(),
+            ),
+            entity,
+        );
+    }
+
+    /// Serialize the given data object to the storage and notifies the
+    /// subscribers of the key.
+    pub fn save<S: Serialize>(&self, key: &str, data: &S) -> SettingsResult<()> {
+        self.observer.write(&*self.storage, key, || {
+            save(&*self.storage, self.migrations(key).as_deref(), key, data)
+        })
+    }
+
+    /// Loads and deserialize data from the storage. Old settings with
+    /// registered migrations are migrated and saved with the current version.
+    pub fn load<D: DeserializeOwned>(&self, key: &str) -> SettingsResult<D> {
+        load(&*self.storage, self.migrations(key).as_deref(), key)
+    }
+}
+
+// --- Helper --
+
+fn save<S: Serialize>(
+    storage: &dyn SettingsStorage,
+    migrations: Option<&Migrations>,
+    key: &str,
+    data: &S,
+) -> SettingsResult<()> {
+    let content = match migrations {
+        Some(migrations) => migrations.serialize(storage.format(), data)?,
+        None => storage.format().serialize(data)?,
+    };
+
+    storage.write(key, content.as_str())
+}
+
+fn load<D: DeserializeOwned>(
+    storage: &dyn SettingsStorage,
+    migrations: Option<&Migrations>,
+    key: &str,
+) -> SettingsResult<D> {
+    let content = storage.read(key)?;
+
+    let migrations = match migrations {
+        Some(migrations) => migrations,
+        None => return storage.format().deserialize(content.as_str()),
+    };
+
+    match migrations.migrate(storage.format(), content.as_str())? {
+        Some(migrated) => {
+            // the settings are upgraded even if the data could not be saved
+            let _ = storage.write(key, migrated.as_str());
+            migrations.deserialize(storage.format(), migrated.as_str())
+        }
+        None => migrations.deserialize(storage.format(), content.as_str()),
+    }
+}
+
+// writes the content to a temporary file that replaces the file, so the file is never written
+// partially. If backup is set, the previous file is kept as `<file>.bak`.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_atomic(path: &Path, content: &str, backup: bool) -> io::Result<()> {
+    static TEMP_FILES: AtomicUsize = AtomicUsize::new(0);
+
+    let file_name = path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        TEMP_FILES.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(content.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| {
+            if backup && path.exists() {
+                fs::copy(path, backup_path(path))?;
+            }
+
+            fs::rename(&temp_path, path)
+        });
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+// path of the backup of the given file
+#[cfg(not(target_arch = "wasm32"))]
+fn backup_path(path: &Path) -> PathBuf {
+    let mut backup_path = path.as_os_str().to_owned();
+    backup_path.push(".bak");
+    PathBuf::from(backup_path)
+}
+
+// --- Helper --
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use serde_derive::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Data {
+        volume: u32,
+        name: String,
+    }
+
+    fn data() -> Data {
+        Data {
+            volume: 7,
+            name: "orbtk".into(),
+        }
+    }
+
+    #[test]
+    fn test_memory_storage() {
+        let (sender, _receiver) = mpsc::channel();
+        let storage = MemoryStorage::new();
+        let settings = Settings::from_storage(
+            "test".into(),
+            Arc::new(storage.clone()),
+            MessageAdapter::new(sender),
+        );
+
+        assert!(settings.load::<Data>("data").is_err());
+        assert!(settings.save("data", &data()).is_ok());
+        assert_eq!(settings.load::<Data>("data").unwrap(), data());
+        assert_eq!(storage.keys(), vec!["data".to_string()]);
+    }
+
+    #[test]
+    fn test_single_file_storage() {
+        let path = std::env::temp_dir().join(format!(
+            "orbtk_single_file_storage_{}.ron",
+            std::process::id()
+        ));
+        let storage = SingleFileStorage::new(&path);
+
+        assert!(save(&storage, None, "data", &data()).is_ok());
+        assert!(save(&storage, None, "volume", &3).is_ok());
+        assert_eq!(load::<Data>(&storage, None, "data").unwrap(), data());
+        assert_eq!(load::<u32>(&storage, None, "volume").unwrap(), 3);
+        assert!(load::<u32>(&storage, None, "other").is_err());
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_migrations() {
+        let (sender, _receiver) = mpsc::channel();
+        let storage = MemoryStorage::new();
+        let settings = Settings::from_storage(
+            "test".into(),
+            Arc::new(storage.clone()),
+            MessageAdapter::new(sender),
+        );
+
+        assert!(settings.save("volume", &7_u32).is_ok());
+
+        settings.register_migrations("volume", Migrations::new().migration(|v1: u32| data_v2(v1)));
+
+        assert_eq!(settings.load::<Data>("volume").unwrap(), data_v2(7));
+        assert!(storage.get("volume").unwrap().contains("version: 2"));
+        assert_eq!(settings.load::<Data>("volume").unwrap(), data_v2(7));
+    }
+
+    fn data_v2(volume: u32) -> Data {
+        Data {
+            volume,
+            name: "v2".into(),
+        }
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let path =
+            std::env::temp_dir().join(format!("orbtk_write_atomic_{}.ron", std::process::id()));
+
+        assert!(write_atomic(&path, "1", true).is_ok());
+        assert!(write_atomic(&path, "2", true).is_ok());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2");
+        assert_eq!(fs::read_to_string(backup_path(&path)).unwrap(), "1");
+
+        let _ = fs::remove_file(backup_path(&path));
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,82 @@
+use ron::ser::{to_string_pretty, PrettyConfig};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{SettingsError, SettingsResult};
+
+/// Describes the file format that is used to serialize and deserialize settings.
+///
+/// `Json` requires the `json` feature and `Toml` the `toml` feature. Toml could only serialize
+/// structs and maps as top level value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SettingsFormat {
+    /// Rusty object notation, see [ron](https://github.com/ron-rs/ron).
+    Ron,
+
+    /// JavaScript object notation.
+    #[cfg(feature = "json")]
+    Json,
+
+    /// Tom's obvious, minimal language.
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl Default for SettingsFormat {
+    fn default() -> Self {
+        SettingsFormat::Ron
+    }
+}
+
+impl SettingsFormat {
+    /// Gets the file extension of the format e.g. `ron`.
+    pub fn extension(self) -> &'static str {
+        match self {
+            SettingsFormat::Ron => "ron",
+            #[cfg(feature = "json")]
+            SettingsFormat::Json => "json",
+            #[cfg(feature = "toml")]
+            SettingsFormat::Toml => "toml",
+        }
+    }
+
+    /// Serializes the given data object to a string of the format.
+    pub fn serialize<S: Serialize>(self, data: &S) -> SettingsResult<String> {
+        let result = match self {
+            SettingsFormat::Ron => {
+                to_string_pretty(data, PrettyConfig::default()).map_err(|e| e.to_string())
+            }
+            #[cfg(feature = "json")]
+            SettingsFormat::Json => serde_json::to_string_pretty(data).map_err(|e| e.to_string()),
+            #[cfg(feature = "toml")]
+            SettingsFormat::Toml => toml::to_string_pretty(data).map_err(|e| e.to_string()),
+        };
+
+        result.map_err(|e| {
+            SettingsError::Saved(format!(
+                "Settings.save: Could not serialize data as {}: {}",
+                self.extension(),
+                e
+            ))
+        })
+    }
+
+    /// Deserializes a data object from the given string of the format.
+    pub fn deserialize<D: DeserializeOwned>(self, content: &str) -> SettingsResult<D> {
+        let result = match self {
+            SettingsFormat::Ron => ron::de::from_str(content).map_err(|e| e.to_string()),
+            #[cfg(feature = "json")]
+            SettingsFormat::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+            #[cfg(feature = "toml")]
+            SettingsFormat::Toml => toml::from_str(content).map_err(|e| e.to_string()),
+        };
+
+        result.map_err(|e| {
+            SettingsError::Loaded(format!(
+                "Settings.load: Could not deserialize data from {}: {}",
+                self.extension(),
+                e
+            ))
+        })
+    }
+}
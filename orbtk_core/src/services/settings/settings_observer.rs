@@ -0,0 +1,378 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime},
+};
+
+use dces::entity::Entity;
+
+use crate::widget_base::MessageAdapter;
+
+use super::SettingsStorage;
+
+/// Message that is sent to the subscribed entities if the settings with the key are saved by
+/// a window of the application or are changed outside of the application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingsChanged {
+    /// The key of the changed settings.
+    pub key: String,
+}
+
+#[derive(Debug)]
+struct Subscription {
+    key: String,
+    entity: Entity,
+    message_adapter: MessageAdapter,
+}
+
+// last known modification of settings, used to notice changes outside of the application
+#[derive(Debug)]
+struct Stamp {
+    modified: Option<SystemTime>,
+    content: Option<String>,
+}
+
+impl Stamp {
+    fn read(storage: &dyn SettingsStorage, key: &str) -> Self {
+        Stamp {
+            modified: storage.modified(key),
+            content: storage.read(key).ok(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Observers {
+    subscriptions: Vec<Subscription>,
+    stamps: HashMap<String, Stamp>,
+    // number of running writes per key, their changes are not taken as outside changes
+    writing: HashMap<String, usize>,
+    poll_interval: Duration,
+    last_poll: Option<Instant>,
+}
+
+/// Keeps the subscriptions to settings changes. The observer is shared by the `Settings`
+/// services of all windows of an application, so each subscribed entity is notified with a
+/// `SettingsChanged` message if one of the windows saves the settings.
+///
+/// # Example
+///
+/// ```rust
+/// impl State for SettingsViewState {
+///     fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+///         registry.get::<Settings>("settings").subscribe("config", ctx.entity());
+///     }
+///
+///     fn messages(&mut self, mut messages: MessageReader, registry: &mut Registry, ctx: &mut Context) {
+///         for message in messages.read::<SettingsChanged>() {
+///             registry.get::<Settings>("settings").load_async::<Config>(message.key, ctx.entity());
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SettingsObserver {
+    observers: Arc<Mutex<Observers>>,
+}
+
+impl Default for SettingsObserver {
+    fn default() -> Self {
+        SettingsObserver {
+            observers: Arc::new(Mutex::new(Observers {
+                subscriptions: vec![],
+                stamps: HashMap::new(),
+                writing: HashMap::new(),
+                poll_interval: Duration::from_secs(1),
+                last_poll: None,
+            })),
+        }
+    }
+}
+
+impl SettingsObserver {
+    /// Creates a new observer without subscriptions.
+    pub fn new() -> Self {
+        SettingsObserver::default()
+    }
+
+    /// Subscribes the given entity to the changes of the settings with the given key. The
+    /// `SettingsChanged` message is sent with the given message adapter.
+    pub fn subscribe(
+        &self,
+        storage: &dyn SettingsStorage,
+        key: impl Into<String>,
+        entity: Entity,
+        message_adapter: MessageAdapter,
+    ) {
+        let key = key.into();
+
+        if let Ok(mut observers) = self.observers.lock() {
+            if !observers.stamps.contains_key(&key) {
+                let stamp = Stamp::read(storage, &key);
+                observers.stamps.insert(key.clone(), stamp);
+            }
+
+            observers.subscriptions.push(Subscription {
+                key,
+                entity,
+                message_adapter,
+            });
+        }
+    }
+
+    /// Removes the subscription of the given entity to the settings with the given key.
+    pub fn unsubscribe(&self, key: &str, entity: Entity) {
+        if let Ok(mut observers) = self.observers.lock() {
+            observers
+                .subscriptions
+                .retain(|subscription| subscription.key != key || subscription.entity != entity);
+        }
+    }
+
+    /// Notifies all entities that are subscribed to the settings with the given key.
+    pub fn notify(&self, storage: &dyn SettingsStorage, key: &str) {
+        if let Ok(mut observers) = self.observers.lock() {
+            notify(&mut observers, storage, key);
+        }
+    }
+
+    /// Writes the settings with the given key by calling `write` and notifies the subscribed
+    /// entities if it succeeds. The write is marked as running while `write` is called without
+    /// locking the observer, so a concurrent `poll` doesn't take the write as outside change and
+    /// each write sends exactly one `SettingsChanged` message.
+    pub fn write<T, E>(
+        &self,
+        storage: &dyn SettingsStorage,
+        key: &str,
+        write: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, E> {
+        if let Ok(mut observers) = self.observers.lock() {
+            *observers.writing.entry(key.to_string()).or_insert(0) += 1;
+        }
+
+        let result = write();
+
+        if let Ok(mut observers) = self.observers.lock() {
+            if let Some(count) = observers.writing.get_mut(key) {
+                *count -= 1;
+
+                if *count == 0 {
+                    observers.writing.remove(key);
+                }
+            }
+
+            if result.is_ok() {
+                notify(&mut observers, storage, key);
+            }
+        }
+
+        result
+    }
+
+    /// Checks after the poll interval if subscribed settings are changed outside of the
+    /// application and notifies the subscribed entities. If the observer is locked by another
+    /// window the check is skipped, so the calling thread is never blocked.
+    pub fn poll(&self, storage: &dyn SettingsStorage) {
+        let mut observers = match self.observers.try_lock() {
+            Ok(observers) => observers,
+            Err(_) => return,
+        };
+
+        let now = Instant::now();
+
+        if let Some(last_poll) = observers.last_poll {
+            if now.duration_since(last_poll) < observers.poll_interval {
+                return;
+            }
+        }
+
+        observers.last_poll = Some(now);
+
+        let mut changed = vec![];
+        let Observers {
+            stamps, writing, ..
+        } = &mut *observers;
+
+        for (key, stamp) in stamps.iter_mut() {
+            // the stamp is updated when the write is finished
+            if writing.contains_key(key) || storage.modified(key) == stamp.modified {
+                continue;
+            }
+
+            let new_stamp = Stamp::read(storage, key);
+
+            // e.g. a single file storage is modified by the settings of another key
+            if new_stamp.content != stamp.content {
+                changed.push(key.clone());
+            }
+
+            *stamp = new_stamp;
+        }
+
+        for key in changed {
+            send(&mut observers, key.as_str());
+        }
+    }
+}
+
+// --- Helper --
+
+// updates the stamp of the written settings and sends the change message to the subscribers
+fn notify(observers: &mut Observers, storage: &dyn SettingsStorage, key: &str) {
+    if observers.stamps.contains_key(key) {
+        observers
+            .stamps
+            .insert(key.to_string(), Stamp::read(storage, key));
+    }
+
+    send(observers, key);
+}
+
+// sends the change message to the subscribers of the key, subscriptions of closed windows are removed
+fn send(observers: &mut Observers, key: &str) {
+    observers.subscriptions.retain(|subscription| {
+        subscription.key != key
+            || subscription.message_adapter.try_send_message(
+                SettingsChanged {
+                    key: key.to_string(),
+                },
+                subscription.entity,
+            )
+    });
+
+    let subscriptions = &observers.subscriptions;
+    observers.stamps.retain(|key, _| {
+        subscriptions
+            .iter()
+            .any(|subscription| &subscription.key == key)
+    });
+}
+
+// --- Helper --
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{mpsc, Arc};
+
+    use super::*;
+    use crate::services::{MemoryStorage, Settings, SettingsError};
+
+    #[test]
+    fn test_notify_other_window() {
+        let storage = Arc::new(MemoryStorage::new());
+        let observer = SettingsObserver::new();
+
+        let (sender, _receiver) = mpsc::channel();
+        let first =
+            Settings::from_storage("test".into(), storage.clone(), MessageAdapter::new(sender))
+                .with_observer(observer.clone());
+
+        let (sender, _second_receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender);
+        let second = Settings::from_storage("test".into(), storage, message_adapter.clone())
+            .with_observer(observer);
+
+        second.subscribe("config", Entity(1));
+        first.save("other", &1).unwrap();
+        assert!(message_adapter.is_empty());
+
+        first.save("config", &1).unwrap();
+        let messages: Vec<SettingsChanged> = message_adapter
+            .message_reader(Entity(1))
+            .read::<SettingsChanged>()
+            .collect();
+        assert_eq!(
+            messages,
+            vec![SettingsChanged {
+                key: "config".into()
+            }]
+        );
+
+        second.unsubscribe("config", Entity(1));
+        first.save("config", &2).unwrap();
+        assert!(message_adapter.is_empty());
+    }
+
+    fn read_messages(message_adapter: &MessageAdapter) -> Vec<SettingsChanged> {
+        message_adapter
+            .message_reader(Entity(1))
+            .read::<SettingsChanged>()
+            .collect()
+    }
+
+    #[test]
+    fn test_poll_outside_change() {
+        let storage = MemoryStorage::new();
+        let observer = SettingsObserver::new();
+        observer.observers.lock().unwrap().poll_interval = Duration::from_secs(0);
+
+        let (sender, _receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender);
+        observer.subscribe(&storage, "config", Entity(1), message_adapter.clone());
+
+        observer.poll(&storage);
+        assert!(message_adapter.is_empty());
+
+        // e.g. another process changes the settings
+        storage.write("config", "1").unwrap();
+        observer.poll(&storage);
+        assert_eq!(
+            read_messages(&message_adapter),
+            vec![SettingsChanged {
+                key: "config".into()
+            }]
+        );
+
+        // modified without changing the content
+        storage.write("config", "1").unwrap();
+        observer.poll(&storage);
+        assert!(message_adapter.is_empty());
+
+        // written by the application
+        observer
+            .write(&storage, "config", || storage.write("config", "2"))
+            .unwrap();
+        observer.poll(&storage);
+        assert_eq!(read_messages(&message_adapter).len(), 1);
+    }
+
+    #[test]
+    fn test_poll_during_write() {
+        let storage = MemoryStorage::new();
+        let observer = SettingsObserver::new();
+        observer.observers.lock().unwrap().poll_interval = Duration::from_secs(0);
+
+        let (sender, _receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender);
+        observer.subscribe(&storage, "config", Entity(1), message_adapter.clone());
+
+        // e.g. the window polls while the thread pool writes the settings
+        observer
+            .write(&storage, "config", || {
+                storage.write("config", "1")?;
+                observer.poll(&storage);
+                assert!(message_adapter.is_empty());
+                Ok::<_, SettingsError>(())
+            })
+            .unwrap();
+
+        assert_eq!(read_messages(&message_adapter).len(), 1);
+        assert!(observer.observers.lock().unwrap().writing.is_empty());
+
+        observer.poll(&storage);
+        assert!(message_adapter.is_empty());
+    }
+
+    #[test]
+    fn test_closed_window() {
+        let storage = MemoryStorage::new();
+        let observer = SettingsObserver::new();
+
+        let (sender, receiver) = mpsc::channel();
+        observer.subscribe(&storage, "config", Entity(1), MessageAdapter::new(sender));
+        drop(receiver);
+
+        observer.notify(&storage, "config");
+        assert!(observer.observers.lock().unwrap().subscriptions.is_empty());
+    }
+}
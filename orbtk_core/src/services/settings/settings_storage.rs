@@ -0,0 +1,26 @@
+use std::{fmt::Debug, time::SystemTime};
+
+use super::{SettingsFormat, SettingsResult};
+
+/// Describes where the serialized settings are stored. Each storage stores the settings by key
+/// in the format that is returned by `format`.
+///
+/// The storage of the `Settings` service could be replaced with `Application::settings_storage`
+/// e.g. to use a `MemoryStorage` in tests.
+pub trait SettingsStorage: Debug + Send + Sync {
+    /// Gets the format that is used to serialize the settings.
+    fn format(&self) -> SettingsFormat;
+
+    /// Reads the serialized settings with the given key.
+    fn read(&self, key: &str) -> SettingsResult<String>;
+
+    /// Writes the serialized settings with the given key.
+    fn write(&self, key: &str, content: &str) -> SettingsResult<()>;
+
+    /// Gets the time of the last modification of the settings with the given key, used to
+    /// notice settings that are changed outside of the application. Returns `None` if the
+    /// storage could not be changed from outside.
+    fn modified(&self, _key: &str) -> Option<SystemTime> {
+        None
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+use ron::ser::{to_string_pretty, PrettyConfig};
+
+use super::{write_atomic, SettingsError, SettingsFormat, SettingsResult, SettingsStorage};
+
+/// Stores the settings of all keys in one `ron` file. The file contains a map of the keys and
+/// the settings serialized in the given format.
+///
+/// ```ron
+/// {
+///     "window": "(width: 800, height: 600)",
+/// }
+/// ```
+///
+/// The file is written to a temporary file first, that replaces the file, so a crash while
+/// saving doesn't corrupt the settings.
+#[derive(Debug)]
+pub struct SingleFileStorage {
+    path: PathBuf,
+    format: SettingsFormat,
+    backup: bool,
+    lock: Mutex<()>,
+}
+
+impl SingleFileStorage {
+    /// Creates a new storage for the given file, the settings are serialized as `ron`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        SingleFileStorage::from_format(path, SettingsFormat::Ron)
+    }
+
+    /// Creates a new storage for the given file, the settings are serialized in the given format.
+    pub fn from_format(path: impl Into<PathBuf>, format: SettingsFormat) -> Self {
+        SingleFileStorage {
+            path: path.into(),
+            format,
+            backup: false,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// If set to `true` the previous file is kept as backup with the extension `.bak` on save.
+    pub fn backup(mut self, backup: bool) -> Self {
+        self.backup = backup;
+        self
+    }
+
+    /// Gets the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // reads the map of all settings, a missing file is handled as empty map
+    fn read_entries(&self) -> Result<BTreeMap<String, String>, String> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let content = fs::read_to_string(&self.path).map_err(|e| e.to_string())?;
+        ron::de::from_str(&content).map_err(|e| e.to_string())
+    }
+}
+
+impl SettingsStorage for SingleFileStorage {
+    fn format(&self) -> SettingsFormat {
+        self.format
+    }
+
+    fn read(&self, key: &str) -> SettingsResult<String> {
+        let _lock = self.lock.lock();
+
+        let entries = self.read_entries().map_err(|e| {
+            SettingsError::Loaded(format!(
+                "Settings.load: Could not read config file {:?}: {}",
+                self.path, e
+            ))
+        })?;
+
+        entries.get(key).cloned().ok_or_else(|| {
+            SettingsError::Loaded(format!(
+                "Settings.load: Could not find settings with key {} in config file {:?}",
+                key, self.path
+            ))
+        })
+    }
+
+    fn write(&self, key: &str, content: &str) -> SettingsResult<()> {
+        let _lock = self.lock.lock();
+
+        let mut entries = self.read_entries().map_err(|e| {
+            SettingsError::Saved(format!(
+                "Settings.save: Could not read config file {:?}: {}",
+                self.path, e
+            ))
+        })?;
+        entries.insert(key.to_string(), content.to_string());
+
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() && fs::create_dir_all(parent).is_err() {
+                return Err(SettingsError::Saved(format!(
+                    "Settings.save: Could not create settings dir {:?}",
+                    parent
+                )));
+            }
+        }
+
+        to_string_pretty(&entries, PrettyConfig::default())
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                write_atomic(&self.path, content.as_str(), self.backup).map_err(|e| e.to_string())
+            })
+            .map_err(|e| {
+                SettingsError::Saved(format!(
+                    "Settings.save: Could not write to config file {:?}: {}",
+                    self.path, e
+                ))
+            })
+    }
+
+    fn modified(&self, _key: &str) -> Option<SystemTime> {
+        fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+}
@@ -39,6 +39,12 @@ impl System<Tree, RenderContext2D> for InitSystem {
             .unwrap()
             .clone();
 
+        // the theme is applied on build before a widget is added to its parent,
+        // so rules like `toolbar button` could only be resolved on the complete tree
+        if theme.has_ancestor_rules() {
+            WidgetContainer::new(root, ecm, &theme, None).update(true);
+        }
+
         let mut current_node = root;
 
         loop {
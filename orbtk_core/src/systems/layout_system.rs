@@ -1,6 +1,12 @@
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, tree::Tree, utils::*};
+use crate::{
+    layout::{begin_layout_pass, end_layout_pass, take_relayout},
+    prelude::*,
+    render::RenderContext2D,
+    tree::Tree,
+    utils::*,
+};
 
 /// The `LayoutSystem` takes care to rebuild the layout of the current
 /// `UI` on a per iteration cycle. `layout widgets` are organized in
@@ -43,22 +49,20 @@ impl System<Tree, RenderContext2D> for LayoutSystem {
             .unwrap()
             .clone();
 
-        self.context_provider.layouts.borrow()[&root].measure(
-            render_context,
-            root,
-            ecm,
-            &self.context_provider.layouts.borrow(),
-            &theme,
-        );
+        let layouts = self.context_provider.layouts.borrow();
 
-        self.context_provider.layouts.borrow()[&root].arrange(
-            render_context,
-            window_size,
-            root,
-            ecm,
-            &self.context_provider.layouts.borrow(),
-            &theme,
-        );
+        begin_layout_pass();
+
+        layouts[&root].measure(render_context, root, ecm, &layouts, &theme);
+        layouts[&root].arrange(render_context, window_size, root, ecm, &layouts, &theme);
+
+        // e.g. wrapped text changed its size on arrange => the parents are measured again
+        if take_relayout() {
+            layouts[&root].measure(render_context, root, ecm, &layouts, &theme);
+            layouts[&root].arrange(render_context, window_size, root, ecm, &layouts, &theme);
+        }
+
+        end_layout_pass();
 
         // if self.debug_flag.get() {
         //     println!("\n------ End layout update   ------\n");
@@ -1,5 +1,9 @@
 pub use self::style_config::*;
 pub use self::theme_config::*;
+pub use self::theme_error::*;
+pub use self::validation::*;
 
 mod style_config;
 mod theme_config;
+mod theme_error;
+mod validation;
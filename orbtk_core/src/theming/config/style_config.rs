@@ -7,7 +7,7 @@ use crate::theming::ThemeState;
 
 /// Defines a style. A style could be base on other styles and
 /// contains a list for properties and a list of state properties.
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StyleConfig {
     // set default string to base style
     #[serde(default)]
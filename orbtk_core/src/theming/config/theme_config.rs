@@ -3,14 +3,14 @@ use std::collections::HashMap;
 use ron::{de::from_str, Value};
 use serde_derive::{Deserialize, Serialize};
 
-use crate::theming::config::StyleConfig;
+use crate::theming::config::{StyleConfig, ThemeError};
 
 pub static BASE_STYLE: &str = "base";
 pub static RESOURCE_KEY: &str = "$";
 
 /// Used to store and read properties that could be requested by a
 /// given property name and a selector.
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename = "Theme")]
 pub struct ThemeConfig {
     #[serde(default)]
@@ -20,6 +20,15 @@ pub struct ThemeConfig {
 }
 
 impl<'a> ThemeConfig {
+    /// Parses a theme from the given RON string. On error the line and column of the error are returned.
+    pub fn parse(s: &str) -> Result<Self, ThemeError> {
+        from_str(s).map_err(|e| ThemeError::Parse {
+            line: e.position.line,
+            column: e.position.col,
+            message: e.code.to_string(),
+        })
+    }
+
     /// Extends the given theme with another theme. Replaces the
     /// current name with the new choosen name `other`.  If `other`
     /// contains a style with the same key entry, this key will be
@@ -37,10 +46,28 @@ impl<'a> ThemeConfig {
 
         self
     }
+
+    // returns the key of the style followed by the keys of its base styles. Stops before an unknown or an already visited base.
+    pub(crate) fn base_chain<'b>(&'b self, style_key: &'b str) -> Vec<&'b str> {
+        let mut chain = vec![];
+        let mut key = style_key;
+
+        while let Some(style) = self.styles.get(key) {
+            chain.push(key);
+
+            if style.base.is_empty() || chain.contains(&style.base.as_str()) {
+                break;
+            }
+
+            key = style.base.as_str();
+        }
+
+        chain
+    }
 }
 
 impl From<&str> for ThemeConfig {
     fn from(s: &str) -> Self {
-        from_str(s).unwrap()
+        ThemeConfig::parse(s).unwrap_or_else(|e| panic!("Could not parse theme: {}", e))
     }
 }
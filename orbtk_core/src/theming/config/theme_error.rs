@@ -0,0 +1,85 @@
+use std::fmt;
+
+/// Describes an error of a `ThemeConfig`, either found on parsing or on validation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ThemeError {
+    /// The theme is no valid RON. Line and column start with 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+
+    /// A property references a `$RESOURCE` that is not defined.
+    MissingResource {
+        style: String,
+        state: Option<String>,
+        property: String,
+        resource: String,
+    },
+
+    /// The key of a style is no valid selector.
+    InvalidSelector { style: String, message: String },
+
+    /// The base of a style is not defined.
+    UnknownBase { style: String, base: String },
+
+    /// The base styles of the listed styles reference each other.
+    CyclicBase { styles: Vec<String> },
+
+    /// The value of a property cannot be converted to the type of the widget property.
+    InvalidValue {
+        style: String,
+        state: Option<String>,
+        property: String,
+        message: String,
+    },
+}
+
+// formats the location of a property e.g. `button.hover.background`
+fn property_path(style: &str, state: &Option<String>, property: &str) -> String {
+    match state {
+        Some(state) => format!("{}.{}.{}", style, state, property),
+        None => format!("{}.{}", style, property),
+    }
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{}: {}", line, column, message),
+            ThemeError::MissingResource {
+                style,
+                state,
+                property,
+                resource,
+            } => write!(
+                f,
+                "{}: resource `{}` is not defined",
+                property_path(style, state, property),
+                resource
+            ),
+            ThemeError::InvalidSelector { style, message } => {
+                write!(f, "{}: invalid style key: {}", style, message)
+            }
+            ThemeError::UnknownBase { style, base } => {
+                write!(f, "{}: base style `{}` is not defined", style, base)
+            }
+            ThemeError::CyclicBase { styles } => {
+                write!(f, "cyclic base styles: {}", styles.join(" -> "))
+            }
+            ThemeError::InvalidValue {
+                style,
+                state,
+                property,
+                message,
+            } => write!(f, "{}: {}", property_path(style, state, property), message),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+
+use ron::Value;
+
+use crate::{
+    theming::{
+        config::{ThemeConfig, ThemeError, RESOURCE_KEY},
+        StyleRule,
+    },
+    utils::{BoxShadow, Expression},
+};
+
+// the widget property type a theme property is converted to
+enum PropertyKind {
+    Alignment,
+    BorderBrushes,
+    BoxShadow,
+    Brush,
+    CornerRadius,
+    Number,
+    Thickness,
+    Any,
+}
+
+// mirrors the property mapping of `WidgetContainer::update_widget`
+fn property_kind(key: &str) -> PropertyKind {
+    match key {
+        "h_align" | "v_align" => PropertyKind::Alignment,
+        "background" | "foreground" => PropertyKind::Brush,
+        "border_brushes" => PropertyKind::BorderBrushes,
+        "box_shadow" => PropertyKind::BoxShadow,
+        "border_width" | "padding" | "margin" => PropertyKind::Thickness,
+        "width" | "height" | "min_width" | "min_height" | "max_width" | "max_height"
+        | "padding_left" | "padding_top" | "padding_right" | "padding_bottom" | "font_size"
+        | "icon_size" | "spacing" | "opacity" => PropertyKind::Number,
+        _ if key.ends_with("_brush") || key.ends_with("_background") => PropertyKind::Brush,
+        _ if key.ends_with("_margin") || key.ends_with("_padding") => PropertyKind::Thickness,
+        _ if key.ends_with("_radius") => PropertyKind::CornerRadius,
+        _ if key.ends_with("_opacity") => PropertyKind::Number,
+        _ => PropertyKind::Any,
+    }
+}
+
+// returns an error message if the value could not be converted to the property type
+fn check_value(kind: PropertyKind, value: &Value) -> Option<String> {
+    let message = match kind {
+        PropertyKind::Alignment => match value {
+            Value::String(s)
+                if matches!(
+                    s.as_str(),
+                    "start" | "Start" | "center" | "Center" | "end" | "End" | "stretch" | "Stretch"
+                ) =>
+            {
+                return None
+            }
+            _ => "expected an alignment (`start`, `center`, `end` or `stretch`)",
+        },
+        PropertyKind::BorderBrushes => match value {
+            Value::Map(map) if map.iter().all(|(key, value)| is_side_brush(key, value)) => {
+                return None
+            }
+            _ => "expected a map of `left`, `top`, `right` and `bottom` brushes",
+        },
+        PropertyKind::BoxShadow => match value {
+            Value::String(s) if BoxShadow::parse(s).is_some() => return None,
+            _ => "expected a box shadow like `0 2 4 #40000000`",
+        },
+        PropertyKind::Brush => match value {
+            Value::String(s) if Expression::from(s.as_str()).brush().is_some() => return None,
+            _ => "expected a color or gradient",
+        },
+        PropertyKind::CornerRadius => match value {
+            Value::Number(_) => return None,
+            Value::Seq(values)
+                if values.len() == 4 && values.iter().all(|v| matches!(v, Value::Number(_))) =>
+            {
+                return None
+            }
+            Value::Map(map) if map.iter().all(|(key, value)| is_corner(key, value)) => return None,
+            _ => "expected a number, four numbers like `(4, 4, 0, 0)` or a map of corners",
+        },
+        PropertyKind::Number => match value {
+            Value::Number(_) => return None,
+            _ => "expected a number",
+        },
+        PropertyKind::Thickness => match value {
+            Value::Number(_) => return None,
+            Value::Map(map) if map.iter().all(|(key, value)| is_side(key, value)) => return None,
+            _ => "expected a number or a map of `left`, `top`, `right` and `bottom`",
+        },
+        PropertyKind::Any => return None,
+    };
+
+    Some(format!("{}, found `{}`", message, describe(value)))
+}
+
+// checks a side entry of a thickness map e.g. `"left": 4`
+fn is_side(key: &Value, value: &Value) -> bool {
+    let key = match key {
+        Value::String(key) => key.as_str(),
+        _ => return false,
+    };
+
+    matches!(key, "left" | "top" | "right" | "bottom") && matches!(value, Value::Number(_))
+}
+
+// checks a side entry of a border brushes map e.g. `"left": "#ff0000"`
+fn is_side_brush(key: &Value, value: &Value) -> bool {
+    let is_side = matches!(key, Value::String(key)
+        if matches!(key.as_str(), "left" | "top" | "right" | "bottom"));
+
+    is_side && matches!(value, Value::String(s) if Expression::from(s.as_str()).brush().is_some())
+}
+
+// checks a corner entry of a corner radius map e.g. `"top_left": 4`
+fn is_corner(key: &Value, value: &Value) -> bool {
+    let key = match key {
+        Value::String(key) => key.as_str(),
+        _ => return false,
+    };
+
+    matches!(
+        key,
+        "top_left" | "top_right" | "bottom_right" | "bottom_left"
+    ) && matches!(value, Value::Number(_))
+}
+
+fn describe(value: &Value) -> String {
+    ron::ser::to_string(value).unwrap_or_else(|_| String::from("?"))
+}
+
+fn check_properties(
+    config: &ThemeConfig,
+    style: &str,
+    state: Option<&str>,
+    properties: &HashMap<String, Value>,
+    errors: &mut Vec<ThemeError>,
+) {
+    for (property, value) in properties {
+        let value = match value {
+            Value::String(s) if s.starts_with(RESOURCE_KEY) => {
+                let resource = s.trim_start_matches(RESOURCE_KEY);
+
+                match config.resources.get(resource) {
+                    Some(value) => value,
+                    None => {
+                        errors.push(ThemeError::MissingResource {
+                            style: style.to_string(),
+                            state: state.map(String::from),
+                            property: property.clone(),
+                            resource: resource.to_string(),
+                        });
+                        continue;
+                    }
+                }
+            }
+            _ => value,
+        };
+
+        if let Some(message) = check_value(property_kind(property), value) {
+            errors.push(ThemeError::InvalidValue {
+                style: style.to_string(),
+                state: state.map(String::from),
+                property: property.clone(),
+                message,
+            });
+        }
+    }
+}
+
+impl ThemeConfig {
+    /// Checks the theme for missing resources, unknown base styles, cyclic base styles and
+    /// property values that could not be converted to the type of the widget property.
+    ///
+    /// Returns all found errors sorted by style.
+    pub fn validate(&self) -> Result<(), Vec<ThemeError>> {
+        let mut errors = vec![];
+        let mut cycles: Vec<Vec<String>> = vec![];
+
+        let mut keys: Vec<&String> = self.styles.keys().collect();
+        keys.sort();
+
+        for key in keys {
+            let style = &self.styles[key];
+
+            if let Err(message) = StyleRule::parse(key) {
+                errors.push(ThemeError::InvalidSelector {
+                    style: key.clone(),
+                    message,
+                });
+            }
+
+            let chain = self.base_chain(key);
+            let last = &self.styles[*chain.last().unwrap()].base;
+
+            if !last.is_empty() {
+                if let Some(pos) = chain.iter().position(|k| k == last) {
+                    // report each cycle once, starting with the smallest key
+                    let mut cycle: Vec<String> =
+                        chain[pos..].iter().map(|k| k.to_string()).collect();
+                    let min = (0..cycle.len()).min_by_key(|i| &cycle[*i]).unwrap();
+                    cycle.rotate_left(min);
+
+                    if !cycles.contains(&cycle) {
+                        cycles.push(cycle.clone());
+                        cycle.push(cycle[0].clone());
+                        errors.push(ThemeError::CyclicBase { styles: cycle });
+                    }
+                } else if chain.len() == 1 {
+                    errors.push(ThemeError::UnknownBase {
+                        style: key.clone(),
+                        base: last.clone(),
+                    });
+                }
+            }
+
+            check_properties(self, key, None, &style.properties, &mut errors);
+
+            for state in &style.states {
+                check_properties(
+                    self,
+                    key,
+                    Some(state.key.as_str()),
+                    &state.properties,
+                    &mut errors,
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Validates the given theme config and panics with a list of all errors. Used to test themes.
+///
+/// # Example
+///
+/// ```rust
+/// check_theme(&ThemeConfig::from(MY_THEME).extend(ThemeConfig::from(MY_THEME_COLORS)));
+/// ```
+pub fn check_theme(config: &ThemeConfig) {
+    if let Err(errors) = config.validate() {
+        let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        panic!("Invalid theme:\n{}", errors.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let error = ThemeConfig::parse("Theme (\n    styles: {\n        \"button\" (").unwrap_err();
+
+        match error {
+            ThemeError::Parse { line, .. } => assert_eq!(line, 3),
+            _ => panic!("expected parse error"),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = ThemeConfig::parse(
+            r##"Theme (
+    styles: {
+        "base": (
+            properties: {
+                "background": "#000000",
+            },
+        ),
+        "button": (
+            base: "base",
+            properties: {
+                "foreground": "$MISSING",
+                "padding": { "left": 4, "right": 4 },
+                "box_shadow": "0 2 4 #40000000",
+                "border_radius": (4, 4, 0, 0),
+                "border_brushes": { "left": "#ff0000", "bottom": "#00ff00" },
+            },
+            states: [
+                (
+                    key: "hover",
+                    properties: {
+                        "border_width": "wide",
+                        "box_shadow": "raised",
+                        "border_radius": (4, 4),
+                    },
+                ),
+            ],
+        ),
+        "label": (
+            base: "unknown",
+        ),
+        "a": (
+            base: "b",
+        ),
+        "b": (
+            base: "a",
+        ),
+        "c": (
+            base: "a",
+        ),
+    },
+)"##,
+        )
+        .unwrap();
+
+        let errors = config.validate().unwrap_err();
+
+        assert_eq!(errors.len(), 6);
+        assert_eq!(
+            errors[0],
+            ThemeError::CyclicBase {
+                styles: vec!["a".to_string(), "b".to_string(), "a".to_string()]
+            }
+        );
+        assert!(errors.contains(&ThemeError::MissingResource {
+            style: "button".to_string(),
+            state: None,
+            property: "foreground".to_string(),
+            resource: "MISSING".to_string()
+        }));
+        assert!(errors.iter().any(|e| matches!(e,
+            ThemeError::InvalidValue { style, state: Some(state), property, .. }
+                if style == "button" && state == "hover" && property == "border_width"
+        )));
+        assert!(errors.iter().any(|e| matches!(e,
+            ThemeError::InvalidValue { state: Some(_), property, .. } if property == "box_shadow"
+        )));
+        assert!(errors.iter().any(|e| matches!(e,
+            ThemeError::InvalidValue { state: Some(_), property, .. } if property == "border_radius"
+        )));
+        assert!(errors.contains(&ThemeError::UnknownBase {
+            style: "label".to_string(),
+            base: "unknown".to_string()
+        }));
+    }
+
+    #[test]
+    fn test_cyclic_base() {
+        let config = ThemeConfig::parse(
+            r#"Theme (
+    styles: {
+        "a": (
+            base: "b",
+            properties: {
+                "height": 32,
+            },
+        ),
+        "b": (
+            base: "a",
+        ),
+    },
+)"#,
+        )
+        .unwrap();
+
+        // must not loop endlessly
+        let theme = crate::theming::Theme::from_config(config);
+        assert!(theme.style("a").is_some());
+    }
+}
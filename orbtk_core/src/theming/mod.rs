@@ -3,11 +3,17 @@
 pub use self::config::*;
 pub use self::selector::*;
 pub use self::style::*;
+pub use self::style_rule::*;
 pub use self::theme::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::theme_loader::*;
 pub use self::theme_state::*;
 
 mod config;
 mod selector;
 mod style;
+mod style_rule;
 mod theme;
+#[cfg(not(target_arch = "wasm32"))]
+mod theme_loader;
 mod theme_state;
@@ -1,11 +1,17 @@
 use std::fmt;
 
+/// Separates the states of a compound state key e.g. `hover+focused`.
+pub static STATE_SEPARATOR: char = '+';
+
 /// The selector is used to read a property value from the `Theme`.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Selector {
     /// Represents the key of a style.
     pub style: Option<String>,
 
+    /// Represents the id of the widget, used by id selectors like `#save_button`.
+    pub id: Option<String>,
+
     // Used to reference the state property list of the given
     // style. The state on the top of the vector is the active one.
     states: Vec<String>,
@@ -19,6 +25,7 @@ impl Selector {
     pub fn new(style: impl Into<String>) -> Self {
         Selector {
             style: Some(style.into()),
+            id: None,
             states: vec![],
             dirty: true,
         }
@@ -87,6 +94,15 @@ impl Selector {
     pub fn has_state(&self, state: &str) -> bool {
         self.states.contains(&state.to_string())
     }
+
+    /// Checks if the given state key of a style matches the active states. A compound key
+    /// like `hover+focused` matches only if all of its states are active.
+    pub fn matches_state(&self, key: &str) -> bool {
+        !key.is_empty()
+            && key
+                .split(STATE_SEPARATOR)
+                .all(|state| self.has_state(state.trim()))
+    }
 }
 
 impl fmt::Display for Selector {
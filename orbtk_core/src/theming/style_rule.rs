@@ -0,0 +1,226 @@
+use crate::theming::{Selector, Style};
+
+/// Prefix of an id in a style key e.g. `#save_button`.
+pub static ID_KEY: char = '#';
+
+/// Separates a parent from a child in a style key e.g. `toolbar > button`.
+pub static CHILD_KEY: char = '>';
+
+// Matches the style and / or the id of one widget e.g. `button#save_button`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct SimpleSelector {
+    style: Option<String>,
+    id: Option<String>,
+}
+
+impl SimpleSelector {
+    fn parse(s: &str) -> Result<Self, String> {
+        let (style, id) = match s.find(ID_KEY) {
+            Some(pos) => (&s[..pos], Some(&s[pos + 1..])),
+            None => (s, None),
+        };
+
+        let is_name = |name: &str| {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        };
+
+        if !style.is_empty() && !is_name(style) {
+            return Err(format!("`{}` is not a valid style name", style));
+        }
+
+        if let Some(id) = id {
+            if !is_name(id) {
+                return Err(format!("`{}` is not a valid id", id));
+            }
+        }
+
+        Ok(SimpleSelector {
+            style: Some(style.to_string()).filter(|s| !s.is_empty()),
+            id: id.map(String::from),
+        })
+    }
+
+    fn matches(&self, selector: &Selector) -> bool {
+        (self.style.is_none() || self.style == selector.style)
+            && (self.id.is_none() || self.id == selector.id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+/// A style that is selected by a style key with ids, parent or ancestor styles like
+/// `#save_button`, `toolbar button` or `toolbar > button#save_button`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StyleRule {
+    key: String,
+    target: SimpleSelector,
+
+    // ancestors from the nearest to the farthest
+    ancestors: Vec<(Combinator, SimpleSelector)>,
+
+    /// The style of the rule.
+    pub style: Style,
+}
+
+impl StyleRule {
+    /// Parses a style key. Returns `None` for the key of a plain style like `button`.
+    pub fn parse(style_key: &str) -> Result<Option<Self>, String> {
+        let key = style_key.replace(CHILD_KEY, &format!(" {} ", CHILD_KEY));
+
+        // combinators[i] is placed between selectors[i] and selectors[i + 1]
+        let mut selectors = vec![];
+        let mut combinators = vec![];
+
+        for token in key.split_whitespace() {
+            if token.starts_with(CHILD_KEY) {
+                if selectors.len() != combinators.len() + 1 {
+                    return Err(format!("misplaced `{}`", CHILD_KEY));
+                }
+
+                combinators.push(Combinator::Child);
+                continue;
+            }
+
+            if selectors.len() > combinators.len() {
+                combinators.push(Combinator::Descendant);
+            }
+
+            selectors.push(SimpleSelector::parse(token)?);
+        }
+
+        if selectors.is_empty() {
+            return Err("empty style key".to_string());
+        }
+
+        if selectors.len() == combinators.len() {
+            return Err(format!("misplaced `{}`", CHILD_KEY));
+        }
+
+        let target = selectors.pop().unwrap();
+
+        if selectors.is_empty() && target.id.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(StyleRule {
+            key: style_key.to_string(),
+            target,
+            ancestors: combinators.into_iter().zip(selectors).rev().collect(),
+            style: Style::new(),
+        }))
+    }
+
+    /// Gets the style key of the rule.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Gets the number of ids and the number of styles in the key, used to sort rules by
+    /// specificity. A plain style has a specificity of `(0, 1)`.
+    pub fn specificity(&self) -> (usize, usize) {
+        let simple = |s: &SimpleSelector| (s.id.is_some() as usize, s.style.is_some() as usize);
+        self.ancestors
+            .iter()
+            .map(|(_, s)| simple(s))
+            .fold(simple(&self.target), |(ids, styles), (i, s)| {
+                (ids + i, styles + s)
+            })
+    }
+
+    /// Returns `true` if the rule has to be checked against the ancestors of a widget.
+    pub fn has_ancestors(&self) -> bool {
+        !self.ancestors.is_empty()
+    }
+
+    /// Checks if the rule could match the selector of a widget, without checking its ancestors.
+    pub fn matches_target(&self, selector: &Selector) -> bool {
+        self.target.matches(selector)
+    }
+
+    /// Checks if the rule matches the selector of a widget. The ancestors are the selectors
+    /// of the parents of the widget, from the nearest to the farthest.
+    pub fn matches(&self, selector: &Selector, ancestors: &[Selector]) -> bool {
+        self.matches_target(selector) && match_ancestors(&self.ancestors, ancestors)
+    }
+}
+
+fn match_ancestors(parts: &[(Combinator, SimpleSelector)], ancestors: &[Selector]) -> bool {
+    let (combinator, simple) = match parts.first() {
+        Some(part) => part,
+        None => return true,
+    };
+
+    match combinator {
+        Combinator::Child => match ancestors.first() {
+            Some(parent) => simple.matches(parent) && match_ancestors(&parts[1..], &ancestors[1..]),
+            None => false,
+        },
+        Combinator::Descendant => (0..ancestors.len()).any(|i| {
+            simple.matches(&ancestors[i]) && match_ancestors(&parts[1..], &ancestors[i + 1..])
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector(style: &str, id: Option<&str>) -> Selector {
+        let mut selector = Selector::new(style);
+        selector.id = id.map(String::from);
+        selector
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(StyleRule::parse("button"), Ok(None));
+        assert!(StyleRule::parse("#save").unwrap().is_some());
+        assert!(StyleRule::parse("toolbar >").is_err());
+        assert!(StyleRule::parse("> button").is_err());
+        assert!(StyleRule::parse("toolbar > > button").is_err());
+        assert!(StyleRule::parse("tool.bar button").is_err());
+        assert!(StyleRule::parse("button#").is_err());
+
+        assert_eq!(
+            StyleRule::parse("#save").unwrap().unwrap().specificity(),
+            (1, 0)
+        );
+        assert_eq!(
+            StyleRule::parse("window toolbar>button#save")
+                .unwrap()
+                .unwrap()
+                .specificity(),
+            (1, 3)
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let button = selector("button", Some("save"));
+        let toolbar = selector("toolbar", None);
+        let stack = selector("stack", None);
+        let window = selector("window", None);
+
+        let rule = |key: &str| StyleRule::parse(key).unwrap().unwrap();
+
+        assert!(rule("#save").matches(&button, &[]));
+        assert!(rule("button#save").matches(&button, &[]));
+        assert!(!rule("check_box#save").matches(&button, &[]));
+
+        let ancestors = [stack, toolbar, window];
+        assert!(rule("toolbar button").matches(&button, &ancestors));
+        assert!(rule("window toolbar button").matches(&button, &ancestors));
+        assert!(!rule("toolbar window button").matches(&button, &ancestors));
+        assert!(!rule("toolbar > button").matches(&button, &ancestors));
+        assert!(rule("toolbar > stack > button").matches(&button, &ancestors));
+        assert!(rule("window stack > #save").matches(&button, &ancestors));
+        assert!(!rule("window > stack button").matches(&button, &ancestors));
+    }
+}
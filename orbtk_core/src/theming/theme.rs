@@ -1,32 +1,180 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use ron::Value;
 
-use crate::theming::{
-    config::{ThemeConfig, RESOURCE_KEY},
-    Selector, Style, ThemeState,
+use crate::{
+    render::Image,
+    theming::{
+        config::{ThemeConfig, RESOURCE_KEY},
+        Selector, Style, StyleRule, ThemeState,
+    },
 };
 
 /// Theme is used to read properties for a given selector with an
 /// internal state.
+///
+/// The properties of a selector are resolved as follows:
+///
+/// * the properties of the style and its base styles are the defaults.
+/// * each state of the style whose key matches the active states of the selector is applied.
+/// * a compound state key like `hover+focused` or `pressed+disabled` matches only if all of its states are active.
+/// * matching states are applied in the order they are declared in the style, a later state overrides
+///   the properties of the states before. Declare compound and more important states last.
+/// * a state of a style overrides the state with the same key of its base style and keeps its position.
+///
+/// Beside plain style keys like `button` the keys of a theme could select widgets by id and by the
+/// styles of their parents, similar to CSS:
+///
+/// * `#save_button` selects the widget with the id `save_button`, `button#save_button` also requires the style.
+/// * `toolbar button` selects a `button` with an ancestor of style `toolbar`.
+/// * `toolbar > button` selects a `button` whose parent has the style `toolbar`.
+///
+/// The plain style of the selector and all matching rules are applied ordered by their specificity: first by the
+/// number of ids, then by the number of styles in the key, a plain style has one style. Rules with the same
+/// specificity are applied in alphabetical order of their keys. The states of a rule are applied on top of its
+/// properties and do not raise its specificity, so a rule overrides also the states of a less specific style.
+/// Declare the states needed on the rule itself.
+///
+/// # Example
+///
+/// ```text
+/// "button": (
+///     properties: { "background": "$BUTTON_BACKGROUND" },
+///     states: [
+///         ( key: "hover", properties: { "background": "$BUTTON_BACKGROUND_HOVER" } ),
+///         ( key: "focused", properties: { "border_brush": "$BUTTON_BORDER_FOCUSED" } ),
+///         ( key: "hover+focused", properties: { "background": "$BUTTON_BACKGROUND_FOCUSED_HOVER" } ),
+///         ( key: "disabled", properties: { "background": "$BUTTON_BACKGROUND_DISABLED" } ),
+///     ],
+/// ),
+/// "toolbar button": (
+///     base: "button",
+///     properties: { "height": 24 },
+/// ),
+/// "#save_button": (
+///     base: "button",
+///     properties: { "background": "$ACCENT_COLOR" },
+/// ),
+/// ```
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Theme {
+    config: ThemeConfig,
     styles: HashMap<String, Style>,
+    rules: Vec<StyleRule>,
+    ancestor_rules: bool,
+    // resource key => keys of the styles that reference the resource
+    dependents: HashMap<String, HashSet<String>>,
     fonts: HashMap<String, &'static [u8]>,
+    images: HashMap<String, Image>,
 }
 
 impl Theme {
     /// Creates a theme from the given config.
     pub fn from_config(config: ThemeConfig) -> Self {
-        let mut styles = HashMap::new();
+        let mut theme = Theme {
+            config,
+            ..Default::default()
+        };
 
-        for style_key in config.styles.keys() {
-            Theme::read_config(style_key, style_key, &config, &mut styles)
+        let mut keys: Vec<String> = theme.config.styles.keys().cloned().collect();
+        keys.sort();
+
+        for style_key in keys {
+            theme.resolve_style(&style_key);
         }
 
-        Theme {
-            styles,
-            fonts: HashMap::new(),
+        // stable sort keeps the alphabetical order of rules with the same specificity
+        theme.rules.sort_by_key(StyleRule::specificity);
+        theme.ancestor_rules = theme.rules.iter().any(StyleRule::has_ancestors);
+
+        theme
+    }
+
+    /// Gets the value of the resource with the given key e.g. `ACCENT_COLOR`.
+    pub fn resource(&self, key: &str) -> Option<&Value> {
+        self.config.resources.get(key)
+    }
+
+    /// Returns the map of resources.
+    pub fn resources(&self) -> &HashMap<String, Value> {
+        &self.config.resources
+    }
+
+    /// Sets the value of the resource with the given key e.g. `ACCENT_COLOR`. Only the styles
+    /// that reference the resource are resolved again. Returns the keys of these styles.
+    ///
+    /// Use `Context::set_theme_resource` to change a resource of a running window.
+    pub fn set_resource(&mut self, key: impl Into<String>, value: Value) -> Vec<String> {
+        let key = key.into();
+
+        let mut style_keys: Vec<String> = self
+            .dependents
+            .get(&key)
+            .map(|keys| keys.iter().cloned().collect())
+            .unwrap_or_default();
+        style_keys.sort();
+
+        self.config.resources.insert(key, value);
+
+        for style_key in &style_keys {
+            self.resolve_style(style_key);
+        }
+
+        style_keys
+    }
+
+    /// Checks if a widget with the given selector could use one of the given styles, e.g. the
+    /// styles returned by `set_resource`. The ancestors of rules are not checked.
+    pub fn uses_styles(&self, selector: &Selector, style_keys: &[String]) -> bool {
+        style_keys.iter().any(|key| {
+            selector.style.as_ref() == Some(key)
+                || self
+                    .rules
+                    .iter()
+                    .any(|rule| rule.key() == key && rule.matches_target(selector))
+        })
+    }
+
+    // reads the style or rule with the given key from the config and records the referenced resources
+    fn resolve_style(&mut self, style_key: &str) {
+        match StyleRule::parse(style_key) {
+            Ok(None) => {
+                let style = Theme::read_config(style_key, &self.config);
+                self.styles.insert(style_key.to_string(), style);
+            }
+            Ok(Some(mut rule)) => {
+                rule.style = Theme::read_config(style_key, &self.config);
+
+                if let Some(pos) = self.rules.iter().position(|r| r.key() == style_key) {
+                    self.rules[pos] = rule;
+                } else {
+                    self.rules.push(rule);
+                }
+            }
+            // invalid keys are reported by `ThemeConfig::validate`
+            Err(_) => return,
+        }
+
+        for base_key in self.config.base_chain(style_key) {
+            let style_config = &self.config.styles[base_key];
+
+            let values = style_config.properties.values().chain(
+                style_config
+                    .states
+                    .iter()
+                    .flat_map(|s| s.properties.values()),
+            );
+
+            for value in values {
+                if let Value::String(value) = value {
+                    if value.starts_with(RESOURCE_KEY) {
+                        self.dependents
+                            .entry(value.replace(RESOURCE_KEY, ""))
+                            .or_default()
+                            .insert(style_key.to_string());
+                    }
+                }
+            }
         }
     }
 
@@ -41,96 +189,119 @@ impl Theme {
         &self.fonts
     }
 
+    /// Registers a new image, image brushes of the theme reference it with `url({key})`.
+    pub fn register_image(mut self, key: &str, image: impl Into<Image>) -> Self {
+        self.images.insert(key.to_string(), image.into());
+        self
+    }
+
+    /// Returns the map of registered images.
+    pub fn images(&self) -> &HashMap<String, Image> {
+        &self.images
+    }
+
     /// Returns a reference to the style corresponding to the key.
     pub fn style(&self, key: &str) -> Option<&Style> {
         self.styles.get(key)
     }
 
+    /// Returns `true` if the theme contains rules like `toolbar button`, that depend on the parents of a widget.
+    pub fn has_ancestor_rules(&self) -> bool {
+        self.ancestor_rules
+    }
+
+    /// Reads the properties of the given selector if it is dirty. Rules that depend on the parents of a widget
+    /// are skipped, use `properties_in_tree` to resolve them.
     pub fn properties(&self, selector: &Selector) -> Option<HashMap<String, Value>> {
-        if !selector.dirty() {
+        self.properties_in_tree(selector, &[])
+    }
+
+    /// Reads the properties of the given selector if it is dirty. The ancestors are the selectors of the
+    /// parents of the widget, from the nearest to the farthest.
+    pub fn properties_in_tree(
+        &self,
+        selector: &Selector,
+        ancestors: &[Selector],
+    ) -> Option<HashMap<String, Value>> {
+        if !selector.dirty() || (selector.style.is_none() && selector.id.is_none()) {
             return None;
         }
 
-        if let Some(style) = &selector.style {
-            let mut properties = HashMap::new();
+        // the plain style has the specificity of one style
+        let mut styles: Vec<((usize, usize), &Style)> = vec![];
 
-            if let Some(style) = self.styles.get(style) {
-                for (key, value) in &style.properties {
-                    properties.insert(key.clone(), value.clone());
-                }
+        if let Some(style) = selector.style.as_ref().and_then(|s| self.styles.get(s)) {
+            styles.push(((0, 1), style));
+        }
 
-                // reverse order because last active state has highest priority
-                for state in style.states.iter().rev() {
-                    if selector.states().contains(&state.key) {
-                        for (key, value) in &state.properties {
-                            // properties of the selected state overrides default properties
-                            properties.insert(key.clone(), value.clone());
-                        }
+        for rule in &self.rules {
+            if rule.matches(selector, ancestors) {
+                styles.push((rule.specificity(), &rule.style));
+            }
+        }
+
+        // stable sort, the plain style stays in front of rules with the same specificity
+        styles.sort_by_key(|(specificity, _)| *specificity);
+
+        let mut properties = HashMap::new();
+
+        for (_, style) in styles {
+            for (key, value) in &style.properties {
+                properties.insert(key.clone(), value.clone());
+            }
 
-                        break;
+            // all matching states are applied in declared order, so a later state overrides
+            // the properties of the default properties and of the states before
+            for state in &style.states {
+                if selector.matches_state(&state.key) {
+                    for (key, value) in &state.properties {
+                        properties.insert(key.clone(), value.clone());
                     }
                 }
             }
-
-            return Some(properties);
         }
 
-        None
+        Some(properties)
     }
 
-    // reads the given config and copy it's data in the given styles map
-    fn read_config(
-        style_key: &str,
-        base_key: &str,
-        config: &ThemeConfig,
-        styles: &mut HashMap<String, Style>,
-    ) {
-        if style_key.is_empty() {
-            return;
-        }
+    // reads the style with the given key and its base styles from the config
+    fn read_config(style_key: &str, config: &ThemeConfig) -> Style {
+        let mut style = Style::new();
 
-        if !styles.contains_key(style_key) {
-            styles.insert(style_key.to_string(), Style::new());
-        }
+        // start from topmost base, unknown or cyclic bases are skipped
+        for base_key in config.base_chain(style_key).iter().rev() {
+            let style_config = &config.styles[*base_key];
 
-        // start from topmost base
-        if let Some(style) = config.styles.get(base_key) {
-            if !style.base.is_empty() && style.base != *style_key {
-                Theme::read_config(style_key, &style.base, config, styles);
+            // reads the properties
+            for (property_key, property_value) in &style_config.properties {
+                style.properties.insert(
+                    property_key.clone(),
+                    Theme::read_value(property_value, &config.resources),
+                );
             }
-        }
 
-        if let Some(style_config) = config.styles.get(base_key) {
-            if let Some(style) = styles.get_mut(style_key) {
-                // reads the properties
-                for (property_key, property_value) in &style_config.properties {
-                    style.properties.insert(
+            // reads the states
+            for state in &style_config.states {
+                let mut new_state = ThemeState::new(state.key.clone());
+
+                for (property_key, property_value) in &state.properties {
+                    new_state.properties.insert(
                         property_key.clone(),
                         Theme::read_value(property_value, &config.resources),
                     );
                 }
 
-                // reads the states
-                for state in &style_config.states {
-                    let mut new_state = ThemeState::new(state.key.clone());
-
-                    for (property_key, property_value) in &state.properties {
-                        new_state.properties.insert(
-                            property_key.clone(),
-                            Theme::read_value(property_value, &config.resources),
-                        );
-                    }
-
-                    // a state with the same name on a lower base style or the indented style overrides the state of a higher base
-                    if let Some(pos) = style.states.iter().position(|s| s.key == new_state.key) {
-                        style.states.remove(pos);
-                        style.states.insert(pos, new_state)
-                    } else {
-                        style.states.push(new_state);
-                    }
+                // a state with the same name on a lower base style or the indented style overrides the state of a higher base
+                if let Some(pos) = style.states.iter().position(|s| s.key == new_state.key) {
+                    style.states.remove(pos);
+                    style.states.insert(pos, new_state)
+                } else {
+                    style.states.push(new_state);
                 }
             }
         }
+
+        style
     }
 
     // if the property value is a place holder replace it with the corresponding value of the resources
@@ -144,3 +315,170 @@ impl Theme {
         property_value.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_properties() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r#"Theme (
+    styles: {
+        "button": (
+            properties: {
+                "background": "default",
+                "foreground": "default",
+            },
+            states: [
+                ( key: "hover", properties: { "background": "hover" } ),
+                ( key: "focused", properties: { "foreground": "focused" } ),
+                ( key: "hover+focused", properties: { "background": "hover+focused" } ),
+                ( key: "disabled", properties: { "background": "disabled", "foreground": "disabled" } ),
+            ],
+        ),
+    },
+)"#,
+        ));
+
+        let background = |states: &[&str]| {
+            let mut selector = Selector::new("button");
+
+            for state in states {
+                selector.push_state(*state);
+            }
+
+            let properties = theme.properties(&selector).unwrap();
+            (
+                properties["background"]
+                    .clone()
+                    .into_rust::<String>()
+                    .unwrap(),
+                properties["foreground"]
+                    .clone()
+                    .into_rust::<String>()
+                    .unwrap(),
+            )
+        };
+
+        assert_eq!(background(&[]), ("default".into(), "default".into()));
+        assert_eq!(background(&["hover"]), ("hover".into(), "default".into()));
+        assert_eq!(
+            background(&["focused", "hover"]),
+            ("hover+focused".into(), "focused".into())
+        );
+        assert_eq!(
+            background(&["disabled", "hover", "focused"]),
+            ("disabled".into(), "disabled".into())
+        );
+    }
+
+    #[test]
+    fn test_properties_in_tree() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r##"Theme (
+    styles: {
+        "button": (
+            properties: { "background": "button", "height": 32 },
+            states: [
+                ( key: "hover", properties: { "background": "hover" } ),
+            ],
+        ),
+        "toolbar button": (
+            properties: { "background": "toolbar button", "height": 24 },
+        ),
+        "stack > button": (
+            properties: { "height": 16 },
+        ),
+        "#save": (
+            properties: { "background": "save" },
+        ),
+    },
+)"##,
+        ));
+
+        let resolve = |id: Option<&str>, ancestors: &[&str]| {
+            let mut selector = Selector::new("button");
+            selector.id = id.map(String::from);
+            selector.push_state("hover");
+
+            let ancestors: Vec<Selector> = ancestors.iter().map(|a| Selector::new(*a)).collect();
+            let properties = theme.properties_in_tree(&selector, &ancestors).unwrap();
+            (
+                properties["background"]
+                    .clone()
+                    .into_rust::<String>()
+                    .unwrap(),
+                properties["height"].clone().into_rust::<f64>().unwrap(),
+            )
+        };
+
+        assert!(theme.has_ancestor_rules());
+        assert_eq!(resolve(None, &[]), ("hover".into(), 32.0));
+        assert_eq!(resolve(None, &["stack"]), ("hover".into(), 16.0));
+        assert_eq!(
+            resolve(None, &["toolbar", "stack"]),
+            ("toolbar button".into(), 24.0)
+        );
+
+        // same specificity, applied in alphabetical order
+        assert_eq!(
+            resolve(None, &["stack", "toolbar"]),
+            ("toolbar button".into(), 24.0)
+        );
+
+        assert_eq!(resolve(Some("save"), &["toolbar"]), ("save".into(), 24.0));
+    }
+
+    #[test]
+    fn test_set_resource() {
+        let mut theme = Theme::from_config(ThemeConfig::from(
+            r##"Theme (
+    styles: {
+        "base": (
+            properties: { "foreground": "$FOREGROUND" },
+        ),
+        "button": (
+            base: "base",
+            properties: { "background": "$ACCENT" },
+        ),
+        "toolbar button": (
+            states: [
+                ( key: "hover", properties: { "border_brush": "$ACCENT" } ),
+            ],
+        ),
+        "text_block": (
+            properties: { "font_size": 12 },
+        ),
+    },
+    resources: {
+        "ACCENT": "#ff0000",
+        "FOREGROUND": "#ffffff",
+    },
+)"##,
+        ));
+
+        let accent = Value::String("#00ff00".to_string());
+
+        assert_eq!(
+            theme.set_resource("ACCENT", accent.clone()),
+            vec!["button".to_string(), "toolbar button".to_string()]
+        );
+        assert_eq!(theme.resource("ACCENT"), Some(&accent));
+        assert_eq!(
+            theme.style("button").unwrap().properties["background"],
+            accent
+        );
+
+        let styles = theme.set_resource("FOREGROUND", accent.clone());
+        assert_eq!(styles, vec!["base".to_string(), "button".to_string()]);
+        assert!(theme.uses_styles(&Selector::new("button"), &styles));
+        assert!(!theme.uses_styles(&Selector::new("text_block"), &styles));
+        assert_eq!(
+            theme.style("button").unwrap().properties["foreground"],
+            accent
+        );
+
+        assert!(theme.set_resource("UNUSED", accent).is_empty());
+    }
+}
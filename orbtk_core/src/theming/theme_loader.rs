@@ -0,0 +1,341 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{
+    render::Image,
+    theming::{Theme, ThemeConfig, ThemeError},
+};
+
+/// Describes why a theme file could not be loaded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ThemeLoadError {
+    /// The file could not be read.
+    Io { path: PathBuf, message: String },
+
+    /// The file is not a valid `ThemeConfig`. The error contains line and column.
+    Parse {
+        path: PathBuf,
+        error: Box<ThemeError>,
+    },
+}
+
+impl fmt::Display for ThemeLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeLoadError::Io { path, message } => {
+                write!(f, "{}: could not read theme: {}", path.display(), message)
+            }
+            ThemeLoadError::Parse { path, error } => write!(f, "{}:{}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for ThemeLoadError {}
+
+// reports an error of a load or a reload of the theme
+type ErrorHandler = Rc<dyn Fn(&ThemeLoadError) + 'static>;
+
+/// Reads a theme from `ThemeConfig` files on disk and watches the files for changes.
+///
+/// The files are merged with `ThemeConfig::extend` in the order they are added.
+/// If the loader is set on the `Application`, each change of a file
+/// reloads the theme and switches it on all windows. Watching is only enabled
+/// in debug builds, a release build loads the theme once. Errors of each load and reload are
+/// reported to the handler set by `on_error`, the previous theme remains active.
+///
+/// # Example
+///
+/// ```rust
+/// Application::new()
+///     .theme_loader(
+///         ThemeLoader::new()
+///             .file("assets/theme.ron")
+///             .file("assets/theme_colors.ron")
+///             .font("Roboto-Regular", ROBOTO_REGULAR_FONT)
+///             .on_error(|error| println!("{}", error)),
+///     )
+///     .window(|ctx| Window::new().build(ctx))
+///     .run();
+/// ```
+#[derive(Clone)]
+pub struct ThemeLoader {
+    files: Vec<PathBuf>,
+    modified: Vec<Option<SystemTime>>,
+    fonts: Vec<(String, &'static [u8])>,
+    images: Vec<(String, Image)>,
+    poll_interval: Duration,
+    last_poll: Option<Instant>,
+    generation: usize,
+    theme: Option<Rc<Theme>>,
+    error: Option<ThemeLoadError>,
+    on_error: Option<ErrorHandler>,
+}
+
+impl Default for ThemeLoader {
+    fn default() -> Self {
+        ThemeLoader {
+            files: vec![],
+            modified: vec![],
+            fonts: vec![],
+            images: vec![],
+            poll_interval: Duration::from_millis(500),
+            last_poll: None,
+            generation: 0,
+            theme: None,
+            error: None,
+            on_error: None,
+        }
+    }
+}
+
+impl ThemeLoader {
+    /// Creates a new theme loader without files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `ThemeConfig` file. Files added later extend the previous ones.
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.files.push(path.into());
+        self.modified.push(None);
+        self
+    }
+
+    /// Registers a font on each loaded theme.
+    pub fn font(mut self, key: impl Into<String>, font: &'static [u8]) -> Self {
+        self.fonts.push((key.into(), font));
+        self
+    }
+
+    /// Registers an image on each loaded theme.
+    pub fn image(mut self, key: impl Into<String>, image: impl Into<Image>) -> Self {
+        self.images.push((key.into(), image.into()));
+        self
+    }
+
+    /// Sets the interval in which the modification times of the files are checked.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets a handler that is called with the error of each failed load or reload, e.g. to show
+    /// file, line and column of a parse error.
+    pub fn on_error<H: Fn(&ThemeLoadError) + 'static>(mut self, handler: H) -> Self {
+        self.on_error = Some(Rc::new(handler));
+        self
+    }
+
+    /// Gets the watched files.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Gets the last successfully loaded theme.
+    pub fn theme(&self) -> Option<Rc<Theme>> {
+        self.theme.clone()
+    }
+
+    /// Gets the number of successful loads. Could be used to check if the theme has changed since the last look.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Gets the error of the last load or `None` if the last load was successful.
+    pub fn error(&self) -> Option<&ThemeLoadError> {
+        self.error.as_ref()
+    }
+
+    /// Reads and merges all files and builds the theme. On failure the error is reported to the
+    /// `on_error` handler and kept until the next load.
+    pub fn load(&mut self) -> Result<Rc<Theme>, ThemeLoadError> {
+        let result = self.build();
+        self.error = result.as_ref().err().cloned();
+
+        if let (Some(error), Some(on_error)) = (&self.error, &self.on_error) {
+            on_error(error);
+        }
+
+        result
+    }
+
+    // reads the files and builds the theme, updates modification times and generation
+    fn build(&mut self) -> Result<Rc<Theme>, ThemeLoadError> {
+        for (path, modified) in self.files.iter().zip(self.modified.iter_mut()) {
+            *modified = modified_time(path);
+        }
+
+        let mut config = ThemeConfig::default();
+
+        for path in &self.files {
+            config = config.extend(read_config(path)?);
+        }
+
+        let theme = self
+            .fonts
+            .iter()
+            .fold(Theme::from_config(config), |theme, (key, font)| {
+                theme.register_font(key, font)
+            });
+
+        let theme = self.images.iter().fold(theme, |theme, (key, image)| {
+            theme.register_image(key, image.clone())
+        });
+
+        let theme = Rc::new(theme);
+        self.theme = Some(theme.clone());
+        self.generation += 1;
+
+        Ok(theme)
+    }
+
+    /// Checks after the poll interval if one of the files is modified. If so the theme is reloaded
+    /// and the result is returned. Returns `None` if nothing has changed.
+    pub fn poll(&mut self) -> Option<Result<Rc<Theme>, ThemeLoadError>> {
+        let now = Instant::now();
+
+        if let Some(last_poll) = self.last_poll {
+            if now.duration_since(last_poll) < self.poll_interval {
+                return None;
+            }
+        }
+
+        self.last_poll = Some(now);
+
+        if self
+            .files
+            .iter()
+            .zip(self.modified.iter())
+            .all(|(path, modified)| modified_time(path) == *modified)
+        {
+            return None;
+        }
+
+        Some(self.load())
+    }
+}
+
+impl fmt::Debug for ThemeLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThemeLoader")
+            .field("files", &self.files)
+            .field("poll_interval", &self.poll_interval)
+            .field("generation", &self.generation)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+// --- Helper --
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn read_config(path: &Path) -> Result<ThemeConfig, ThemeLoadError> {
+    let content = fs::read_to_string(path).map_err(|e| ThemeLoadError::Io {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+
+    ThemeConfig::parse(content.as_str()).map_err(|error| ThemeLoadError::Parse {
+        path: path.to_path_buf(),
+        error: Box::new(error),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("orbtk_{}_{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load() {
+        let styles = temp_file(
+            "loader_styles.ron",
+            r#"Theme (
+    styles: {
+        "button": (
+            properties: {
+                "background": "$BACKGROUND",
+            },
+        ),
+    },
+)"#,
+        );
+        let colors = temp_file(
+            "loader_colors.ron",
+            r##"Theme (
+    resources: {
+        "BACKGROUND": "#000000",
+    },
+)"##,
+        );
+
+        let mut loader = ThemeLoader::new().file(&styles).file(&colors);
+        let theme = loader.load().unwrap();
+
+        assert!(theme.style("button").is_some());
+        assert_eq!(loader.generation(), 1);
+        assert!(loader.error().is_none());
+        assert!(loader.poll().is_none());
+
+        fs::remove_file(styles).unwrap();
+        fs::remove_file(colors).unwrap();
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let path = temp_file(
+            "loader_error.ron",
+            "Theme (\n    styles: {\n        \"button\" (\n",
+        );
+
+        let reported = Rc::new(std::cell::Cell::new(0));
+        let counter = reported.clone();
+        let mut loader = ThemeLoader::new()
+            .file(&path)
+            .on_error(move |_| counter.set(counter.get() + 1));
+
+        let error = match loader.load() {
+            Err(ThemeLoadError::Parse { error, .. }) => *error,
+            _ => panic!("expected parse error"),
+        };
+
+        match error {
+            ThemeError::Parse { line, column, .. } => {
+                assert_eq!(line, 3);
+                assert!(column > 1);
+            }
+            _ => panic!("expected parse error"),
+        }
+
+        assert_eq!(loader.generation(), 0);
+        assert!(loader.theme().is_none());
+        assert!(matches!(loader.error(), Some(ThemeLoadError::Parse { .. })));
+        assert_eq!(reported.get(), 1);
+
+        fs::write(&path, "Theme ()").unwrap();
+        assert!(loader.load().is_ok());
+        assert!(loader.error().is_none());
+        assert_eq!(reported.get(), 1);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let mut loader = ThemeLoader::new().file("does_not_exist.ron");
+
+        assert!(matches!(loader.load(), Err(ThemeLoadError::Io { .. })));
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
     render::RenderContext2D,
     shell::{ShellRequest, WindowRequest},
     tree::Tree,
+    utils::{FlowDirection, Rectangle},
 };
 
 use super::WidgetContainer;
@@ -64,6 +65,12 @@ impl<'a> Context<'a> {
         self.render_context.data()
     }
 
+    /// Copies the given region of the current frame as image e.g. the bounds of a `Canvas`
+    /// to put it on the clipboard.
+    pub fn frame_image(&self, region: Rectangle) -> ClipboardImage {
+        frame_image(self.render_context, region)
+    }
+
     /// Gets the current entity.
     pub fn entity(&self) -> Entity {
         self.entity
@@ -433,6 +440,8 @@ impl<'a> Context<'a> {
             self.provider.shell_sender.clone(),
             create_fn,
             self.provider.localization.clone(),
+            self.provider.settings_storage.clone(),
+            self.provider.settings_observer.clone(),
         );
         self.provider
             .shell_sender
@@ -460,18 +469,78 @@ impl<'a> Context<'a> {
             self.render_context.register_font(key, *font);
         }
 
+        for (key, image) in self.theme.images() {
+            self.render_context.register_image(key, image);
+        }
+
         // update on window to update all widgets in the tree
         self.window().update_dirty(true);
     }
 
-    /// Sets the current language.
+    /// Changes the value of a theme resource of the current window e.g. `ACCENT_COLOR` or `FONT_SIZE_12`.
+    /// Only the styles that reference the resource are resolved again and only the widgets using
+    /// these styles are updated, their children are not touched. Setting the current value does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ctx.set_theme_resource("ACCENT_COLOR", ron::Value::String("#3c95df".to_string()));
+    /// ```
+    pub fn set_theme_resource(&mut self, key: impl Into<String>, value: ron::Value) {
+        let key = key.into();
+
+        if self.theme.resource(&key) == Some(&value) {
+            return;
+        }
+
+        // the theme is only copied if it is shared
+        let style_keys = Rc::make_mut(&mut self.theme).set_resource(key, value);
+        *self.window().get_mut::<Rc<Theme>>("theme") = Rc::clone(&self.theme);
+
+        if style_keys.is_empty() {
+            return;
+        }
+
+        let root = self.ecm.entity_store().root();
+        let entities: Vec<Entity> = self
+            .ecm
+            .entity_store()
+            .start_node(root)
+            .into_iter()
+            .filter(|entity| {
+                self.ecm
+                    .component_store()
+                    .get::<Selector>("selector", *entity)
+                    .map(|selector| self.theme.uses_styles(selector, &style_keys))
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        for entity in entities {
+            self.get_widget(entity).update_dirty_self();
+        }
+    }
+
+    /// Sets the current language. If the flow direction of the window is not set explicit, it
+    /// follows the new language.
     pub fn set_language(&mut self, key: &str) {
         if let Some(localization) = &self.provider.localization {
             localization.borrow_mut().set_language(key);
         }
 
+        let flow_direction = self.locale().flow_direction();
         let root = self.ecm.entity_store().root.unwrap();
-        self.get_widget(root).update_dirty(true);
+        let mut window = self.get_widget(root);
+
+        // an explicit flow direction of the window is kept
+        if window
+            .try_get::<bool>("flow_direction_from_locale")
+            .map_or(false, |follows_locale| *follows_locale)
+        {
+            window.set("flow_direction", flow_direction);
+        }
+
+        window.update_dirty(true);
     }
 
     /// Localize the text property of a widget. If the given key has
@@ -484,6 +553,31 @@ impl<'a> Context<'a> {
         key
     }
 
+    /// Localize the text property of a widget and replaces its placeholders with the given
+    /// arguments. If no localization service has been included, only the placeholders of the key
+    /// will be replaced.
+    pub fn localize_text_with_args(&self, key: String, args: &LocalizationArgs) -> String {
+        if let Some(localization) = &self.provider.localization {
+            return localization.borrow().text_with_args(key, args);
+        }
+        args.format(&key, &self.locale())
+    }
+
+    /// Gets the locale of the current language to format numbers, currencies and dates. If no
+    /// localization service has been included, the locale of `en_US` will be returned.
+    pub fn locale(&self) -> Locale {
+        if let Some(localization) = &self.provider.localization {
+            return localization.borrow().locale();
+        }
+        Locale::default()
+    }
+
+    /// Gets the flow direction of the current widget. If the widget doesn't define a flow
+    /// direction, the flow direction of the nearest ancestor is used.
+    pub fn flow_direction(&self) -> FlowDirection {
+        flow_direction(self.ecm, self.entity)
+    }
+
     /// Returns a cloned event adapter.
     pub fn event_adapter(&self) -> EventAdapter {
         self.provider.event_adapter.clone()
@@ -562,4 +656,41 @@ pub fn get_all_children(children: &mut Vec<Entity>, parent: Entity, tree: &Tree)
     }
 }
 
+// Copies the given region of the frame. The pixels of the frame are stored premultiplied in BGRA
+// order, the image gets RGBA.
+fn frame_image(render_context: &RenderContext2D, region: Rectangle) -> ClipboardImage {
+    ClipboardImage::from_frame(
+        render_context.width() as u32,
+        render_context.height() as u32,
+        render_context.data(),
+        region,
+    )
+    .unwrap_or_default()
+}
+
 // -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{Color, Point, Size};
+
+    #[test]
+    fn frame_image_is_rgba() {
+        let mut render_context = RenderContext2D::new(4.0, 4.0);
+        render_context.set_fill_style(Color::rgb(255, 0, 0));
+        render_context.fill_rect(0.0, 0.0, 4.0, 4.0);
+
+        let image = frame_image(
+            &render_context,
+            Rectangle::new(Point::new(1.0, 1.0), Size::new(2.0, 2.0)),
+        );
+
+        assert_eq!(image.width(), 2);
+        assert_eq!(image.height(), 2);
+        assert!(image
+            .data()
+            .chunks(4)
+            .all(|pixel| pixel == [255, 0, 0, 255]));
+    }
+}
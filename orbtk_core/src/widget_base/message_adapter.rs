@@ -123,6 +123,21 @@ impl MessageAdapter {
             .expect("MessageAdapter::send_message: Cannot send redraw request.");
     }
 
+    /// Sends a new message to the message pipeline. Returns `false` if the
+    /// window of the message adapter is closed.
+    pub(crate) fn try_send_message<M: Any + Send>(&self, message: M, target: Entity) -> bool {
+        if let Ok(mut locked_messages) = self.messages.lock() {
+            locked_messages
+                .entry(target)
+                .or_insert_with(HashMap::new)
+                .entry(TypeId::of::<M>())
+                .or_insert_with(Vec::new)
+                .push(MessageBox::new(message, target));
+        }
+
+        self.window_sender.send(WindowRequest::Redraw).is_ok()
+    }
+
     /// Returns a list of entities that has messages.
     pub(crate) fn entities(&self) -> Vec<Entity> {
         self.messages
@@ -445,6 +445,29 @@ impl<'a> WidgetContainer<'a> {
         self.update_widget(self.current_node, force, true);
     }
 
+    /// Update all properties from theme for the current widget and mark the widget as dirty. In
+    /// contrast to `update_dirty` the children are not updated.
+    pub fn update_dirty_self(&mut self) {
+        if !self.has::<Selector>("selector") {
+            return;
+        }
+
+        let entity = self.current_node;
+        let mut selector = self.clone::<Selector>("selector");
+        selector.set_dirty(true);
+
+        self.update_properties(entity, &selector);
+
+        // direct access to prevent initial setting of dirty flag on widget
+        self.ecm
+            .component_store_mut()
+            .get_mut::<Selector>("selector", self.current_node)
+            .unwrap()
+            .set_dirty(false);
+
+        mark_as_dirty("selector", self.current_node, self.ecm);
+    }
+
     /// Update all properties from theme for the given widget.
     pub fn update_widget(&mut self, entity: Entity, force: bool, should_mark_as_dirty: bool) {
         self.current_node = entity;
@@ -467,7 +490,38 @@ impl<'a> WidgetContainer<'a> {
             return;
         }
 
-        if let Some(props) = &mut self.theme.properties(&selector) {
+        self.update_properties(entity, &selector);
+
+        let force = selector.dirty() || force;
+
+        for child in &(self.ecm.entity_store().children.clone())[&entity] {
+            self.update_widget(*child, force, should_mark_as_dirty);
+        }
+
+        self.current_node = entity;
+
+        // direct access to prevent initial setting of dirty flag on widget
+        self.ecm
+            .component_store_mut()
+            .get_mut::<Selector>("selector", self.current_node)
+            .unwrap()
+            .set_dirty(false);
+
+        if should_mark_as_dirty {
+            mark_as_dirty("selector", self.current_node, self.ecm);
+        }
+    }
+
+    // sets the properties of the theme that match the given dirty selector on the current widget
+    fn update_properties(&mut self, entity: Entity, selector: &Selector) {
+        // rules like `toolbar button` are resolved against the selectors of the parents
+        let ancestors = if self.theme.has_ancestor_rules() {
+            self.ancestor_selectors(entity)
+        } else {
+            vec![]
+        };
+
+        if let Some(props) = &mut self.theme.properties_in_tree(&selector, &ancestors) {
             for (key, value) in props.drain() {
                 match key.as_str() {
                     // special mapping
@@ -490,30 +544,37 @@ impl<'a> WidgetContainer<'a> {
                             self.update_value::<String, Value>(&key, Value(value));
                         } else if self.is::<Alignment>(&key) {
                             self.update_value::<Alignment, Value>(&key, Value(value));
+                        } else if self.is::<BoxShadow>(&key) {
+                            self.update_value::<BoxShadow, Value>(&key, Value(value));
+                        } else if self.is::<CornerRadius>(&key) {
+                            self.update_value::<CornerRadius, Value>(&key, Value(value));
+                        } else if self.is::<BorderBrushes>(&key) {
+                            self.update_value::<BorderBrushes, Value>(&key, Value(value));
                         }
                     }
                 }
             }
         }
+    }
 
-        let force = selector.dirty() || force;
-
-        for child in &(self.ecm.entity_store().children.clone())[&entity] {
-            self.update_widget(*child, force, should_mark_as_dirty);
-        }
-
-        self.current_node = entity;
+    // collects the selectors of the parents of the given entity, from the nearest to the farthest
+    fn ancestor_selectors(&self, entity: Entity) -> Vec<Selector> {
+        let mut selectors = vec![];
+        let mut current = entity;
 
-        // direct access to prevent initial setting of dirty flag on widget
-        self.ecm
-            .component_store_mut()
-            .get_mut::<Selector>("selector", self.current_node)
-            .unwrap()
-            .set_dirty(false);
+        while let Some(Some(parent)) = self.ecm.entity_store().parent.get(&current) {
+            if let Ok(selector) = self
+                .ecm
+                .component_store()
+                .get::<Selector>("selector", *parent)
+            {
+                selectors.push(selector.clone());
+            }
 
-        if should_mark_as_dirty {
-            mark_as_dirty("selector", self.current_node, self.ecm);
+            current = *parent;
         }
+
+        selectors
     }
 
     fn get_name(&self) -> String {
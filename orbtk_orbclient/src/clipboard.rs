@@ -0,0 +1,532 @@
+//! This module contains the content types that are exchanged with the clipboard of the system.
+
+use crate::{render::Image, utils::Rectangle};
+
+/// Describes which clipboard of the system is meant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClipboardSelection {
+    /// The clipboard that is used by copy and paste.
+    Clipboard,
+
+    /// The primary selection of X11 style environments. It contains the last selected text and
+    /// is pasted with a middle click.
+    Primary,
+}
+
+impl Default for ClipboardSelection {
+    fn default() -> Self {
+        ClipboardSelection::Clipboard
+    }
+}
+
+/// Describes a format in which content could be stored on the clipboard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClipboardFormat {
+    /// Plain utf-8 text.
+    Text,
+
+    /// Html markup.
+    Html,
+
+    /// List of uris e.g. copied files.
+    UriList,
+
+    /// Image with RGBA pixels.
+    Image,
+}
+
+impl ClipboardFormat {
+    /// Gets the MIME type of the format. Images are exchanged as png with the system.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ClipboardFormat::Text => "text/plain;charset=utf-8",
+            ClipboardFormat::Html => "text/html",
+            ClipboardFormat::UriList => "text/uri-list",
+            ClipboardFormat::Image => "image/png",
+        }
+    }
+
+    /// Gets the format of the given MIME type.
+    pub fn from_mime_type(mime_type: &str) -> Option<ClipboardFormat> {
+        match mime_type.split(';').next().map(str::trim) {
+            Some("text/plain") | Some("UTF8_STRING") | Some("STRING") | Some("TEXT") => {
+                Some(ClipboardFormat::Text)
+            }
+            Some("text/html") => Some(ClipboardFormat::Html),
+            Some("text/uri-list") => Some(ClipboardFormat::UriList),
+            Some("image/png") => Some(ClipboardFormat::Image),
+            _ => None,
+        }
+    }
+}
+
+/// Image with straight (not premultiplied) RGBA pixels, four bytes per pixel, row by row.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClipboardImage {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl ClipboardImage {
+    /// Creates a new image from the given RGBA bytes. Returns `None` if the length of the data
+    /// does not match the size.
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Option<Self> {
+        if data.len() != width as usize * height as usize * 4 {
+            return None;
+        }
+
+        Some(ClipboardImage {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Creates a new image from the given ARGB pixels as they are used by `Image`.
+    pub fn from_argb(width: u32, height: u32, pixels: &[u32]) -> Option<Self> {
+        if pixels.len() != width as usize * height as usize {
+            return None;
+        }
+
+        let mut data = Vec::with_capacity(pixels.len() * 4);
+
+        for pixel in pixels {
+            let [a, r, g, b] = pixel.to_be_bytes();
+            data.extend_from_slice(&[r, g, b, a]);
+        }
+
+        ClipboardImage::new(width, height, data)
+    }
+
+    /// Creates a new image from the given premultiplied RGBA bytes.
+    pub fn from_premultiplied(width: u32, height: u32, data: &[u8]) -> Option<Self> {
+        if data.len() != width as usize * height as usize * 4 {
+            return None;
+        }
+
+        let mut image = Vec::with_capacity(data.len());
+        demultiply_into(&mut image, data, [0, 1, 2]);
+
+        ClipboardImage::new(width, height, image)
+    }
+
+    /// Creates a new image from the given region of a rendered frame with premultiplied BGRA
+    /// bytes. Only the pixels inside of the region are converted, the region is clipped to the
+    /// bounds of the frame.
+    pub fn from_frame(width: u32, height: u32, data: &[u8], region: Rectangle) -> Option<Self> {
+        if data.len() != width as usize * height as usize * 4 {
+            return None;
+        }
+
+        let (x, y, region_width, region_height) = clip(width, height, region);
+        let mut image = Vec::with_capacity(region_width * region_height * 4);
+
+        for row in y..y + region_height {
+            let start = (row * width as usize + x) * 4;
+            demultiply_into(
+                &mut image,
+                &data[start..start + region_width * 4],
+                [2, 1, 0],
+            );
+        }
+
+        ClipboardImage::new(region_width as u32, region_height as u32, image)
+    }
+
+    /// Gets the width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Gets the height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Gets the RGBA bytes.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Gets the pixels in the ARGB layout of `Image`.
+    pub fn to_argb(&self) -> Vec<u32> {
+        self.data
+            .chunks(4)
+            .map(|p| u32::from_be_bytes([p[3], p[0], p[1], p[2]]))
+            .collect()
+    }
+
+    /// Copies the given region of the image. The region is clipped to the bounds of the image.
+    pub fn region(&self, region: Rectangle) -> ClipboardImage {
+        let (x, y, width, height) = clip(self.width, self.height, region);
+        let mut data = Vec::with_capacity(width * height * 4);
+
+        for row in y..y + height {
+            let start = (row * self.width as usize + x) * 4;
+            data.extend_from_slice(&self.data[start..start + width * 4]);
+        }
+
+        ClipboardImage {
+            width: width as u32,
+            height: height as u32,
+            data,
+        }
+    }
+}
+
+impl From<&Image> for ClipboardImage {
+    fn from(image: &Image) -> Self {
+        ClipboardImage::from_argb(image.width() as u32, image.height() as u32, image.data())
+            .unwrap_or_default()
+    }
+}
+
+impl From<ClipboardImage> for Image {
+    fn from(image: ClipboardImage) -> Self {
+        Image::from_data(image.width, image.height, image.to_argb()).unwrap_or_default()
+    }
+}
+
+/// The content of the clipboard. The same content could be offered in multiple formats e.g.
+/// html together with a plain text alternative, so each reader could request the format it
+/// supports best.
+///
+/// # Example
+///
+/// ```rust
+/// let content = ClipboardContent::from_html("<b>OrbTk</b>").with_text("OrbTk");
+///
+/// assert_eq!(content.html(), Some("<b>OrbTk</b>"));
+/// assert_eq!(content.plain_text(), Some("OrbTk".to_string()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClipboardContent {
+    text: Option<String>,
+    html: Option<String>,
+    uri_list: Vec<String>,
+    image: Option<ClipboardImage>,
+}
+
+impl ClipboardContent {
+    /// Creates an empty clipboard content.
+    pub fn new() -> Self {
+        ClipboardContent::default()
+    }
+
+    /// Creates a clipboard content with the given plain text.
+    pub fn from_text(text: impl Into<String>) -> Self {
+        ClipboardContent::new().with_text(text)
+    }
+
+    /// Creates a clipboard content with the given html.
+    pub fn from_html(html: impl Into<String>) -> Self {
+        ClipboardContent::new().with_html(html)
+    }
+
+    /// Creates a clipboard content with the given list of uris.
+    pub fn from_uri_list(uri_list: Vec<String>) -> Self {
+        ClipboardContent::new().with_uri_list(uri_list)
+    }
+
+    /// Creates a clipboard content with the given image.
+    pub fn from_image(image: ClipboardImage) -> Self {
+        ClipboardContent::new().with_image(image)
+    }
+
+    /// Builder method that offers the content as plain text.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Builder method that offers the content as html.
+    pub fn with_html(mut self, html: impl Into<String>) -> Self {
+        self.html = Some(html.into());
+        self
+    }
+
+    /// Builder method that offers the content as list of uris.
+    pub fn with_uri_list(mut self, uri_list: Vec<String>) -> Self {
+        self.uri_list = uri_list;
+        self
+    }
+
+    /// Builder method that offers the content as image.
+    pub fn with_image(mut self, image: ClipboardImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Gets the plain text if it is offered.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Gets the html if it is offered.
+    pub fn html(&self) -> Option<&str> {
+        self.html.as_deref()
+    }
+
+    /// Gets the list of uris, the list is empty if it is not offered.
+    pub fn uri_list(&self) -> &[String] {
+        &self.uri_list
+    }
+
+    /// Gets the image if it is offered.
+    pub fn image(&self) -> Option<&ClipboardImage> {
+        self.image.as_ref()
+    }
+
+    /// Gets the formats that are offered by the content.
+    pub fn formats(&self) -> Vec<ClipboardFormat> {
+        let mut formats = vec![];
+
+        if self.text.is_some() {
+            formats.push(ClipboardFormat::Text);
+        }
+
+        if self.html.is_some() {
+            formats.push(ClipboardFormat::Html);
+        }
+
+        if !self.uri_list.is_empty() {
+            formats.push(ClipboardFormat::UriList);
+        }
+
+        if self.image.is_some() {
+            formats.push(ClipboardFormat::Image);
+        }
+
+        formats
+    }
+
+    /// Returns `true` if the content is offered in the given format.
+    pub fn has_format(&self, format: ClipboardFormat) -> bool {
+        self.formats().contains(&format)
+    }
+
+    /// Returns `true` if the content is not offered in any format.
+    pub fn is_empty(&self) -> bool {
+        self.formats().is_empty()
+    }
+
+    /// Gets the content as plain text. If no plain text is offered the text is taken from the
+    /// list of uris or from the html without markup.
+    pub fn plain_text(&self) -> Option<String> {
+        if let Some(text) = &self.text {
+            return Some(text.clone());
+        }
+
+        if !self.uri_list.is_empty() {
+            return Some(self.uri_list.join("\n"));
+        }
+
+        self.html.as_deref().map(strip_html)
+    }
+
+    /// Returns a copy of the content that only offers the given formats. Plain text is derived
+    /// from the other formats if it is requested but not offered.
+    pub fn filter(&self, formats: &[ClipboardFormat]) -> ClipboardContent {
+        ClipboardContent {
+            text: if formats.contains(&ClipboardFormat::Text) {
+                self.plain_text()
+            } else {
+                None
+            },
+            html: if formats.contains(&ClipboardFormat::Html) {
+                self.html.clone()
+            } else {
+                None
+            },
+            uri_list: if formats.contains(&ClipboardFormat::UriList) {
+                self.uri_list.clone()
+            } else {
+                vec![]
+            },
+            image: if formats.contains(&ClipboardFormat::Image) {
+                self.image.clone()
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Reads a list of uris in the `text/uri-list` format. Comments are skipped.
+    pub fn parse_uri_list(uri_list: &str) -> Vec<String> {
+        uri_list
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(String::from)
+            .collect()
+    }
+
+    /// Writes the list of uris in the `text/uri-list` format.
+    pub fn format_uri_list(&self) -> String {
+        self.uri_list
+            .iter()
+            .map(|uri| format!("{}\r\n", uri))
+            .collect()
+    }
+}
+
+impl From<String> for ClipboardContent {
+    fn from(text: String) -> Self {
+        ClipboardContent::from_text(text)
+    }
+}
+
+impl From<&str> for ClipboardContent {
+    fn from(text: &str) -> Self {
+        ClipboardContent::from_text(text)
+    }
+}
+
+impl From<ClipboardImage> for ClipboardContent {
+    fn from(image: ClipboardImage) -> Self {
+        ClipboardContent::from_image(image)
+    }
+}
+
+// --- Helper --
+
+// clips the region to the given size, returns x, y, width and height in pixels
+fn clip(width: u32, height: u32, region: Rectangle) -> (usize, usize, usize, usize) {
+    let x = region.x().max(0.).min(width as f64) as usize;
+    let y = region.y().max(0.).min(height as f64) as usize;
+    let right = (region.x() + region.width()).max(0.).min(width as f64) as usize;
+    let bottom = (region.y() + region.height()).max(0.).min(height as f64) as usize;
+
+    (x, y, right.saturating_sub(x), bottom.saturating_sub(y))
+}
+
+// appends the straight RGBA pixels of the given premultiplied pixels, `channels` are the
+// indices of red, green and blue inside of a source pixel
+fn demultiply_into(image: &mut Vec<u8>, data: &[u8], channels: [usize; 3]) {
+    for pixel in data.chunks_exact(4) {
+        let a = pixel[3] as u32;
+        let demultiply = |c: u8| {
+            (c as u32 * 255 + a / 2)
+                .checked_div(a)
+                .map_or(0, |c| c.min(255) as u8)
+        };
+
+        image.extend_from_slice(&[
+            demultiply(pixel[channels[0]]),
+            demultiply(pixel[channels[1]]),
+            demultiply(pixel[channels[2]]),
+            pixel[3],
+        ]);
+    }
+}
+
+// removes the markup of the given html and decodes the basic entities
+fn strip_html(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+// --- Helper --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(
+            ClipboardContent::from_text("text").plain_text(),
+            Some("text".to_string())
+        );
+        assert_eq!(
+            ClipboardContent::from_html("<p>Tom &amp; <b>Jerry</b></p>").plain_text(),
+            Some("Tom & Jerry".to_string())
+        );
+        assert_eq!(
+            ClipboardContent::from_uri_list(vec!["file:///a".into(), "file:///b".into()])
+                .plain_text(),
+            Some("file:///a\nfile:///b".to_string())
+        );
+        assert_eq!(ClipboardContent::new().plain_text(), None);
+    }
+
+    #[test]
+    fn test_formats() {
+        let content = ClipboardContent::from_html("<b>a</b>").with_text("a");
+
+        assert_eq!(
+            content.formats(),
+            vec![ClipboardFormat::Text, ClipboardFormat::Html]
+        );
+        assert!(!content.has_format(ClipboardFormat::Image));
+
+        let text_only = ClipboardContent::from_html("<b>a</b>").filter(&[ClipboardFormat::Text]);
+        assert_eq!(text_only, ClipboardContent::from_text("a"));
+    }
+
+    #[test]
+    fn test_uri_list() {
+        let uri_list = ClipboardContent::parse_uri_list("# comment\r\nfile:///a\r\nfile:///b\r\n");
+
+        assert_eq!(
+            uri_list,
+            vec!["file:///a".to_string(), "file:///b".to_string()]
+        );
+        assert_eq!(
+            ClipboardContent::from_uri_list(uri_list).format_uri_list(),
+            "file:///a\r\nfile:///b\r\n"
+        );
+    }
+
+    #[test]
+    fn test_image() {
+        let image =
+            ClipboardImage::from_argb(2, 2, &[0xff00_0000, 0xffff_0000, 0x8000_ff00, 0]).unwrap();
+
+        assert_eq!(&image.data()[4..8], &[255, 0, 0, 255]);
+        assert_eq!(
+            image.to_argb(),
+            vec![0xff00_0000, 0xffff_0000, 0x8000_ff00, 0]
+        );
+
+        let region = image.region(Rectangle::new((1., 0.), (4., 1.)));
+        assert_eq!((region.width(), region.height()), (1, 1));
+        assert_eq!(region.to_argb(), vec![0xffff_0000]);
+
+        assert!(ClipboardImage::new(2, 2, vec![0; 3]).is_none());
+        assert_eq!(
+            ClipboardImage::from_premultiplied(1, 1, &[64, 0, 0, 128])
+                .unwrap()
+                .data(),
+            &[128, 0, 0, 128]
+        );
+
+        let frame = ClipboardImage::from_frame(
+            2,
+            1,
+            &[0, 0, 64, 128, 255, 0, 0, 255],
+            Rectangle::new((1., -1.), (4., 4.)),
+        )
+        .unwrap();
+        assert_eq!((frame.width(), frame.height()), (1, 1));
+        assert_eq!(frame.data(), &[0, 0, 255, 255]);
+        assert!(ClipboardImage::from_frame(2, 2, &[0; 4], Rectangle::default()).is_none());
+    }
+}
@@ -409,3 +409,26 @@ pub struct KeyEvent {
     /// The text string
     pub text: String,
 }
+
+/// Represents a text composition event of an input method (IME).
+///
+/// Input methods compose text in several steps, e.g. to pick CJK
+/// characters. While the composition is active, the composed (preedit)
+/// text is not part of the text input yet.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum CompositionEvent {
+    /// A new composition is started.
+    Start,
+
+    /// The preedit text of the composition is changed.
+    Update {
+        /// The current preedit text.
+        text: String,
+
+        /// The character index of the caret inside of the preedit text.
+        cursor: usize,
+    },
+
+    /// The composition is finished. Contains the committed text, that is empty if the composition was canceled.
+    Commit(String),
+}
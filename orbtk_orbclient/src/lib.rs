@@ -27,6 +27,7 @@ runner.run()
 #[macro_use]
 extern crate lazy_static;
 
+pub mod clipboard;
 pub mod event;
 pub mod prelude;
 pub mod window_adapter;
@@ -53,6 +54,9 @@ pub enum WindowRequest {
 
     /// Request to change the title of the `Windows`.
     ChangeTitle(String),
+
+    /// Request to move the candidate window of the input method to the given caret rectangle (window coordinates).
+    ChangeImeCaret(utils::Rectangle),
 }
 
 /// Used to send a request to the application shell.
@@ -98,4 +102,7 @@ pub struct WindowSettings {
 
     /// List of fonts to register.
     pub fonts: HashMap<String, &'static [u8]>,
+
+    /// List of images to register.
+    pub images: HashMap<String, render::Image>,
 }
@@ -4,11 +4,20 @@ use std::sync::{
 };
 
 #[cfg(not(target_os = "redox"))]
-use std::thread;
+use std::sync::atomic::AtomicUsize;
+
+#[cfg(not(target_os = "redox"))]
+use std::{
+    ffi::CStr,
+    os::raw::{c_int, c_void},
+    sync::{Mutex, Once},
+    thread,
+};
 
 use super::MouseState;
 use crate::{
-    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    clipboard::{ClipboardContent, ClipboardFormat, ClipboardSelection},
+    event::{ButtonState, CompositionEvent, Key, KeyEvent, MouseButton, MouseEvent},
     render::RenderContext2D,
     window_adapter::WindowAdapter,
     WindowRequest,
@@ -22,7 +31,11 @@ use orbclient::Renderer;
 #[cfg(not(target_os = "redox"))]
 use raw_window_handle::HasRawWindowHandle;
 
-use orbtk_utils::Point;
+use orbtk_utils::{Point, Rectangle};
+
+// OrbClient exchanges only plain text with the clipboard of the system. The primary selection is
+// not supported, it is kept inside of the application.
+const CLIPBOARD_FORMATS: &[ClipboardFormat] = &[ClipboardFormat::Text];
 
 /// Represents a wrapper for an orbclient window.
 
@@ -44,9 +57,52 @@ where
     close: bool,
     has_clipboard_update: bool,
     #[cfg(not(target_os = "redox"))]
+    clipboard_updates: usize,
+    composing: bool,
+    #[cfg(not(target_os = "redox"))]
     _sdl2_sync_thread: Option<thread::JoinHandle<()>>,
 }
 
+#[cfg(not(target_os = "redox"))]
+lazy_static! {
+    // Text editing events (window id, preedit text, cursor) of all windows. OrbClient
+    // drops them, so they are collected by an sdl2 event watch.
+    static ref TEXT_EDITING_EVENTS: Mutex<Vec<(u32, String, usize)>> = Mutex::new(vec![]);
+}
+
+// Number of clipboard updates of the system. The clipboard is shared by all windows, so each
+// window compares it with the number of updates it has already read.
+#[cfg(not(target_os = "redox"))]
+static CLIPBOARD_UPDATES: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(not(target_os = "redox"))]
+static EVENT_WATCH: Once = Once::new();
+
+// Event watch that collects the text editing and clipboard update events of sdl2 before they are
+// polled by OrbClient.
+#[cfg(not(target_os = "redox"))]
+unsafe extern "C" fn watch_events(
+    _userdata: *mut c_void,
+    event: *mut sdl2::sys::SDL_Event,
+) -> c_int {
+    if (*event).type_ == sdl2::sys::SDL_EventType::SDL_CLIPBOARDUPDATE as u32 {
+        CLIPBOARD_UPDATES.fetch_add(1, Ordering::SeqCst);
+    }
+
+    if (*event).type_ == sdl2::sys::SDL_EventType::SDL_TEXTEDITING as u32 {
+        let edit = (*event).edit;
+        let text = CStr::from_ptr(edit.text.as_ptr())
+            .to_string_lossy()
+            .into_owned();
+
+        if let Ok(mut events) = TEXT_EDITING_EVENTS.lock() {
+            events.push((edit.windowID, text, edit.start.max(0) as usize));
+        }
+    }
+
+    0
+}
+
 // Internal sync method for OrbClient sdl2 backend
 #[cfg(not(target_os = "redox"))]
 fn init_sync(
@@ -96,6 +152,7 @@ where
             redraw: Arc::new(AtomicBool::new(true)),
             close: false,
             has_clipboard_update: true,
+            composing: false,
         }
     }
 
@@ -111,6 +168,10 @@ where
 
         adapter.set_raw_window_handle(window.raw_window_handle());
 
+        EVENT_WATCH.call_once(|| unsafe {
+            sdl2::sys::SDL_AddEventWatch(Some(watch_events), std::ptr::null_mut());
+        });
+
         let (request_receiver, _sdl2_sync_thread) = {
             if let Some(receiver) = request_receiver {
                 let (rec, sync) = init_sync(&window, receiver);
@@ -132,19 +193,77 @@ where
             redraw,
             close: false,
             has_clipboard_update: true,
+            clipboard_updates: CLIPBOARD_UPDATES.load(Ordering::SeqCst),
+            composing: false,
         }
     }
 
-    #[cfg(not(target_os = "redox"))]
-    fn has_clipboard_update(&self) -> bool {
+    // Checks if the clipboard of the system is updated since the last read.
+    fn has_clipboard_update(&mut self) -> bool {
+        #[cfg(not(target_os = "redox"))]
+        {
+            let clipboard_updates = CLIPBOARD_UPDATES.load(Ordering::SeqCst);
+
+            if clipboard_updates != self.clipboard_updates {
+                self.clipboard_updates = clipboard_updates;
+                self.has_clipboard_update = true;
+            }
+        }
+
         self.has_clipboard_update
     }
 
-    // todo: workaround until clipboard update events available on orbital
+    // Propagates the collected text editing events of this window as composition events.
+    #[cfg(not(target_os = "redox"))]
+    fn drain_composition_events(&mut self) {
+        let id = self.window.id();
+
+        let events: Vec<(u32, String, usize)> = match TEXT_EDITING_EVENTS.lock() {
+            Ok(mut events) => {
+                let (own, other) = events.drain(..).partition(|event| event.0 == id);
+                *events = other;
+                own
+            }
+            Err(_) => return,
+        };
+
+        for (_, text, cursor) in events {
+            if !self.composing {
+                // sdl2 sends an empty text editing event after a composition is finished
+                if text.is_empty() {
+                    continue;
+                }
+
+                self.composing = true;
+                self.adapter.composition_event(CompositionEvent::Start);
+            }
+
+            self.adapter
+                .composition_event(CompositionEvent::Update { text, cursor });
+            self.update = true;
+        }
+    }
+
+    // Composition events are not available on orbital.
     #[cfg(target_os = "redox")]
-    fn has_clipboard_update(&self) -> bool {
-        true
+    fn drain_composition_events(&mut self) {}
+
+    #[cfg(not(target_os = "redox"))]
+    fn change_ime_caret(&mut self, caret: Rectangle) {
+        let mut rect = sdl2::sys::SDL_Rect {
+            x: caret.x() as i32,
+            y: caret.y() as i32,
+            w: caret.width().max(1.0) as i32,
+            h: caret.height() as i32,
+        };
+
+        unsafe {
+            sdl2::sys::SDL_SetTextInputRect(&mut rect);
+        }
     }
+
+    #[cfg(target_os = "redox")]
+    fn change_ime_caret(&mut self, _caret: Rectangle) {}
 }
 
 #[cfg(not(target_os = "redox"))]
@@ -226,30 +345,46 @@ where
 
     /// Updates the clipboard.
     pub fn update_clipboard(&mut self) {
-        let mut clipboard_value = if self.has_clipboard_update() {
+        let mut clipboard_content = if self.has_clipboard_update() {
             self.has_clipboard_update = false;
-            Some(self.window.clipboard())
+            Some(ClipboardContent::from_text(self.window.clipboard()))
         } else {
             None
         };
 
-        self.adapter.clipboard_update(&mut clipboard_value);
+        self.adapter.clipboard_update(
+            ClipboardSelection::Clipboard,
+            CLIPBOARD_FORMATS,
+            &mut clipboard_content,
+        );
 
-        if let Some(value) = clipboard_value {
-            self.window.set_clipboard(value.as_str());
+        if let Some(text) = clipboard_content.and_then(|content| content.plain_text()) {
+            self.window.set_clipboard(text.as_str());
         }
     }
 
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self) {
-        for event in self.window.events() {
+        let events = self.window.events();
+
+        // text editing events are collected while the events are polled
+        self.drain_composition_events();
+
+        for event in events {
             match event.to_option() {
                 orbclient::EventOption::Key(event) => {
                     self.push_key_event(event);
                     self.update = true;
                 }
                 orbclient::EventOption::TextInput(event) => {
-                    self.adapter.text_input(event.character.to_string());
+                    if self.composing {
+                        self.composing = false;
+                        self.adapter.composition_event(CompositionEvent::Commit(
+                            event.character.to_string(),
+                        ));
+                    } else {
+                        self.adapter.text_input(event.character.to_string());
+                    }
                     self.update = true;
                 }
                 orbclient::EventOption::Mouse(event) => {
@@ -347,6 +482,7 @@ where
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::ChangeImeCaret(caret) => self.change_ime_caret(caret),
                 }
             }
         }
@@ -355,6 +491,16 @@ where
     /// Runs update on the adapter.
     pub fn update(&mut self) {
         //super::CONSOLE.time("complete");
+        if self.adapter.poll() {
+            self.update = true;
+        }
+
+        // images of image brushes are loaded in the background and drawn once they are received
+        if self.render_context.receive_images() {
+            self.adapter.redraw();
+            self.update = true;
+        }
+
         if !self.update {
             return;
         }
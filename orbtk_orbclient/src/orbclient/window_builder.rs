@@ -2,8 +2,10 @@ use std::{collections::HashMap, sync::mpsc};
 
 use super::{Shell, Window};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    render::{Image, RenderContext2D},
+    utils::Rectangle,
+    window_adapter::WindowAdapter,
+    WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the minifb backend.
@@ -18,6 +20,7 @@ where
     always_on_top: bool,
     borderless: bool,
     fonts: HashMap<String, &'static [u8]>,
+    images: HashMap<String, Image>,
     bounds: Rectangle,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
 }
@@ -36,6 +39,7 @@ where
             always_on_top: false,
             borderless: false,
             fonts: HashMap::new(),
+            images: HashMap::new(),
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
             request_receiver: None,
         }
@@ -51,6 +55,7 @@ where
             always_on_top: settings.always_on_top,
             borderless: settings.borderless,
             fonts: settings.fonts,
+            images: settings.images,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
             request_receiver: None,
         }
@@ -92,6 +97,12 @@ where
         self
     }
 
+    /// Registers a new image with key, image brushes reference it with `url({key})`.
+    pub fn image(mut self, key: impl Into<String>, image: impl Into<Image>) -> Self {
+        self.images.insert(key.into(), image.into());
+        self
+    }
+
     /// Register a window request receiver to communicate with the window shell from outside.
     pub fn request_receiver(mut self, request_receiver: mpsc::Receiver<WindowRequest>) -> Self {
         self.request_receiver = Some(request_receiver);
@@ -130,6 +141,10 @@ where
             render_context.register_font(&family, font);
         }
 
+        for (key, image) in self.images {
+            render_context.register_image(&key, &image);
+        }
+
         self.shell.window_shells.push(Window::new(
             window,
             self.adapter,
@@ -1,4 +1,5 @@
 //! This module pre-selects commonly used OrbTk crates and put them into scope.
 pub use crate::{
-    event::*, orbclient::*, window_adapter::*, ShellRequest, WindowRequest, WindowSettings,
+    clipboard::*, event::*, orbclient::*, window_adapter::*, ShellRequest, WindowRequest,
+    WindowSettings,
 };
@@ -264,6 +264,7 @@ where
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::ChangeImeCaret(_) => {}
                 }
             }
         }
@@ -1,7 +1,7 @@
 //! This module contains traits to inject custom logic into the window shell.
 
 use crate::render::RenderContext2D;
-use crate::{event::*, utils::Point};
+use crate::{clipboard::*, event::*, utils::Point};
 
 /// The `WindowAdapter` represents the bridge to the `Shell` backend.
 /// It receives events from the `Window` and runs it's own logic.  
@@ -9,8 +9,17 @@ pub trait WindowAdapter {
     /// Sets raw window handle.
     fn set_raw_window_handle(&mut self, raw_window_handle: raw_window_handle::RawWindowHandle);
 
-    /// Used to update the clipboard, could be used to read and set the current clipboard value.
-    fn clipboard_update(&mut self, value: &mut Option<String>);
+    /// Used to update the clipboard, could be used to read and set the current clipboard content.
+    /// `selection` is the clipboard of the system that is updated and `formats` are the formats
+    /// the shell could exchange with it. The content is `Some` if the shell has read new content
+    /// from the system clipboard, if it is `Some` after the call the shell writes it to the
+    /// system clipboard.
+    fn clipboard_update(
+        &mut self,
+        selection: ClipboardSelection,
+        formats: &[ClipboardFormat],
+        content: &mut Option<ClipboardContent>,
+    );
 
     /// Is called after the window is resized.
     fn resize(&mut self, _width: f64, _height: f64) {}
@@ -30,6 +39,9 @@ pub trait WindowAdapter {
     /// Is called when the keyboard emits an text input.
     fn text_input(&mut self, _text: String) {}
 
+    /// Is called when an input method starts, updates or commits a text composition.
+    fn composition_event(&mut self, _event: CompositionEvent) {}
+
     /// Is called after the quit event of the window is called.
     fn quit_event(&mut self) {}
 
@@ -45,6 +57,15 @@ pub trait WindowAdapter {
     /// This method is called when a text string is dropped on the window.
     fn text_drop_event(&mut self, text: String);
 
+    /// Is called on each iteration of the shell loop. Returns `true` if the window needs an update.
+    fn poll(&mut self) -> bool {
+        false
+    }
+
+    /// Is called if the content of the window has to be drawn again without a change of its
+    /// widgets, e.g. after the image of an image brush is loaded.
+    fn redraw(&mut self) {}
+
     /// Runs the inner logic of the shell adapter.
     fn run(&mut self, render_context: &mut RenderContext2D);
 }
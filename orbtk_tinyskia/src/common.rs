@@ -320,6 +320,47 @@ where
     r_stops
 }
 
+/// Returns the color at the given position (0.0 to 1.0) between the given sorted stops. A repeating
+/// gradient repeats the stops after the last one.
+pub fn gradient_color(stops: &[(f64, Color)], pos: f64, repeat: bool) -> Color {
+    let (first, last) = match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Color::rgba(0, 0, 0, 0),
+    };
+
+    let mut pos = pos;
+
+    if repeat && last.0 - first.0 > f64::EPSILON {
+        pos = first.0 + (pos - first.0).rem_euclid(last.0 - first.0);
+    }
+
+    if pos <= first.0 {
+        return first.1;
+    }
+
+    for stop in stops.windows(2) {
+        let ((start, from), (end, to)) = (stop[0], stop[1]);
+
+        if pos <= end {
+            let t = if end - start > f64::EPSILON {
+                (pos - start) / (end - start)
+            } else {
+                1.0
+            };
+            let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+            return Color::rgba(
+                mix(from.r(), to.r()),
+                mix(from.g(), to.g()),
+                mix(from.b(), to.b()),
+                mix(from.a(), to.a()),
+            );
+        }
+    }
+
+    last.1
+}
+
 // Given an angle and a `Size` this function returns the ends of that gradient in the frame size gived
 pub fn linear_gradient_ends_from_angle(angle: Angle, size: Size) -> Point {
     let angle = TAU - ((angle.to_radians() + FRAC_PI_2) % TAU);
@@ -343,7 +384,44 @@ pub fn linear_gradient_ends_from_angle(angle: Angle, size: Size) -> Point {
 
 #[cfg(test)]
 mod tests {
-    use super::PathRect;
+    use super::{gradient_color, PathRect};
+    use orbtk_utils::Color;
+
+    #[test]
+    fn test_gradient_color() {
+        let stops = [
+            (0.0, Color::rgba(0, 0, 0, 255)),
+            (0.5, Color::rgba(200, 100, 0, 255)),
+            (0.5, Color::rgba(0, 0, 255, 255)),
+            (1.0, Color::rgba(0, 0, 255, 0)),
+        ];
+
+        assert_eq!(
+            gradient_color(&stops, 0.25, false),
+            Color::rgba(100, 50, 0, 255)
+        );
+        assert_eq!(
+            gradient_color(&stops, 0.5, false),
+            Color::rgba(200, 100, 0, 255)
+        );
+        assert_eq!(
+            gradient_color(&stops, 0.75, false),
+            Color::rgba(0, 0, 255, 128)
+        );
+        assert_eq!(
+            gradient_color(&stops, 1.5, false),
+            Color::rgba(0, 0, 255, 0)
+        );
+
+        let stops = [
+            (0.0, Color::rgba(0, 0, 0, 255)),
+            (0.2, Color::rgba(100, 0, 0, 255)),
+        ];
+        assert_eq!(
+            gradient_color(&stops, 0.5, true),
+            Color::rgba(50, 0, 0, 255)
+        );
+    }
 
     #[test]
     fn test_pathrect_lines() {
@@ -0,0 +1,194 @@
+use tiny_skia::{ClipMask, FillRule, Path, PathBuilder, Transform};
+
+use crate::utils::{Brush, CornerRadius, Rectangle, Thickness};
+
+use super::RenderContext2D;
+
+impl RenderContext2D {
+    /// Adds a rectangle with rounded corners to the current path. The radii are limited to
+    /// the half of the width and the height.
+    pub fn round_rect(&mut self, x: f64, y: f64, width: f64, height: f64, radius: CornerRadius) {
+        push_rounded_rect(
+            &mut self.path_builder,
+            x as f32,
+            y as f32,
+            width as f32,
+            height as f32,
+            radius,
+        );
+        self.path_rect.record_rect(x, y, width, height);
+    }
+
+    /// Draws the border inside of the given bounds with rounded corners. Each side is drawn
+    /// with its own width and brush, the brushes are ordered left, top, right and bottom.
+    pub fn draw_border(
+        &mut self,
+        bounds: Rectangle,
+        radius: CornerRadius,
+        thickness: Thickness,
+        brushes: [&Brush; 4],
+    ) {
+        let (x, y, width, height) = (bounds.x(), bounds.y(), bounds.width(), bounds.height());
+
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        let radius = radius.fit(width, height);
+        let inner_radius = CornerRadius::new(
+            radius.top_left - thickness.left.max(thickness.top),
+            radius.top_right - thickness.right.max(thickness.top),
+            radius.bottom_right - thickness.right.max(thickness.bottom),
+            radius.bottom_left - thickness.left.max(thickness.bottom),
+        );
+
+        let (l, t, r, b) = (
+            thickness.left,
+            thickness.top,
+            thickness.right,
+            thickness.bottom,
+        );
+
+        let mut builder = PathBuilder::new();
+        push_rounded_rect(
+            &mut builder,
+            x as f32,
+            y as f32,
+            width as f32,
+            height as f32,
+            radius,
+        );
+        push_rounded_rect(
+            &mut builder,
+            (x + l) as f32,
+            (y + t) as f32,
+            (width - l - r) as f32,
+            (height - t - b) as f32,
+            inner_radius,
+        );
+
+        let ring = match builder.finish() {
+            Some(ring) => ring,
+            None => return,
+        };
+
+        // a border with one brush is filled at once
+        if brushes.iter().all(|brush| *brush == brushes[0]) {
+            self.fill_border_part(&ring, brushes[0], bounds, None);
+            return;
+        }
+
+        // each side is clipped to the area between the lines from the corners of the border
+        // over the corners of the inner rectangle to the center
+        let outer = [
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+        ];
+        let inner = [
+            (x + l, y + t),
+            (x + width - r, y + t),
+            (x + width - r, y + height - b),
+            (x + l, y + height - b),
+        ];
+        let center = (x + width / 2.0, y + height / 2.0);
+
+        // corners of each side: left from bottom left to top left, top from top left to top right, ...
+        let sides = [(3, 0, l), (0, 1, t), (1, 2, r), (2, 3, b)];
+
+        for (i, (start, end, side_width)) in sides.iter().enumerate() {
+            if *side_width <= 0.0 || brushes[i].is_transparent() {
+                continue;
+            }
+
+            let mut builder = PathBuilder::new();
+            builder.move_to(outer[*start].0 as f32, outer[*start].1 as f32);
+            builder.line_to(outer[*end].0 as f32, outer[*end].1 as f32);
+            builder.line_to(inner[*end].0 as f32, inner[*end].1 as f32);
+            builder.line_to(center.0 as f32, center.1 as f32);
+            builder.line_to(inner[*start].0 as f32, inner[*start].1 as f32);
+            builder.close();
+
+            let mut clip_mask = ClipMask::new();
+            let clip_mask = builder.finish().and_then(|path| {
+                clip_mask.set_path(
+                    self.pixmap.width(),
+                    self.pixmap.height(),
+                    &path,
+                    FillRule::Winding,
+                    true,
+                )?;
+                Some(clip_mask)
+            });
+
+            if clip_mask.is_some() {
+                self.fill_border_part(&ring, brushes[i], bounds, clip_mask.as_ref());
+            }
+        }
+    }
+
+    fn fill_border_part(
+        &mut self,
+        ring: &Path,
+        brush: &Brush,
+        frame: Rectangle,
+        clip_mask: Option<&ClipMask>,
+    ) {
+        if brush.is_transparent()
+            || (clip_mask.is_none()
+                && self.fill_pattern_brush(ring, FillRule::EvenOdd, brush, frame))
+        {
+            return;
+        }
+
+        let paint = Self::paint_from_brush(brush, frame, self.config.alpha);
+        self.pixmap.fill_path(
+            ring,
+            &paint,
+            FillRule::EvenOdd,
+            Transform::identity(),
+            clip_mask,
+        );
+    }
+}
+
+// adds a closed rectangle with rounded corners to the path
+pub(super) fn push_rounded_rect(
+    builder: &mut PathBuilder,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    radius: CornerRadius,
+) {
+    if width <= 0.0 || height <= 0.0 {
+        return;
+    }
+
+    let radius = radius.fit(width as f64, height as f64);
+
+    if radius.is_zero() {
+        builder.push_rect(x, y, width, height);
+        return;
+    }
+
+    let (tl, tr, br, bl) = (
+        radius.top_left as f32,
+        radius.top_right as f32,
+        radius.bottom_right as f32,
+        radius.bottom_left as f32,
+    );
+    let r = x + width;
+    let b = y + height;
+    builder.move_to(x + tl, y);
+    builder.line_to(r - tr, y);
+    builder.quad_to(r, y, r, y + tr);
+    builder.line_to(r, b - br);
+    builder.quad_to(r, b, r - br, b);
+    builder.line_to(x + bl, b);
+    builder.quad_to(x, b, x, b - bl);
+    builder.line_to(x, y + tl);
+    builder.quad_to(x, y, x + tl, y);
+    builder.close();
+}
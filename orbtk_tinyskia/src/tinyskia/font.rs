@@ -57,6 +57,11 @@ impl Font {
             .ok_or("Could not load font from bytes")
     }
 
+    /// Gets the underlying rusttype font.
+    pub(crate) fn inner(&self) -> &rusttype::Font<'static> {
+        &self.inner
+    }
+
     /// Measures the text width and height (given in pixels).
     pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
         let scale = rusttype::Scale::uniform(size as f32);
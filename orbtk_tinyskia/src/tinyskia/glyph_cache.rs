@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use tiny_skia::{Paint, Pixmap, Shader};
+
+use super::Font;
+
+/// Number of horizontal and vertical subpixel positions a glyph is rasterized for.
+const SUBPIXEL_STEPS: f32 = 4.0;
+
+/// Maximum number of coverage masks, before the cache is flushed.
+const MAX_GLYPHS: usize = 4096;
+
+/// Maximum number of memoized text measurements, before the cache is flushed.
+const MAX_MEASUREMENTS: usize = 4096;
+
+/// Identifies a rasterized glyph of a font family inside of the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct GlyphKey {
+    size: u32,
+    glyph_id: u16,
+    subpixel: (u8, u8),
+}
+
+/// Coverage mask of a rasterized glyph.
+#[derive(Debug, Clone, Default)]
+struct GlyphMask {
+    left: i32,
+    top: i32,
+    width: u32,
+    height: u32,
+    coverage: Vec<u8>,
+}
+
+/// Splits the given position in a whole pixel part and a quantized subpixel offset.
+fn split_position(position: f32) -> (i32, u8) {
+    let whole = position.floor();
+    let step = ((position - whole) * SUBPIXEL_STEPS).round();
+
+    // rounding up the fraction can reach the next whole pixel
+    if step >= SUBPIXEL_STEPS {
+        (whole as i32 + 1, 0)
+    } else {
+        (whole as i32, step as u8)
+    }
+}
+
+/// Caches the coverage masks of rasterized glyphs and the results of
+/// text measurements.
+///
+/// Glyphs are keyed by font family, font size, glyph id and subpixel
+/// offset. Text drawn with a solid color is blitted from the cached
+/// masks. Other shaders fall back to path rendering.
+#[derive(Debug, Default)]
+pub struct GlyphCache {
+    glyphs: HashMap<String, HashMap<GlyphKey, Option<GlyphMask>>>,
+    glyphs_len: usize,
+    measurements: HashMap<String, HashMap<u32, HashMap<String, (f64, f64)>>>,
+    measurements_len: usize,
+}
+
+impl GlyphCache {
+    /// Creates a new empty glyph cache.
+    pub fn new() -> Self {
+        GlyphCache::default()
+    }
+
+    /// Removes all cached glyphs and measurements.
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+        self.glyphs_len = 0;
+        self.measurements.clear();
+        self.measurements_len = 0;
+    }
+
+    /// Gets the number of cached glyph masks.
+    pub fn len(&self) -> usize {
+        self.glyphs_len
+    }
+
+    /// Returns `true` if no glyph mask is cached.
+    pub fn is_empty(&self) -> bool {
+        self.glyphs_len == 0
+    }
+
+    /// Measures the text width and height (given in pixels). The
+    /// result is memoized per font family, font size and text.
+    pub fn measure_text(&mut self, family: &str, font: &Font, text: &str, size: f64) -> (f64, f64) {
+        let size_key = (size as f32).to_bits();
+
+        if let Some(metrics) = self
+            .measurements
+            .get(family)
+            .and_then(|sizes| sizes.get(&size_key))
+            .and_then(|texts| texts.get(text))
+        {
+            return *metrics;
+        }
+
+        let metrics = font.measure_text(text, size);
+
+        if self.measurements_len >= MAX_MEASUREMENTS {
+            self.measurements.clear();
+            self.measurements_len = 0;
+        }
+
+        self.measurements
+            .entry(family.to_string())
+            .or_insert_with(HashMap::new)
+            .entry(size_key)
+            .or_insert_with(HashMap::new)
+            .insert(text.to_string(), metrics);
+        self.measurements_len += 1;
+
+        metrics
+    }
+
+    /// Renders the given text by blitting the cached glyph masks to the pixmap.
+    pub fn render_text(
+        &mut self,
+        family: &str,
+        font: &Font,
+        font_size: f64,
+        paint: &Paint,
+        pixmap: &mut Pixmap,
+        position: (f64, f64),
+        text: &str,
+    ) {
+        let color = match paint.shader {
+            Shader::SolidColor(color) => color,
+            _ => {
+                font.render_text(font_size, paint, pixmap, position, text);
+                return;
+            }
+        };
+
+        if self.glyphs_len >= MAX_GLYPHS {
+            self.glyphs.clear();
+            self.glyphs_len = 0;
+        }
+
+        if !self.glyphs.contains_key(family) {
+            self.glyphs.insert(family.to_string(), HashMap::new());
+        }
+        let glyphs = self.glyphs.get_mut(family).unwrap();
+
+        let scale = rusttype::Scale::uniform(font_size as f32);
+        let v_metrics = font.inner().v_metrics(scale);
+        let offset = rusttype::point(0.0, v_metrics.ascent);
+        let size = scale.x.to_bits();
+
+        // premultiplied source color
+        let alpha = color.alpha();
+        let source = [
+            color.red() * alpha,
+            color.green() * alpha,
+            color.blue() * alpha,
+            alpha,
+        ];
+
+        for glyph in font.inner().layout(text, scale, offset) {
+            let glyph_position = glyph.position();
+            let (x, sub_x) = split_position(glyph_position.x + position.0 as f32);
+            let (y, sub_y) = split_position(glyph_position.y + position.1 as f32);
+
+            let key = GlyphKey {
+                size,
+                glyph_id: glyph.id().0,
+                subpixel: (sub_x, sub_y),
+            };
+
+            let glyphs_len = &mut self.glyphs_len;
+            let mask = glyphs.entry(key).or_insert_with(|| {
+                *glyphs_len += 1;
+                let glyph = glyph.into_unpositioned().positioned(rusttype::point(
+                    f32::from(sub_x) / SUBPIXEL_STEPS,
+                    f32::from(sub_y) / SUBPIXEL_STEPS,
+                ));
+
+                glyph.pixel_bounding_box().map(|bbox| {
+                    let width = bbox.width() as u32;
+                    let height = bbox.height() as u32;
+                    let mut coverage = vec![0; (width * height) as usize];
+                    glyph.draw(|gx, gy, v| {
+                        coverage[(gy * width + gx) as usize] = (v.min(1.0) * 255.0) as u8;
+                    });
+
+                    GlyphMask {
+                        left: bbox.min.x,
+                        top: bbox.min.y,
+                        width,
+                        height,
+                        coverage,
+                    }
+                })
+            });
+
+            if let Some(mask) = mask {
+                blit_mask(pixmap, mask, x, y, source);
+            }
+        }
+    }
+}
+
+/// Blends the given premultiplied source color through the coverage mask
+/// onto the pixmap (source over).
+fn blit_mask(pixmap: &mut Pixmap, mask: &GlyphMask, x: i32, y: i32, source: [f32; 4]) {
+    let pixmap_width = pixmap.width() as i32;
+    let pixmap_height = pixmap.height() as i32;
+    let data = pixmap.data_mut();
+
+    for my in 0..mask.height as i32 {
+        let py = y + mask.top + my;
+        if py < 0 || py >= pixmap_height {
+            continue;
+        }
+
+        for mx in 0..mask.width as i32 {
+            let px = x + mask.left + mx;
+            if px < 0 || px >= pixmap_width {
+                continue;
+            }
+
+            let coverage = mask.coverage[(my * mask.width as i32 + mx) as usize];
+            if coverage == 0 {
+                continue;
+            }
+
+            let coverage = f32::from(coverage) / 255.0;
+            let inverse_alpha = 1.0 - source[3] * coverage;
+            let index = ((py * pixmap_width + px) * 4) as usize;
+
+            for (channel, source) in source.iter().enumerate() {
+                let destination = f32::from(data[index + channel]) / 255.0;
+                let value = source * coverage + destination * inverse_alpha;
+                data[index + channel] = (value * 255.0).round().min(255.0) as u8;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_position() {
+        assert_eq!(split_position(10.0), (10, 0));
+        assert_eq!(split_position(10.25), (10, 1));
+        assert_eq!(split_position(10.5), (10, 2));
+        assert_eq!(split_position(10.95), (11, 0));
+        assert_eq!(split_position(-0.25), (-1, 3));
+    }
+
+    #[test]
+    fn test_blit_mask() {
+        let mut pixmap = Pixmap::new(2, 2).unwrap();
+        let mask = GlyphMask {
+            left: 0,
+            top: 0,
+            width: 2,
+            height: 1,
+            coverage: vec![255, 0],
+        };
+
+        blit_mask(&mut pixmap, &mask, 0, 1, [1.0, 0.0, 0.0, 1.0]);
+
+        let data = pixmap.data();
+        assert_eq!(&data[0..8], &[0; 8]);
+        assert_eq!(&data[8..12], &[255, 0, 0, 255]);
+        assert_eq!(&data[12..16], &[0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,90 @@
+use tiny_skia::{ClipMask, FillRule, Path, Pixmap, PixmapPaint, Transform};
+
+use crate::{common::*, utils::*};
+
+use super::RenderContext2D;
+
+impl RenderContext2D {
+    // Fills the path with a conic gradient. tiny-skia has no shader for conic gradients, so the
+    // colors are computed for each pixel of the frame and drawn through a clip mask of the path.
+    // Returns `false` if the brush is not a conic gradient.
+    pub(super) fn fill_conic_gradient(
+        &mut self,
+        path: &Path,
+        fill_rule: FillRule,
+        brush: &Brush,
+        frame: Rectangle,
+    ) -> bool {
+        let (coords, stops, repeat) = match brush {
+            Brush::Gradient(Gradient {
+                kind: GradientKind::Conic(coords),
+                stops,
+                repeat,
+            }) => (coords, stops, *repeat),
+            _ => return false,
+        };
+
+        // only the visible part of the frame is computed
+        let left = frame.x().floor().max(0.0);
+        let top = frame.y().floor().max(0.0);
+        let right = (frame.x() + frame.width())
+            .ceil()
+            .min(self.pixmap.width() as f64);
+        let bottom = (frame.y() + frame.height())
+            .ceil()
+            .min(self.pixmap.height() as f64);
+
+        if right <= left || bottom <= top {
+            return true;
+        }
+
+        let mut pixmap = match Pixmap::new((right - left) as u32, (bottom - top) as u32) {
+            Some(pixmap) => pixmap,
+            None => return true,
+        };
+
+        let stops = build_unit_percent_gradient(stops, 1.0, |p, c| (p, c));
+        let center = frame.position() + coords.center(frame.size());
+        let alpha = self.config.alpha as f64;
+        let width = pixmap.width() as usize;
+
+        for (i, pixel) in pixmap.data_mut().chunks_mut(4).enumerate() {
+            let point = Point::new(
+                left + (i % width) as f64 + 0.5,
+                top + (i / width) as f64 + 0.5,
+            );
+            let color = gradient_color(&stops, coords.unit_percent(center, point), repeat);
+            let a = color.a() as f64 / 255.0 * alpha;
+
+            // premultiplied and with swapped red and blue like the colors of the other paints
+            pixel[0] = (color.b() as f64 * a).round() as u8;
+            pixel[1] = (color.g() as f64 * a).round() as u8;
+            pixel[2] = (color.r() as f64 * a).round() as u8;
+            pixel[3] = (255.0 * a).round() as u8;
+        }
+
+        let mut clip_mask = ClipMask::new();
+
+        if clip_mask
+            .set_path(
+                self.pixmap.width(),
+                self.pixmap.height(),
+                path,
+                fill_rule,
+                true,
+            )
+            .is_some()
+        {
+            self.pixmap.draw_pixmap(
+                left as i32,
+                top as i32,
+                pixmap.as_ref(),
+                &PixmapPaint::default(),
+                Transform::identity(),
+                Some(&clip_mask),
+            );
+        }
+
+        true
+    }
+}
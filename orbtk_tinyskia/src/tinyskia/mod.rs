@@ -10,13 +10,20 @@ use tiny_skia::{
     ClipMask, FillRule, Paint, PathBuilder, Pixmap, PixmapPaint, Shader, Stroke, Transform,
 };
 
+use self::pattern::{is_pattern_brush, ImageLoader};
 use crate::{common::*, utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
 
 pub use self::font::*;
+pub use self::glyph_cache::GlyphCache;
 pub use self::image::Image;
 
+mod border;
 mod font;
+mod glyph_cache;
+mod gradient;
 mod image;
+mod pattern;
+mod shadow;
 
 #[derive(Debug)]
 struct State {
@@ -38,6 +45,9 @@ pub struct RenderContext2D {
     config: RenderConfig,
     fill_paint: Paint<'static>,
     fonts: HashMap<String, Font>,
+    glyph_cache: GlyphCache,
+    images: HashMap<String, Option<Pixmap>>,
+    image_loader: ImageLoader,
     path_builder: PathBuilder,
     path_rect: PathRect,
     pixmap: Pixmap,
@@ -61,6 +71,9 @@ impl RenderContext2D {
                 1.0,
             ),
             fonts: HashMap::new(),
+            glyph_cache: GlyphCache::new(),
+            images: HashMap::new(),
+            image_loader: ImageLoader::default(),
             path_builder: PathBuilder::new(),
             path_rect: PathRect::new(None),
             pixmap,
@@ -83,7 +96,7 @@ impl RenderContext2D {
                 Shader::SolidColor(color)
             }
             Brush::Gradient(Gradient {
-                kind: GradientKind::Linear(coords),
+                kind,
                 stops,
                 repeat,
             }) => {
@@ -91,49 +104,86 @@ impl RenderContext2D {
                     true => tiny_skia::SpreadMode::Repeat,
                     false => tiny_skia::SpreadMode::Pad,
                 };
-                let (start, end) = match coords {
-                    LinearGradientCoords::Ends { start, end } => {
-                        (*start + frame.position(), *end + frame.position())
-                    }
-                    LinearGradientCoords::Angle {
-                        angle,
-                        displacement,
-                    } => {
-                        let z = linear_gradient_ends_from_angle(*angle, frame.size());
-                        let disp = displacement.pixels(frame.size());
-                        let start = frame.position() + frame.size() / 2.0 + -z + disp;
-                        let end = frame.position() + frame.size() / 2.0 + z + disp;
-                        (start, end)
-                    }
-                    LinearGradientCoords::Direction {
-                        direction,
-                        displacement,
-                    } => {
-                        let width = frame.width();
-                        let height = frame.height();
-                        let (mut start, mut end) = direction.cross(width, height);
-                        let displacement = displacement.pixels(frame.size());
-                        start = start + frame.position() + displacement;
-                        end = end + frame.position() + displacement;
-                        (start, end)
-                    }
-                };
-                let g_stops = build_unit_percent_gradient(stops, end.distance(start), |p, c| {
+                let to_stop = |p: f64, c: Color| {
                     let mut color = tiny_skia::Color::from_rgba8(c.b(), c.g(), c.r(), c.a());
                     color.set_alpha(color.alpha() * global_alpha);
                     tiny_skia::GradientStop::new(p as f32, color)
-                });
-                let tstart = tiny_skia::Point::from_xy(start.x() as f32, start.y() as f32);
-                let tend = tiny_skia::Point::from_xy(end.x() as f32, end.y() as f32);
-                tiny_skia::LinearGradient::new(
-                    tstart,
-                    tend,
-                    g_stops,
-                    spread,
-                    tiny_skia::Transform::identity(),
-                )
-                .unwrap_or(Shader::SolidColor(tiny_skia::Color::WHITE))
+                };
+                match kind {
+                    GradientKind::Linear(coords) => {
+                        let (start, end) = match coords {
+                            LinearGradientCoords::Ends { start, end } => {
+                                (*start + frame.position(), *end + frame.position())
+                            }
+                            LinearGradientCoords::Angle {
+                                angle,
+                                displacement,
+                            } => {
+                                let z = linear_gradient_ends_from_angle(*angle, frame.size());
+                                let disp = displacement.pixels(frame.size());
+                                let start = frame.position() + frame.size() / 2.0 + -z + disp;
+                                let end = frame.position() + frame.size() / 2.0 + z + disp;
+                                (start, end)
+                            }
+                            LinearGradientCoords::Direction {
+                                direction,
+                                displacement,
+                            } => {
+                                let width = frame.width();
+                                let height = frame.height();
+                                let (mut start, mut end) = direction.cross(width, height);
+                                let displacement = displacement.pixels(frame.size());
+                                start = start + frame.position() + displacement;
+                                end = end + frame.position() + displacement;
+                                (start, end)
+                            }
+                        };
+                        let g_stops =
+                            build_unit_percent_gradient(stops, end.distance(start), to_stop);
+                        let tstart = tiny_skia::Point::from_xy(start.x() as f32, start.y() as f32);
+                        let tend = tiny_skia::Point::from_xy(end.x() as f32, end.y() as f32);
+                        tiny_skia::LinearGradient::new(
+                            tstart,
+                            tend,
+                            g_stops,
+                            spread,
+                            tiny_skia::Transform::identity(),
+                        )
+                        .unwrap_or(Shader::SolidColor(tiny_skia::Color::WHITE))
+                    }
+                    GradientKind::Radial(coords) => {
+                        let center = frame.position() + coords.center(frame.size());
+                        let (radius_x, radius_y) = coords.radii(frame.size());
+                        let g_stops = build_unit_percent_gradient(stops, radius_x, to_stop);
+                        // the gradient is defined on a unit circle that is scaled to the ellipse
+                        let origin = tiny_skia::Point::from_xy(0.0, 0.0);
+                        Transform::from_row(
+                            radius_x as f32,
+                            0.0,
+                            0.0,
+                            radius_y as f32,
+                            center.x() as f32,
+                            center.y() as f32,
+                        )
+                        .and_then(|transform| {
+                            tiny_skia::RadialGradient::new(
+                                origin, origin, 1.0, g_stops, spread, transform,
+                            )
+                        })
+                        .unwrap_or(Shader::SolidColor(tiny_skia::Color::WHITE))
+                    }
+                    // conic gradients are drawn by `fill_pattern_brush`, text and other
+                    // shapes use the first color
+                    GradientKind::Conic(_) => {
+                        let c = stops.first().map(|s| s.color).unwrap_or_default();
+                        let mut color = tiny_skia::Color::from_rgba8(c.b(), c.g(), c.r(), c.a());
+                        color.set_alpha(color.alpha() * global_alpha);
+                        Shader::SolidColor(color)
+                    }
+                }
             }
+            // images are drawn by `fill_pattern_brush`, text could not be filled with them
+            Brush::Image(_) => Shader::SolidColor(tiny_skia::Color::TRANSPARENT),
         };
         Paint {
             shader,
@@ -153,6 +203,21 @@ impl RenderContext2D {
         }
     }
 
+    /// Returns `true` if a font with the given family is registered.
+    pub fn has_font(&self, family: &str) -> bool {
+        self.fonts.contains_key(family)
+    }
+
+    /// Returns the width of the pixmap.
+    pub fn width(&self) -> f64 {
+        self.pixmap.width() as f64
+    }
+
+    /// Returns the height of the pixmap.
+    pub fn height(&self) -> f64 {
+        self.pixmap.height() as f64
+    }
+
     /// Resizes pixmap with new height and width
     pub fn resize(&mut self, width: f64, height: f64) {
         self.pixmap = Pixmap::new(width as u32, height as u32).unwrap();
@@ -172,6 +237,20 @@ impl RenderContext2D {
         if width > 0.0 && height > 0.0 {
             self.path_rect.record_rect(x, y, width, height);
             let rect = self.path_rect.get_rect().unwrap();
+            if is_pattern_brush(&self.config.fill_style) {
+                let mut builder = PathBuilder::new();
+                builder.push_rect(
+                    (x as f32).floor(),
+                    (y as f32).floor(),
+                    width as f32,
+                    height as f32,
+                );
+                if let Some(path) = builder.finish() {
+                    let brush = self.config.fill_style.clone();
+                    self.fill_pattern_brush(&path, FillRule::Winding, &brush, rect);
+                }
+                return;
+            }
             self.fill_paint =
                 Self::paint_from_brush(&self.config.fill_style, rect, self.config.alpha as f32);
             self.pixmap.fill_rect(
@@ -438,6 +517,10 @@ impl RenderContext2D {
         self.fill_paint =
             Self::paint_from_brush(&self.config.fill_style, rect, self.config.alpha as f32);
         if let Some(path) = self.path_builder.clone().finish() {
+            let brush = self.config.fill_style.clone();
+            if self.fill_pattern_brush(&path, FillRule::EvenOdd, &brush, rect) {
+                return;
+            }
             self.pixmap.fill_path(
                 &path,
                 &self.fill_paint,
@@ -461,7 +544,9 @@ impl RenderContext2D {
             Self::paint_from_brush(&self.config.fill_style, rect, self.config.alpha as f32);
 
         if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            font.render_text(
+            self.glyph_cache.render_text(
+                &self.config.font_config.family,
+                font,
                 self.config.font_config.font_size,
                 &self.fill_paint,
                 &mut self.pixmap,
@@ -499,7 +584,12 @@ impl RenderContext2D {
         }
 
         if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let (width, height) = font.measure_text(text, self.config.font_config.font_size);
+            let (width, height) = self.glyph_cache.measure_text(
+                &self.config.font_config.family,
+                font,
+                text,
+                self.config.font_config.font_size,
+            );
 
             text_metrics.width = width;
             text_metrics.height = height;
@@ -531,13 +621,21 @@ impl RenderContext2D {
         self.stroke_paint =
             Self::paint_from_brush(&self.config.stroke_style, rect, self.config.alpha as f32);
         if let Some(path) = self.path_builder.clone().finish() {
+            let stroke = Stroke {
+                width: self.config.line_width as f32,
+                ..Default::default()
+            };
+            if is_pattern_brush(&self.config.stroke_style) {
+                let brush = self.config.stroke_style.clone();
+                if let Some(outline) = path.stroke(&stroke, 1.0) {
+                    self.fill_pattern_brush(&outline, FillRule::Winding, &brush, rect);
+                }
+                return;
+            }
             self.pixmap.stroke_path(
                 &path,
                 &self.stroke_paint,
-                &Stroke {
-                    width: self.config.line_width as f32,
-                    ..Default::default()
-                },
+                &stroke,
                 Transform::identity(),
                 None,
             );
@@ -0,0 +1,227 @@
+use std::{
+    collections::HashSet,
+    ptr,
+    sync::mpsc::{channel, Receiver, Sender},
+    thread,
+};
+
+use tiny_skia::{
+    ClipMask, FillRule, FilterQuality, Paint, Path, PathBuilder, Pattern, Pixmap, SpreadMode,
+    Transform,
+};
+
+use crate::utils::*;
+
+use super::{image::os_path, Image, RenderContext2D};
+
+impl RenderContext2D {
+    /// Registers an image with the given key. Image brushes reference it with `url({key})`.
+    pub fn register_image(&mut self, key: &str, image: &Image) {
+        self.images
+            .insert(key.to_string(), pixmap_from_image(image));
+    }
+
+    /// Returns `true` if an image with the given key is registered.
+    pub fn has_image(&self, key: &str) -> bool {
+        matches!(self.images.get(key), Some(Some(_)))
+    }
+
+    /// Starts to load the image of the given path in the background, if it is not registered
+    /// or loading yet. Image brushes with the path as source draw nothing until the image is
+    /// received by `receive_images`.
+    pub fn load_image(&mut self, path: &str) {
+        if self.images.contains_key(path) || !self.image_loader.loading.insert(path.to_string()) {
+            return;
+        }
+
+        let sender = self.image_loader.sender.clone();
+        let path = path.to_string();
+
+        thread::spawn(move || {
+            let image = Image::from_path(os_path(path.clone())).ok();
+            let pixmap = image.and_then(|image| pixmap_from_image(&image));
+
+            // the render context could be dropped meanwhile
+            let _ = sender.send((path, pixmap));
+        });
+    }
+
+    /// Adds the images that are loaded in the background. Returns `true` if an image was added,
+    /// the frame should then be drawn again.
+    pub fn receive_images(&mut self) -> bool {
+        let mut received = false;
+
+        for (path, pixmap) in self.image_loader.receiver.try_iter() {
+            self.image_loader.loading.remove(&path);
+
+            // an image registered meanwhile is kept
+            self.images.entry(path).or_insert(pixmap);
+            received = true;
+        }
+
+        received
+    }
+
+    // Fills the path with brushes that could not be expressed as paint of the render context.
+    // Returns `false` for all other brushes.
+    pub(super) fn fill_pattern_brush(
+        &mut self,
+        path: &Path,
+        fill_rule: FillRule,
+        brush: &Brush,
+        frame: Rectangle,
+    ) -> bool {
+        match brush {
+            Brush::Image(image_brush) => {
+                self.fill_image_brush(path, fill_rule, image_brush, frame);
+                true
+            }
+            _ => self.fill_conic_gradient(path, fill_rule, brush, frame),
+        }
+    }
+
+    fn fill_image_brush(
+        &mut self,
+        path: &Path,
+        fill_rule: FillRule,
+        brush: &ImageBrush,
+        frame: Rectangle,
+    ) {
+        // images that are not registered are loaded from their path in the background
+        let image = match self.images.get(&brush.source) {
+            Some(Some(image)) => image,
+            Some(None) => return,
+            None => {
+                self.load_image(&brush.source);
+                return;
+            }
+        };
+
+        let image_size = Size::new(image.width() as f64, image.height() as f64);
+        let tile = brush.tile_size(frame.size(), image_size);
+        let origin = frame.position() + brush.offset;
+
+        let transform = match Transform::from_row(
+            (tile.width() / image_size.width()) as f32,
+            0.0,
+            0.0,
+            (tile.height() / image_size.height()) as f32,
+            origin.x() as f32,
+            origin.y() as f32,
+        ) {
+            Some(transform) => transform,
+            None => return,
+        };
+
+        let paint = Paint {
+            shader: Pattern::new(
+                image.as_ref(),
+                SpreadMode::Repeat,
+                FilterQuality::Bicubic,
+                self.config.alpha,
+                transform,
+            ),
+            anti_alias: true,
+            ..Default::default()
+        };
+
+        // the pattern repeats in both directions, the other modes are limited by a clip mask
+        let area = match brush.repeat {
+            ImageRepeat::Repeat => None,
+            ImageRepeat::RepeatX => Some((frame.x(), origin.y(), frame.width(), tile.height())),
+            ImageRepeat::RepeatY => Some((origin.x(), frame.y(), tile.width(), frame.height())),
+            ImageRepeat::NoRepeat => Some((origin.x(), origin.y(), tile.width(), tile.height())),
+        };
+
+        let clip_mask = match area {
+            Some((x, y, width, height)) => {
+                let mut builder = PathBuilder::new();
+                builder.push_rect(x as f32, y as f32, width as f32, height as f32);
+
+                let mut clip_mask = ClipMask::new();
+                let clip_path = match builder.finish() {
+                    Some(clip_path) => clip_path,
+                    None => return,
+                };
+
+                if clip_mask
+                    .set_path(
+                        self.pixmap.width(),
+                        self.pixmap.height(),
+                        &clip_path,
+                        FillRule::Winding,
+                        true,
+                    )
+                    .is_none()
+                {
+                    return;
+                }
+
+                Some(clip_mask)
+            }
+            None => None,
+        };
+
+        self.pixmap.fill_path(
+            path,
+            &paint,
+            fill_rule,
+            Transform::identity(),
+            clip_mask.as_ref(),
+        );
+    }
+}
+
+// loads the images of image brushes in background threads
+pub(super) struct ImageLoader {
+    loading: HashSet<String>,
+    sender: Sender<(String, Option<Pixmap>)>,
+    receiver: Receiver<(String, Option<Pixmap>)>,
+}
+
+impl Default for ImageLoader {
+    fn default() -> Self {
+        let (sender, receiver) = channel();
+
+        ImageLoader {
+            loading: HashSet::new(),
+            sender,
+            receiver,
+        }
+    }
+}
+
+// returns `true` for brushes that are drawn by `fill_pattern_brush`
+pub(super) fn is_pattern_brush(brush: &Brush) -> bool {
+    matches!(
+        brush,
+        Brush::Image(_)
+            | Brush::Gradient(Gradient {
+                kind: GradientKind::Conic(_),
+                ..
+            })
+    )
+}
+
+// converts the colors of the image to the premultiplied colors of a pixmap
+fn pixmap_from_image(image: &Image) -> Option<Pixmap> {
+    let mut pixmap = Pixmap::new(image.width() as u32, image.height() as u32)?;
+
+    unsafe {
+        ptr::copy_nonoverlapping(
+            image.data().as_ptr() as *const u8,
+            pixmap.data_mut().as_mut_ptr(),
+            image.data().len() * 4,
+        )
+    }
+
+    for pixel in pixmap.data_mut().chunks_mut(4) {
+        let a = pixel[3] as u32;
+
+        for c in pixel.iter_mut().take(3) {
+            *c = ((*c as u32 * a + 127) / 255) as u8;
+        }
+    }
+
+    Some(pixmap)
+}
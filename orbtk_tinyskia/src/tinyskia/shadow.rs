@@ -0,0 +1,241 @@
+use tiny_skia::{ClipMask, FillRule, Paint, PathBuilder, Pixmap, PixmapPaint, Transform};
+
+use crate::utils::{CornerRadius, Shadow};
+
+use super::{border::push_rounded_rect, RenderContext2D};
+
+impl RenderContext2D {
+    /// Draws the shadow of a rectangle with the given corner radius. An outer shadow is only
+    /// visible outside of the rectangle, an inset shadow only inside of it.
+    pub fn draw_box_shadow(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radius: CornerRadius,
+        shadow: &Shadow,
+    ) {
+        if width <= 0.0 || height <= 0.0 || shadow.color.a() == 0 {
+            return;
+        }
+
+        let (x, y, width, height) = (x as f32, y as f32, width as f32, height as f32);
+        let spread = shadow.spread as f32;
+        let (offset_x, offset_y) = (shadow.offset.x() as f32, shadow.offset.y() as f32);
+
+        // space around the shape that is reached by the blur
+        let margin = shadow.blur.ceil() as f32 + 1.0;
+
+        let mut color = tiny_skia::Color::from_rgba8(
+            shadow.color.b(),
+            shadow.color.g(),
+            shadow.color.r(),
+            shadow.color.a(),
+        );
+        color.set_alpha(color.alpha() * self.config.alpha);
+
+        let mut paint = Paint::default();
+        paint.set_color(color);
+        paint.anti_alias = true;
+
+        let (pixmap_x, pixmap_y, mut pixmap, clip_fill_rule) = if shadow.inset {
+            let pixmap_width = width + 2.0 * margin;
+            let pixmap_height = height + 2.0 * margin;
+
+            // fill everything except of the shrunken and moved shape
+            let mut builder = PathBuilder::new();
+            builder.push_rect(0.0, 0.0, pixmap_width, pixmap_height);
+            push_rounded_rect(
+                &mut builder,
+                margin + offset_x + spread,
+                margin + offset_y + spread,
+                width - 2.0 * spread,
+                height - 2.0 * spread,
+                radius.grow(-shadow.spread),
+            );
+
+            let mut pixmap =
+                match Pixmap::new(pixmap_width.ceil() as u32, pixmap_height.ceil() as u32) {
+                    Some(pixmap) => pixmap,
+                    None => return,
+                };
+
+            if let Some(path) = builder.finish() {
+                pixmap.fill_path(
+                    &path,
+                    &paint,
+                    FillRule::EvenOdd,
+                    Transform::identity(),
+                    None,
+                );
+            }
+
+            (x - margin, y - margin, pixmap, FillRule::Winding)
+        } else {
+            let shadow_width = width + 2.0 * spread;
+            let shadow_height = height + 2.0 * spread;
+
+            if shadow_width <= 0.0 || shadow_height <= 0.0 {
+                return;
+            }
+
+            let mut pixmap = match Pixmap::new(
+                (shadow_width + 2.0 * margin).ceil() as u32,
+                (shadow_height + 2.0 * margin).ceil() as u32,
+            ) {
+                Some(pixmap) => pixmap,
+                None => return,
+            };
+
+            let mut builder = PathBuilder::new();
+            push_rounded_rect(
+                &mut builder,
+                margin,
+                margin,
+                shadow_width,
+                shadow_height,
+                radius.grow(shadow.spread),
+            );
+
+            if let Some(path) = builder.finish() {
+                pixmap.fill_path(
+                    &path,
+                    &paint,
+                    FillRule::Winding,
+                    Transform::identity(),
+                    None,
+                );
+            }
+
+            (
+                x - spread - margin + offset_x,
+                y - spread - margin + offset_y,
+                pixmap,
+                FillRule::EvenOdd,
+            )
+        };
+
+        let blur_radius = (shadow.blur / 2.0).round() as usize;
+
+        if blur_radius > 0 {
+            let (pixmap_width, pixmap_height) = (pixmap.width() as usize, pixmap.height() as usize);
+            blur(pixmap.data_mut(), pixmap_width, pixmap_height, blur_radius);
+        }
+
+        // an outer shadow is clipped to the outside of the shape, an inset shadow to the inside
+        let mut builder = PathBuilder::new();
+
+        if !shadow.inset {
+            builder.push_rect(
+                0.0,
+                0.0,
+                self.pixmap.width() as f32,
+                self.pixmap.height() as f32,
+            );
+        }
+
+        push_rounded_rect(&mut builder, x, y, width, height, radius);
+
+        let mut clip_mask = ClipMask::new();
+        let clip_mask = builder.finish().and_then(|path| {
+            clip_mask.set_path(
+                self.pixmap.width(),
+                self.pixmap.height(),
+                &path,
+                clip_fill_rule,
+                true,
+            )?;
+            Some(clip_mask)
+        });
+
+        self.pixmap.draw_pixmap(
+            pixmap_x.round() as i32,
+            pixmap_y.round() as i32,
+            pixmap.as_ref(),
+            &PixmapPaint::default(),
+            Transform::identity(),
+            clip_mask.as_ref(),
+        );
+    }
+}
+
+// approximates a gaussian blur of premultiplied RGBA pixels with three box blurs
+fn blur(data: &mut [u8], width: usize, height: usize, radius: usize) {
+    let mut buffer = vec![0; data.len()];
+
+    for _ in 0..3 {
+        for row in 0..height {
+            blur_line(data, &mut buffer, row * width, 1, width, radius);
+        }
+
+        for column in 0..width {
+            blur_line(&buffer, data, column, width, height, radius);
+        }
+    }
+}
+
+// blurs the pixels start, start + step, ... with a box of 2 * radius + 1 pixels,
+// pixels outside of the line are transparent
+fn blur_line(src: &[u8], dst: &mut [u8], start: usize, step: usize, len: usize, radius: usize) {
+    let size = (2 * radius + 1) as u32;
+    let index = |i: usize| (start + i * step) * 4;
+    let mut sum = [0u32; 4];
+
+    for i in 0..=radius.min(len - 1) {
+        for (c, s) in sum.iter_mut().enumerate() {
+            *s += src[index(i) + c] as u32;
+        }
+    }
+
+    for i in 0..len {
+        for (c, s) in sum.iter().enumerate() {
+            dst[index(i) + c] = ((*s + size / 2) / size) as u8;
+        }
+
+        if i + radius + 1 < len {
+            for (c, s) in sum.iter_mut().enumerate() {
+                *s += src[index(i + radius + 1) + c] as u32;
+            }
+        }
+
+        if i >= radius {
+            for (c, s) in sum.iter_mut().enumerate() {
+                *s -= src[index(i - radius) + c] as u32;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blur() {
+        let mut data = vec![0; 9 * 9 * 4];
+        let center = (4 * 9 + 4) * 4;
+
+        for c in 0..4 {
+            data[center + c] = 255;
+        }
+
+        blur(&mut data, 9, 9, 1);
+
+        let alpha = |x: usize, y: usize| data[(y * 9 + x) * 4 + 3];
+        let total: u32 = data.iter().skip(3).step_by(4).map(|a| *a as u32).sum();
+
+        // spreads symmetrically and keeps roughly the same coverage
+        assert!(alpha(4, 4) < 255);
+        assert!(alpha(4, 4) > alpha(3, 4));
+        assert_eq!(alpha(3, 4), alpha(5, 4));
+        assert_eq!(alpha(4, 3), alpha(4, 5));
+        assert!(alpha(3, 4) > 0);
+        assert!(total > 200 && total <= 255);
+
+        // color channels never exceed alpha
+        for pixel in data.chunks(4) {
+            assert!(pixel[0] <= pixel[3]);
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     api::prelude::*,
     proc_macros::*,
     render::TextMetrics,
-    shell::prelude::{Key, KeyEvent},
+    shell::prelude::{CompositionEvent, Key, KeyEvent, MouseButton, WindowRequest},
     themes::theme_orbtk::orbtk_fonts,
     Cursor, TextBlock,
 };
@@ -18,6 +18,7 @@ pub static FOCUSED_STATE: &str = "focused";
 pub enum TextAction {
     KeyDown(KeyEvent),
     TextInput(String),
+    Composition(CompositionEvent),
     MouseDown(Mouse),
     MouseUp,
     MouseMove(Point),
@@ -60,6 +61,9 @@ pub struct TextBehaviorState {
     update_selection: bool,
     event_adapter: EventAdapter,
     window: Entity, //mouse_up_count: usize,
+    preedit: String,
+    preedit_cursor: usize,
+    paste_position: Option<Point>,
 }
 
 impl TextBehaviorState {
@@ -76,23 +80,43 @@ impl TextBehaviorState {
     }
 
     fn copy(&self, registry: &mut Registry, ctx: &mut Context) {
-        let selection = self.selection(ctx);
+        if let Some(copy_text) = self.selected_text(ctx) {
+            registry.get_mut::<Clipboard>("clipboard").set(copy_text);
+        }
+    }
 
-        let (start, end) = self.selection_start_end(selection);
+    fn paste(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        if let Some(value) = registry.get::<Clipboard>("clipboard").get() {
+            self.insert_text(value, ctx);
+        }
+    }
 
-        if selection.is_empty() {
+    // stores the selected text as primary selection
+    fn update_primary_selection(&self, registry: &mut Registry, ctx: &mut Context) {
+        if !self.focused(ctx) || !*TextBehavior::primary_selection_ref(&ctx.widget()) {
             return;
         }
 
-        if let Some(copy_text) = String16::from(ctx.get_widget(self.target).clone::<String>("text"))
-            .get_string(start, end)
-        {
-            registry.get_mut::<Clipboard>("clipboard").set(copy_text);
+        if let Some(selected_text) = self.selected_text(ctx) {
+            let clipboard = registry.get_mut::<Clipboard>("clipboard");
+
+            if clipboard.get_primary().as_ref() != Some(&selected_text) {
+                clipboard.set_primary(selected_text);
+            }
         }
     }
 
-    fn paste(&mut self, registry: &mut Registry, ctx: &mut Context) {
-        if let Some(value) = registry.get::<Clipboard>("clipboard").get() {
+    // inserts the primary selection at the given position (middle click)
+    fn paste_primary(&mut self, registry: &mut Registry, ctx: &mut Context, position: Point) {
+        if !*TextBehavior::primary_selection_ref(&ctx.widget()) {
+            return;
+        }
+
+        if let Some(value) = registry.get::<Clipboard>("clipboard").get_primary() {
+            let mut selection = self.selection(ctx);
+            selection.set(self.get_new_selection_position(ctx, position));
+            self.set_selection(ctx, selection);
+
             self.insert_text(value, ctx);
         }
     }
@@ -102,6 +126,13 @@ impl TextBehaviorState {
             return;
         }
 
+        let mask = TextBehavior::mask_clone(&ctx.widget());
+
+        if !mask.is_empty() {
+            self.insert_masked_text(insert_text, mask.as_str(), ctx);
+            return;
+        }
+
         let mut update_focus_state = self.len(ctx) == 0;
 
         update_focus_state = update_focus_state || self.clear_selection(ctx);
@@ -126,6 +157,44 @@ impl TextBehaviorState {
         }
     }
 
+    // inserts the text only if the result matches the input mask
+    fn insert_masked_text(&mut self, insert_text: String, mask: &str, ctx: &mut Context) {
+        let (start, end) = self.selection_start_end(self.selection(ctx));
+        self.edit_masked_text(start, end, insert_text.as_str(), mask, ctx);
+
+        // used to trigger bounds adjustments
+        self.direction = Direction::Right;
+    }
+
+    // replaces the chars between start and end with the given text and formats the input with
+    // the mask again, the edit is rejected if the result does not match the mask
+    fn edit_masked_text(
+        &mut self,
+        start: usize,
+        end: usize,
+        insert_text: &str,
+        mask: &str,
+        ctx: &mut Context,
+    ) {
+        let text = ctx.get_widget(self.target).clone::<String>("text");
+
+        let (text, caret) = match edit_masked(mask, text.as_str(), start, end, insert_text) {
+            Some(result) => result,
+            None => return,
+        };
+
+        let update_focus_state = self.len(ctx) == 0 || text.is_empty();
+
+        let mut selection = self.selection(ctx);
+        selection.set(caret);
+        self.set_selection(ctx, selection);
+        self.set_text(ctx, text);
+
+        if update_focus_state {
+            self.update_focused_state(ctx);
+        }
+    }
+
     // handle back space
     fn back_space(&mut self, ctx: &mut Context) {
         if self.clear_selection(ctx) {
@@ -138,6 +207,14 @@ impl TextBehaviorState {
             return;
         }
 
+        let mask = TextBehavior::mask_clone(&ctx.widget());
+
+        if !mask.is_empty() {
+            self.edit_masked_text(selection.start() - 1, selection.start(), "", &mask, ctx);
+            self.direction = Direction::Left;
+            return;
+        }
+
         selection.set(selection.start() - 1);
 
         let mut text = String16::from(ctx.get_widget(self.target).clone::<String>("text"));
@@ -175,6 +252,13 @@ impl TextBehaviorState {
             return;
         }
 
+        let mask = TextBehavior::mask_clone(&ctx.widget());
+
+        if !mask.is_empty() {
+            self.edit_masked_text(selection.start(), selection.start() + 1, "", &mask, ctx);
+            return;
+        }
+
         let mut text = String16::from(ctx.get_widget(self.target).clone::<String>("text"));
 
         text.remove(selection.start());
@@ -190,9 +274,16 @@ impl TextBehaviorState {
             return false;
         }
 
-        let mut text = String16::from(ctx.get_widget(self.target).clone::<String>("text"));
-
         let (start, end) = self.selection_start_end(selection);
+        let mask = TextBehavior::mask_clone(&ctx.widget());
+
+        if !mask.is_empty() {
+            self.edit_masked_text(start, end, "", &mask, ctx);
+            self.direction = Direction::Left;
+            return true;
+        }
+
+        let mut text = String16::from(ctx.get_widget(self.target).clone::<String>("text"));
 
         for i in (start..end).rev() {
             text.remove(i);
@@ -220,14 +311,123 @@ impl TextBehaviorState {
 
     // -- Text operations --
 
+    // -- Composition --
+
+    // handles the text composition of an input method
+    fn compose(&mut self, ctx: &mut Context, event: CompositionEvent) {
+        if !self.focused(ctx) {
+            return;
+        }
+
+        match event {
+            // the composed text replaces the selected text
+            CompositionEvent::Start => {
+                self.clear_selection(ctx);
+            }
+            CompositionEvent::Update { text, cursor } => {
+                self.preedit = text;
+                self.preedit_cursor = cursor;
+                self.update_preedit(ctx);
+            }
+            CompositionEvent::Commit(text) => {
+                self.clear_preedit(ctx);
+                self.insert_text(text, ctx);
+            }
+        }
+    }
+
+    // draws the preedit text underlined at the caret
+    fn update_preedit(&mut self, ctx: &mut Context) {
+        let rich_text = if self.preedit.is_empty() {
+            RichText::default()
+        } else {
+            let text = String16::from(TextBlock::text_clone(&ctx.get_widget(self.text_block)));
+            let caret = self.selection(ctx).start().min(text.len());
+
+            RichText::from(vec![
+                TextSpan::from(text.get_string(0, caret).unwrap_or_default()),
+                TextSpan::create()
+                    .text(self.display_preedit(ctx, self.preedit.chars().count()))
+                    .underline(true)
+                    .build(),
+                TextSpan::from(text.get_string(caret, text.len()).unwrap_or_default()),
+            ])
+        };
+
+        TextBlock::rich_text_set(&mut ctx.get_widget(self.text_block), rich_text);
+
+        // used to trigger bounds adjustments
+        self.direction = Direction::Right;
+        self.update_selection = true;
+    }
+
+    fn clear_preedit(&mut self, ctx: &mut Context) {
+        if self.preedit.is_empty() {
+            return;
+        }
+
+        self.preedit.clear();
+        self.preedit_cursor = 0;
+        self.update_preedit(ctx);
+    }
+
+    // gets the first `count` chars of the preedit text as shown, targets with an `echo` char
+    // (e.g. `PasswordBox`) mask the preedit with it
+    fn display_preedit(&self, ctx: &mut Context, count: usize) -> String {
+        match ctx.get_widget(self.target).try_clone::<char>("echo") {
+            Some(echo) => self.preedit.chars().take(count).map(|_| echo).collect(),
+            None => self.preedit.chars().take(count).collect(),
+        }
+    }
+
+    // measures the preedit text in front of its caret
+    fn measure_preedit(&self, ctx: &mut Context) -> f64 {
+        if self.preedit.is_empty() {
+            return 0.;
+        }
+
+        let text = self.display_preedit(ctx, self.preedit_cursor);
+        let font = TextBehavior::font_clone(&ctx.widget());
+        let font_size = *TextBehavior::font_size_ref(&ctx.widget());
+
+        ctx.render_context_2_d()
+            .measure(text.as_str(), font_size, font)
+            .width
+    }
+
+    // reports the caret rectangle to the shell, used to place the candidate window of input methods
+    fn change_ime_caret(&self, ctx: &mut Context) {
+        if !self.focused(ctx) {
+            return;
+        }
+
+        let cursor_x = *Cursor::cursor_x_ref(&ctx.get_widget(self.cursor));
+        let (position, bounds, offset) = {
+            let text_block = ctx.get_widget(self.text_block);
+            (
+                *text_block.get::<Point>("position"),
+                *text_block.get::<Rectangle>("bounds"),
+                *TextBlock::offset_ref(&text_block),
+            )
+        };
+
+        ctx.send_window_request(WindowRequest::ChangeImeCaret(Rectangle::new(
+            (position.x() + offset + cursor_x, position.y()),
+            (1., bounds.height()),
+        )));
+    }
+
+    // -- Composition --
+
     // -- Selection --
 
     fn update_cursor(&mut self, ctx: &mut Context) {
         let selection = self.selection(ctx);
         let (start, end) = self.selection_start_end(selection);
 
-        let cursor_start_measure = self.measure(ctx, 0, selection.start());
-        Cursor::cursor_x_set(&mut ctx.get_widget(self.cursor), cursor_start_measure.width);
+        // the caret is placed inside of the preedit text while composing
+        let cursor_x = self.measure(ctx, 0, selection.start()).width + self.measure_preedit(ctx);
+        Cursor::cursor_x_set(&mut ctx.get_widget(self.cursor), cursor_x);
 
         let start_measure = self.measure(ctx, 0, start);
         Cursor::selection_x_set(&mut ctx.get_widget(self.cursor), start_measure.width);
@@ -243,14 +443,14 @@ impl TextBehaviorState {
         let width = Cursor::bounds_ref(&ctx.get_widget(self.cursor)).width();
         let delta = width - offset;
 
-        if self.direction == Direction::Right && cursor_start_measure.width > delta {
-            let offset_delta = delta - cursor_start_measure.width;
+        if self.direction == Direction::Right && cursor_x > delta {
+            let offset_delta = delta - cursor_x;
             Cursor::offset_set(&mut ctx.get_widget(self.cursor), offset + offset_delta);
             TextBlock::offset_set(&mut ctx.get_widget(self.text_block), offset + offset_delta);
         }
 
-        if self.direction == Direction::Left && cursor_start_measure.width + offset < 0. {
-            let offset_delta = cursor_start_measure.width + offset;
+        if self.direction == Direction::Left && cursor_x + offset < 0. {
+            let offset_delta = cursor_x + offset;
             Cursor::offset_set(&mut ctx.get_widget(self.cursor), offset - offset_delta);
             TextBlock::offset_set(&mut ctx.get_widget(self.text_block), offset - offset_delta);
         }
@@ -318,7 +518,8 @@ impl TextBehaviorState {
 
     // handles the key down event
     fn key_down(&mut self, registry: &mut Registry, ctx: &mut Context, key_event: KeyEvent) {
-        if !self.focused(ctx) {
+        // keys are handled by the input method while composing
+        if !self.focused(ctx) || !self.preedit.is_empty() {
             return;
         }
 
@@ -373,13 +574,25 @@ impl TextBehaviorState {
     }
 
     // handles mouse down event
-    fn mouse_down(&mut self, ctx: &mut Context, mouse: Mouse) {
-        self.pressed = true;
+    fn mouse_down(&mut self, registry: &mut Registry, ctx: &mut Context, mouse: Mouse) {
         if !*TextBehavior::focused_ref(&ctx.widget()) {
+            self.pressed = mouse.button != MouseButton::Middle;
             self.request_focus();
+
+            if mouse.button == MouseButton::Middle {
+                self.paste_position = Some(mouse.position);
+            }
+
             return;
         }
 
+        if mouse.button == MouseButton::Middle {
+            self.paste_primary(registry, ctx, mouse.position);
+            return;
+        }
+
+        self.pressed = true;
+
         let selection_start = self.get_new_selection_position(ctx, mouse.position);
         let mut selection = self.selection(ctx);
         selection.set(selection_start);
@@ -441,7 +654,7 @@ impl TextBehaviorState {
     }
 
     // handles focus changed event
-    fn focused_changed(&mut self, ctx: &mut Context) {
+    fn focused_changed(&mut self, registry: &mut Registry, ctx: &mut Context) {
         self.adjust_selection(ctx);
 
         if *TextBehavior::select_all_on_focus_ref(&ctx.widget()) {
@@ -451,8 +664,15 @@ impl TextBehaviorState {
         if self.focused(ctx) {
             Cursor::visibility_set(&mut ctx.get_widget(self.cursor), Visibility::Visible);
             self.update_focused_state(ctx);
+
+            // a middle click on the unfocused text pastes after the focus is received
+            if let Some(position) = self.paste_position.take() {
+                self.paste_primary(registry, ctx, position);
+            }
         } else {
+            self.paste_position = None;
             Cursor::visibility_set(&mut ctx.get_widget(self.cursor), Visibility::Collapsed);
+            self.clear_preedit(ctx);
 
             if self.len(ctx) == 0 {
                 ctx.get_widget(self.target)
@@ -501,6 +721,19 @@ impl TextBehaviorState {
         *TextBehavior::selection_ref(&ctx.widget())
     }
 
+    // gets the selected text, `None` if the selection is empty
+    fn selected_text(&self, ctx: &mut Context) -> Option<String> {
+        let selection = self.selection(ctx);
+
+        if selection.is_empty() {
+            return None;
+        }
+
+        let (start, end) = self.selection_start_end(selection);
+
+        String16::from(ctx.get_widget(self.target).clone::<String>("text")).get_string(start, end)
+    }
+
     // check if control is pressed or on macos home key
     fn is_ctlr_home_down(&self, ctx: &mut Context) -> bool {
         // todo move window to api
@@ -703,18 +936,22 @@ impl State for TextBehaviorState {
         for action in messages.read::<TextAction>() {
             match action {
                 TextAction::KeyDown(event) => self.key_down(registry, ctx, event),
-                TextAction::MouseDown(p) => self.mouse_down(ctx, p),
+                TextAction::MouseDown(p) => self.mouse_down(registry, ctx, p),
                 TextAction::Drop(text, position) => {
                     if check_mouse_condition(position, &ctx.get_widget(self.target)) {
                         self.insert_text(text, ctx);
                     }
                 }
-                TextAction::FocusedChanged => self.focused_changed(ctx),
-                TextAction::SelectionChanged => self.update_selection = true,
+                TextAction::FocusedChanged => self.focused_changed(registry, ctx),
+                TextAction::SelectionChanged => {
+                    self.update_selection = true;
+                    self.update_primary_selection(registry, ctx);
+                }
                 TextAction::MouseMove(position) => self.mouse_move(ctx, position),
                 TextAction::MouseUp => self.mouse_up(ctx),
                 TextAction::ForceUpdate(force) => self.force_update(ctx, force),
                 TextAction::TextInput(text) => self.insert_text(text, ctx),
+                TextAction::Composition(event) => self.compose(ctx, event),
             }
         }
     }
@@ -722,6 +959,7 @@ impl State for TextBehaviorState {
     fn update_post_layout(&mut self, _registry: &mut Registry, ctx: &mut Context) {
         if self.update_selection {
             self.update_cursor(ctx);
+            self.change_ime_caret(ctx);
 
             self.update_selection = false;
         }
@@ -739,6 +977,8 @@ widget!(
     /// * move cursor by the left or right arrow keys or clicking with mouse
     /// * delete characters by pressing the Backspace or the Delete key
     /// * run on_activate() callback on pressing the Enter key
+    /// * show the composed (preedit) text of input methods underlined at the cursor
+    /// * reject input that does not match the input `mask`
     ///
     /// Following prerequisties must be met and presented to the TextBehavior
     /// to make it work correctly:
@@ -806,7 +1046,7 @@ widget!(
     ///
     /// [`Entity`]: https://docs.rs/dces/0.2.0/dces/entity/struct.Entity.html
     /// [`Cursor`]: ../struct.Cursor.html
-    TextBehavior<TextBehaviorState>: ActivateHandler, KeyDownHandler, TextInputHandler, DropHandler, MouseHandler {
+    TextBehavior<TextBehaviorState>: ActivateHandler, KeyDownHandler, TextInputHandler, TextCompositionHandler, DropHandler, MouseHandler {
     /// Reference the target (parent) widget e.g. `TextBox` or `PasswordBox`.
     target: u32,
 
@@ -838,7 +1078,16 @@ widget!(
     selection: TextSelection,
 
     /// If set to `true` all character will be focused when the widget gets focus. Default is `true`
-    select_all_on_focus: bool
+    select_all_on_focus: bool,
+
+    /// Sets or shares the input mask. `#` accepts a digit, `A` a letter, `*` a letter or digit
+    /// and `?` any character. All other characters of the mask are inserted automatically.
+    /// An empty mask accepts any input.
+    mask: String,
+
+    /// If set to `true` selected text is stored as primary selection and a middle click pastes
+    /// the primary selection. Default is `true`.
+    primary_selection: bool
     }
 );
 
@@ -852,6 +1101,8 @@ impl Template for TextBehavior {
             .focused(false)
             .lose_focus_on_activation(true)
             .select_all_on_focus(false)
+            .mask("")
+            .primary_selection(true)
             .on_key_down(move |ctx, event| -> bool {
                 ctx.send_message(TextAction::KeyDown(event), id);
                 false
@@ -860,6 +1111,10 @@ impl Template for TextBehavior {
                 ctx.send_message(TextAction::TextInput(text.to_string()), id);
                 false
             })
+            .on_text_composition(move |ctx, event| {
+                ctx.send_message(TextAction::Composition(event), id);
+                false
+            })
             .on_drop_file(move |ctx, file_name, position| {
                 ctx.send_message(TextAction::Drop(file_name, position), id);
                 false
@@ -918,6 +1173,97 @@ fn move_selection_right(mut selection: TextSelection, len: usize) -> TextSelecti
     selection
 }
 
+// checks if the given char is accepted by the placeholder or literal of the mask
+fn accepts_mask_char(mask_char: char, c: char) -> bool {
+    match mask_char {
+        '#' => c.is_ascii_digit(),
+        'A' => c.is_alphabetic(),
+        '*' => c.is_alphanumeric(),
+        '?' => true,
+        _ => mask_char == c,
+    }
+}
+
+fn is_mask_placeholder(mask_char: char) -> bool {
+    matches!(mask_char, '#' | 'A' | '*' | '?')
+}
+
+// Inserts the text at the caret and fills in the literals of the mask. Returns the new text and
+// caret or `None` if the result does not match the mask.
+fn apply_mask(mask: &str, text: &str, caret: usize, insert_text: &str) -> Option<(String, usize)> {
+    let mask: Vec<char> = mask.chars().collect();
+    let mut chars: Vec<char> = text.chars().collect();
+    let mut caret = caret.min(chars.len());
+
+    for c in insert_text.chars() {
+        // literals in front of the next placeholder are inserted automatically
+        while let Some(&mask_char) = mask.get(caret) {
+            if is_mask_placeholder(mask_char) || mask_char == c {
+                break;
+            }
+
+            if chars.get(caret) != Some(&mask_char) {
+                chars.insert(caret, mask_char);
+            }
+
+            caret += 1;
+        }
+
+        // a typed literal that is already part of the text moves the caret
+        if mask.get(caret) == Some(&c) && !is_mask_placeholder(c) && chars.get(caret) == Some(&c) {
+            caret += 1;
+            continue;
+        }
+
+        chars.insert(caret, c);
+        caret += 1;
+    }
+
+    if chars.len() > mask.len()
+        || !chars
+            .iter()
+            .zip(mask.iter())
+            .all(|(c, mask_char)| accepts_mask_char(*mask_char, *c))
+    {
+        return None;
+    }
+
+    Some((chars.into_iter().collect(), caret))
+}
+
+// Replaces the chars between start and end of the text with the inserted text. The following
+// input is formatted with the mask again, so it moves into freed placeholders. Returns the new
+// text and caret or `None` if the result does not match the mask.
+fn edit_masked(
+    mask: &str,
+    text: &str,
+    start: usize,
+    end: usize,
+    insert_text: &str,
+) -> Option<(String, usize)> {
+    let mask_chars: Vec<char> = mask.chars().collect();
+    let head: String = text.chars().take(start).collect();
+
+    // the input that follows the edit without the literals that are inserted by the mask
+    let tail: String = text
+        .chars()
+        .enumerate()
+        .skip(end.max(start))
+        .filter(|(i, _)| {
+            mask_chars
+                .get(*i)
+                .map_or(true, |mask_char| is_mask_placeholder(*mask_char))
+        })
+        .map(|(_, c)| c)
+        .collect();
+
+    let (text, caret) = apply_mask(mask, "", 0, head.as_str())?;
+    let (text, caret) = apply_mask(mask, text.as_str(), caret, insert_text)?;
+    let (text, _) = apply_mask(mask, text.as_str(), caret, tail.as_str())?;
+
+    Some((text, caret))
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -979,4 +1325,76 @@ mod tests {
         assert_eq!(result.start(), 6);
         assert_eq!(result.end(), 6);
     }
+
+    #[test]
+    fn test_apply_mask() {
+        assert_eq!(
+            apply_mask("####-##-##", "", 0, "2021"),
+            Some(("2021".to_string(), 4))
+        );
+        assert_eq!(
+            apply_mask("####-##-##", "2021", 4, "1"),
+            Some(("2021-1".to_string(), 6))
+        );
+        assert_eq!(
+            apply_mask("####-##-##", "2021", 4, "-"),
+            Some(("2021-".to_string(), 5))
+        );
+        assert_eq!(
+            apply_mask("####-##-##", "2021-", 4, "-"),
+            Some(("2021-".to_string(), 5))
+        );
+        assert_eq!(
+            apply_mask("####-##-##", "", 0, "20210131"),
+            Some(("2021-01-31".to_string(), 10))
+        );
+        assert_eq!(apply_mask("####-##-##", "2021", 4, "a"), None);
+        assert_eq!(apply_mask("####-##-##", "2021-01-31", 10, "1"), None);
+        assert_eq!(
+            apply_mask("AA##", "D", 1, "E1"),
+            Some(("DE1".to_string(), 3))
+        );
+        assert_eq!(apply_mask("AA##", "DE", 1, "1"), None);
+    }
+
+    #[test]
+    fn test_edit_masked() {
+        // delete a digit, the following digits move into the free placeholder
+        assert_eq!(
+            edit_masked("####-##-##", "2021-01-15", 6, 7, ""),
+            Some(("2021-01-5".to_string(), 6))
+        );
+
+        // back space over a literal moves the caret in front of it
+        assert_eq!(
+            edit_masked("####-##-##", "2021-01", 4, 5, ""),
+            Some(("2021-01".to_string(), 4))
+        );
+
+        // delete a selection
+        assert_eq!(
+            edit_masked("####-##-##", "2021-01-15", 2, 8, ""),
+            Some(("2015".to_string(), 2))
+        );
+        assert_eq!(
+            edit_masked("####-##-##", "2021-01-15", 0, 10, ""),
+            Some((String::new(), 0))
+        );
+
+        // insert in the middle of the text
+        assert_eq!(
+            edit_masked("####-##-##", "2021-11-5", 5, 5, "0"),
+            Some(("2021-01-15".to_string(), 6))
+        );
+
+        // replace a selection
+        assert_eq!(
+            edit_masked("####-##-##", "2021-01-15", 5, 7, "12"),
+            Some(("2021-12-15".to_string(), 7))
+        );
+
+        // a letter would move into a digit placeholder
+        assert_eq!(edit_masked("AA##", "DE12", 1, 2, ""), None);
+        assert_eq!(edit_masked("####-##-##", "2021-01-15", 5, 6, "a"), None);
+    }
 }
@@ -11,7 +11,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -19,6 +19,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -93,6 +96,7 @@ impl Template for Button {
                             .border_radius(id)
                             .border_width(id)
                             .border_brush(id)
+                            .border_brushes(id)
                             .padding(id)
                             .opacity(id)
                             .margin(("container_margin", id))
@@ -114,7 +114,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -122,6 +122,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the foreground property.
         foreground: Brush,
 
@@ -330,7 +333,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -338,6 +341,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the foreground property.
         foreground: Brush,
 
@@ -403,6 +409,7 @@ impl Template for ComboBox {
             .border_radius(id)
             .border_width(id)
             .border_brush(id)
+            .border_brushes(id)
             .padding(id)
             .child(
                 Grid::new()
@@ -7,7 +7,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -15,6 +15,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
+        /// Sets or shares the box shadow property e.g. `0 2 4 #40000000`.
+        box_shadow: BoxShadow,
+
         /// Sets or shares the padding property.
         padding: Thickness
     }
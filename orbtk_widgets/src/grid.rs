@@ -15,7 +15,7 @@ widget!(
         rows: Blocks,
 
         /// Sets or shares the border radius property.
-        border_radius: f64
+        border_radius: CornerRadius
 
         attached_properties: {
             /// Attach a column position to a widget.
@@ -35,6 +35,7 @@ pub struct NumericBoxState {
     pub current_value: Decimal,
     event_adapter: EventAdapter,
     window: Entity,
+    locale: Locale,
 }
 
 impl NumericBoxState {
@@ -54,8 +55,23 @@ impl NumericBoxState {
             ctx.widget().set("val", val);
         }
 
-        ctx.get_widget(self.input)
-            .set::<String>("text", self.current_value.to_string());
+        let text = self.format_value(ctx);
+        ctx.get_widget(self.input).set::<String>("text", text);
+    }
+
+    // formats the current value with the locale of the current language and at least the
+    // fraction digits of the step
+    fn format_value(&self, ctx: &Context) -> String {
+        let fraction_digits = self
+            .step
+            .normalize()
+            .scale()
+            .max(self.current_value.normalize().scale());
+
+        ctx.locale().format_fixed(
+            self.current_value.to_f64().unwrap_or_default(),
+            fraction_digits as usize,
+        )
     }
 
     fn min(&self, d: Decimal) -> Decimal {
@@ -103,13 +119,23 @@ impl State for NumericBoxState {
         self.max = default_or("max", MAX, ctx);
         self.step = default_or("step", 1.0, ctx);
         self.current_value = default_or("val", 0.0, ctx);
+        self.locale = ctx.locale();
 
-        let init_value = self.current_value.to_string();
+        let init_value = self.format_value(ctx);
         ctx.get_widget(self.input).set::<String>("text", init_value);
     }
 
     // TODO: let the user type the value, or select it for cut, copy, paste operations
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // the language is changed => the value is formatted with the new locale
+        let locale = ctx.locale();
+        if locale != self.locale {
+            self.locale = locale;
+
+            let text = self.format_value(ctx);
+            ctx.get_widget(self.input).set::<String>("text", text);
+        }
+
         if let Some(action) = &self.action {
             match action {
                 InputAction::Inc => {
@@ -161,6 +187,8 @@ widget!(
     /// The widget can be controlled by clicking on the two control buttons, or the keybaord's
     /// Up and Down, Numpad+ and Numpad- keys, or the mouse scroll.
     /// Note: after the widget is initialized, changing the min, max or step properties has no effect.
+    /// The value is formatted with the locale of the current language e.g. `1.234,5` in german
+    /// and formatted again if the language is changed.
     ///
     /// # Examples:
     /// Create a NumericBox with default values:
@@ -179,11 +207,14 @@ widget!(
         /// Sets or shares the border color property
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the border width property
         border_width: Thickness,
 
         /// Sets or shares the border radius property
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the focused property
         focused: bool,
@@ -54,6 +54,7 @@ widget!(
     ///
     /// Notes:
     /// * If the input is empty, it will render the content of the `water_mark` property.
+    /// * The composed (preedit) text of input methods is masked with the `echo` char as well.
     /// * Changing the `echo` property after the `PasswordBox` is created has no effect.
     /// * The password is stored in plain text currently
     ///
@@ -87,7 +88,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -95,6 +96,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -143,6 +147,7 @@ impl Template for PasswordBox {
             .request_focus(id)
             .text(id)
             .selection(id)
+            .primary_selection(false)
             .build(ctx);
 
         self.state_mut().text_behavior = text_behavior;
@@ -173,6 +178,7 @@ impl Template for PasswordBox {
                     .border_radius(id)
                     .border_width(id)
                     .border_brush(id)
+                    .border_brushes(id)
                     .padding(id)
                     .child(
                         Grid::new()
@@ -85,13 +85,13 @@ widget!(
         indicator_background: Brush,
 
         /// Defines the border radius of the indicator.
-        indicator_border_radius: f64,
+        indicator_border_radius: CornerRadius,
 
         /// Sets or shares the border color property
         border_brush: Brush,
 
         /// Sets or shares the border radius property
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border width property
         border_width: Thickness,
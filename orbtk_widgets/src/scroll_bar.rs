@@ -15,7 +15,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64
+        border_radius: CornerRadius
     }
 );
 
@@ -63,26 +63,33 @@ impl State for ScrollIndicatorState {
         // adjust horizontal scroll bar
         if mode.horizontal != ScrollMode::Disabled && content_size.width() > view_port_size.width()
         {
+            let right_to_left = ctx.flow_direction().is_right_to_left();
             let mut scroll_bar = ctx.get_widget(self.horizontal_scroll_bar);
 
             if *scroll_bar.get::<Visibility>("visibility") != Visibility::Visible {
                 scroll_bar.set("visibility", Visibility::Visible);
             }
 
-            scroll_bar
-                .get_mut::<Rectangle>("bounds")
-                .set_width(scroll_bar_size(
-                    size.width(),
-                    content_size.width(),
-                    view_port_size.width(),
-                    padding.left() + padding.right(),
-                ));
-
-            scroll_bar.get_mut::<Rectangle>("bounds").set_x(-offset(
+            let scroll_bar_width = scroll_bar_size(
                 size.width(),
                 content_size.width(),
-                scroll_padding.left(),
-            ));
+                view_port_size.width(),
+                padding.left() + padding.right(),
+            );
+            let mut scroll_bar_x =
+                -offset(size.width(), content_size.width(), scroll_padding.left());
+
+            // the scroll bar starts at the right edge
+            if right_to_left {
+                scroll_bar_x = size.width() - scroll_bar_x - scroll_bar_width;
+            }
+
+            scroll_bar
+                .get_mut::<Rectangle>("bounds")
+                .set_width(scroll_bar_width);
+            scroll_bar
+                .get_mut::<Rectangle>("bounds")
+                .set_x(scroll_bar_x);
         }
     }
 }
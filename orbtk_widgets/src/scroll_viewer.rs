@@ -14,8 +14,14 @@ impl ScrollViewerState {
 
 impl State for ScrollViewerState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(delta) = self.delta {
+        if let Some(mut delta) = self.delta {
             self.delta = None;
+
+            // the content is mirrored, keep the scroll direction of the mouse wheel
+            if ctx.flow_direction().is_right_to_left() {
+                delta.set_x(-delta.x());
+            }
+
             let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
 
             if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
@@ -57,7 +63,8 @@ impl State for ScrollViewerState {
 
 widget!(
     /// The `ScrollViewer` is used to scroll its child vertical and or horizontal.
-    /// Only the first child of the scroll viewer can be scrolled.
+    /// Only the first child of the scroll viewer can be scrolled. If the flow direction is
+    /// `RightToLeft`, the child starts at the right edge and is scrolled to the left.
     ScrollViewer<ScrollViewerState>: MouseHandler {
         /// Sets or shares the scroll mode property.
         mode: ScrollViewerMode,
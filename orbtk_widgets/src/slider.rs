@@ -121,6 +121,13 @@ impl State for SliderState {
                             .width();
                         let slider_x = ctx.widget().get::<Point>("position").x();
 
+                        // the thumb is mirrored, the val grows from the right edge
+                        let mouse_x = if ctx.flow_direction().is_right_to_left() {
+                            mirror_mouse_x(mouse_x, slider_x, track_width)
+                        } else {
+                            mouse_x
+                        };
+
                         let thumb_x =
                             calculate_thumb_x(mouse_x, thumb_width, slider_x, track_width);
 
@@ -156,7 +163,8 @@ impl State for SliderState {
 }
 
 widget!(
-    /// The `Slider` allows to use a val in a range of values.
+    /// The `Slider` allows to use a val in a range of values. If the flow direction is
+    /// `RightToLeft`, the val grows from the right to the left.
     ///
     /// **style:** `slider`
     ///
@@ -179,7 +187,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -302,6 +310,10 @@ fn calculate_thumb_x(mouse_x: f64, thumb_width: f64, slider_x: f64, track_width:
         .min(track_width - thumb_width)
 }
 
+fn mirror_mouse_x(mouse_x: f64, slider_x: f64, track_width: f64) -> f64 {
+    2.0 * slider_x + track_width - mouse_x
+}
+
 fn calculate_val(thumb_x: f64, min: f64, max: f64, thumb_width: f64, track_width: f64) -> f64 {
     thumb_x / (track_width - thumb_width) * (max - min)
 }
@@ -334,6 +346,16 @@ mod tests {
         assert!((68.0 - calculate_thumb_x(1000.0, 32.0, 0.0, 100.0)).abs() < ERROR);
     }
 
+    #[test]
+    fn test_mirror_mouse_x() {
+        assert!((100.0 - mirror_mouse_x(0.0, 0.0, 100.0)).abs() < ERROR);
+        assert!((40.0 - mirror_mouse_x(80.0, 10.0, 100.0)).abs() < ERROR);
+        assert!(
+            (68.0 - calculate_thumb_x(mirror_mouse_x(0.0, 0.0, 100.0), 32.0, 0.0, 100.0)).abs()
+                < ERROR
+        );
+    }
+
     #[test]
     fn test_calculate_val() {
         assert!((0.0 - calculate_val(0.0, 0.0, 100.0, 32.0, 100.0)).abs() < ERROR);
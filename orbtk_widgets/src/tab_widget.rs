@@ -65,7 +65,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -73,6 +73,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -508,7 +511,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -516,6 +519,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the padding property.
         padding: Thickness
     }
@@ -555,6 +561,7 @@ impl Template for TabWidget {
                         .id(BODY_CONTAINER)
                         .background(id)
                         .border_brush(id)
+                        .border_brushes(id)
                         .border_width(id)
                         .border_radius(id)
                         .attach(Grid::row(1))
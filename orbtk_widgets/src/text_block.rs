@@ -2,20 +2,71 @@ use crate::{api::prelude::*, proc_macros::*, themes::theme_orbtk::*};
 
 enum TextAction {
     Localize,
+    MouseUp(Point),
 }
 
-/// Handles the localization of the text.
+/// Handles the localization of the text and clicks on links of the rich text.
 #[derive(Debug, Clone, Default, AsAny)]
 pub struct TextBlockState;
 
 impl TextBlockState {
+    // Raises a `LinkClickedEvent`, if a link of the rich text is clicked.
+    fn click_link(&self, ctx: &mut Context, position: Point) {
+        let rich_text = TextBlock::rich_text_clone(&ctx.widget());
+
+        if rich_text.is_empty() || !check_mouse_condition(position, &ctx.widget()) {
+            return;
+        }
+
+        let (bounds, widget_position, offset, style, format) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                *widget.get::<Point>("position"),
+                *TextBlock::offset_ref(&widget),
+                TextStyle {
+                    font: TextBlock::font_clone(&widget),
+                    font_size: *TextBlock::font_size_ref(&widget),
+                    foreground: TextBlock::foreground_clone(&widget),
+                },
+                TextFormat::from_widget(&widget),
+            )
+        };
+
+        let layout = TextLayout::new(
+            ctx.render_context_2_d(),
+            &rich_text,
+            &style,
+            &format,
+            Some(bounds.width()),
+        );
+
+        let href = layout
+            .run_at(Point::new(
+                position.x() - widget_position.x() - offset,
+                position.y() - widget_position.y(),
+            ))
+            .and_then(|run| rich_text.spans()[run.span].link.clone());
+
+        if let Some(href) = href {
+            ctx.event_adapter()
+                .push_event_direct(ctx.entity(), LinkClickedEvent { href, position });
+        }
+    }
+
     fn localize(&self, ctx: &mut Context) {
         if !*TextBlock::localizable_ref(&ctx.widget()) {
             return;
         }
 
         let text = TextBlock::text_clone(&ctx.widget());
-        let localized_text = ctx.localize_text(text);
+        let args = TextBlock::localization_args_clone(&ctx.widget());
+
+        let localized_text = if args.is_empty() {
+            ctx.localize_text(text)
+        } else {
+            ctx.localize_text_with_args(text, &args)
+        };
 
         TextBlock::localized_text_set(&mut ctx.widget(), localized_text);
     }
@@ -39,19 +90,46 @@ impl State for TextBlockState {
         for message in messages.read::<TextAction>() {
             match message {
                 TextAction::Localize => self.localize(ctx),
+                TextAction::MouseUp(position) => self.click_link(ctx, position),
             }
         }
     }
 }
 
 widget!(
-    /// The `TextBlock` widget is used to draw text. It is not interactive.
+    /// The `TextBlock` widget is used to draw text. It is not interactive,
+    /// except of links inside of its rich text.
+    ///
+    /// If the `rich_text` is not empty, it is drawn instead of the `text`.
+    /// Rich text raises a `LinkClickedEvent` if one of its links is clicked.
+    ///
+    /// A localizable text is used as key of the translation. Its `localization_args` replace
+    /// the placeholders of the translation e.g. `{count}`.
+    ///
+    /// Text that is wider than the available width is broken into lines as
+    /// defined by `text_wrap` and trimmed as defined by `text_trimming`.
+    /// `max_lines` limits the number of drawn lines. By default rich text is
+    /// broken between words and plain text only on line breaks.
     ///
     /// **style:** `text-block`
-    TextBlock<TextBlockState> {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// TextBlock::new()
+    ///     .text("A long description of unknown length")
+    ///     .text_wrap("word")
+    ///     .text_trimming("word-ellipsis")
+    ///     .max_lines(2)
+    ///     .build(ctx)
+    /// ```
+    TextBlock<TextBlockState>: MouseHandler, LinkHandler {
         /// Sets or shares the text property.
         text: String,
 
+        /// Sets or shares the rich text property. Could be build from spans or from markup.
+        rich_text: RichText,
+
         /// If the `TextBlock` is localizable and the localized text is not empty, the localized_text will be drawn.
         localized_text: String,
 
@@ -71,7 +149,20 @@ widget!(
         offset: f64,
 
         /// Defines if the text is localizable. If set to `false` the text will not be localized.
-        localizable: bool
+        localizable: bool,
+
+        /// Sets or shares the arguments of the localized text. They replace the placeholders
+        /// `{name}` and choose plural and select forms of the translation.
+        localization_args: LocalizationArgs,
+
+        /// Defines how the text is broken into lines.
+        text_wrap: TextWrap,
+
+        /// Defines how lines are trimmed, that do not fit into the available width.
+        text_trimming: TextTrimming,
+
+        /// Defines the maximum number of lines. `0` means unlimited.
+        max_lines: usize
     }
 );
 
@@ -83,9 +174,16 @@ impl Template for TextBlock {
             .font_size(orbtk_fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
             .localizable(true)
+            .text_wrap(TextWrap::Auto)
+            .text_trimming(TextTrimming::None)
+            .max_lines(0)
             .on_changed("text", move |ctx, _| {
                 ctx.send_message(TextAction::Localize, id)
             })
+            .on_changed("localization_args", move |ctx, _| {
+                ctx.send_message(TextAction::Localize, id)
+            })
+            .on_mouse_up(move |ctx, m| ctx.send_message(TextAction::MouseUp(m.position), id))
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
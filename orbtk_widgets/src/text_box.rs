@@ -1,17 +1,135 @@
+use regex::Regex;
+
 use super::behaviors::{TextAction, TextBehavior};
 
 use crate::{api::prelude::*, prelude::*, proc_macros::*, themes::theme_orbtk::*};
 
 // --- KEYS --
 pub static STYLE_TEXT_BOX: &str = "text_box";
+pub static STYLE_TEXT_BOX_ERROR: &str = "text_box_error";
+pub static ERROR_STATE: &str = "error";
 static ID_CURSOR: &str = "id_cursor";
+static ID_VALIDATION_ERROR: &str = "id_validation_error";
 // --- KEYS --
 
+// space between the box and the validation error message
+const VALIDATION_ERROR_SPACING: f64 = 2.0;
+
+/// Callback closure to validate the text of a `TextBox`. Returns an error message if the text is not valid.
+pub type ValidatorFn = dyn Fn(&str) -> Result<(), String> + 'static;
+
+enum TextBoxAction {
+    Validate,
+}
+
+/// The `TextBoxState` validates the text of the `TextBox`.
+#[derive(Default, AsAny)]
+pub struct TextBoxState {
+    validator: Option<Box<ValidatorFn>>,
+    // height that is added to the bottom margin for the validation error message
+    reserved_height: f64,
+}
+
+impl TextBoxState {
+    fn validate(&mut self, ctx: &mut Context) {
+        let validator = match &self.validator {
+            Some(validator) => validator,
+            None => return,
+        };
+
+        let error = validator(TextBox::text_ref(&ctx.widget())).err();
+
+        set_error_state(
+            ctx.widget().get_mut::<Selector>("selector"),
+            error.is_some(),
+        );
+        ctx.widget().update(false);
+
+        let message = error.unwrap_or_default();
+        let visible = *TextBox::show_validation_error_ref(&ctx.widget()) && !message.is_empty();
+
+        // the message is placed beneath the box in the space that is reserved by the bottom margin
+        let reserved_height = if visible {
+            let (font, font_size) = {
+                let error_text = ctx.child(ID_VALIDATION_ERROR);
+                (
+                    error_text.clone::<String>("font"),
+                    *error_text.get::<f64>("font_size"),
+                )
+            };
+
+            ctx.render_context_2_d()
+                .measure(message.as_str(), font_size, font)
+                .height
+                + VALIDATION_ERROR_SPACING
+        } else {
+            0.0
+        };
+
+        let mut error_text = ctx.child(ID_VALIDATION_ERROR);
+        error_text.set(
+            "visibility",
+            if visible {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            },
+        );
+        error_text.set("margin", Thickness::new(0., 0., 0., -reserved_height));
+
+        if (reserved_height - self.reserved_height).abs() > f64::EPSILON {
+            let mut margin = *ctx.widget().get::<Thickness>("margin");
+            margin.set_bottom(margin.bottom() - self.reserved_height + reserved_height);
+            ctx.widget().set("margin", margin);
+            self.reserved_height = reserved_height;
+        }
+
+        TextBox::validation_error_set(&mut ctx.widget(), message);
+    }
+}
+
+impl State for TextBoxState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.validate(ctx);
+    }
+
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for action in messages.read::<TextBoxAction>() {
+            match action {
+                TextBoxAction::Validate => self.validate(ctx),
+            }
+        }
+    }
+}
+
 widget!(
     /// The `TextBox` widget represents a single line text input widget.
     ///
+    /// Input that does not match the `mask` is rejected. If a validator is
+    /// set, the text is validated on each change. If the validation fails,
+    /// the `error` state is pushed to the selector and `validation_error`
+    /// contains the error message. With `show_validation_error` the message
+    /// is shown beneath the box, the bottom margin of the box is enlarged by
+    /// the height of the message.
+    ///
     /// * style: `text_box`
-    TextBox: ActivateHandler,
+    /// * style of the validation error: `text_box_error`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// TextBox::new()
+    ///     .mask("####-##-##")
+    ///     .validator_regex(r"\d{4}-\d{2}-\d{2}", "Enter a complete date")
+    ///     .show_validation_error(true)
+    ///     .build(ctx)
+    /// ```
+    TextBox<TextBoxState>: ActivateHandler,
     KeyDownHandler,
     TextInputHandler {
         /// Sets or shares the text property.
@@ -36,7 +154,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -44,6 +162,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -60,10 +181,36 @@ widget!(
         select_all_on_focus: bool,
 
         /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool
+        hover: bool,
+
+        /// Sets or shares the input mask. `#` accepts a digit, `A` a letter, `*` a letter or digit
+        /// and `?` any character. All other characters of the mask are inserted automatically.
+        mask: String,
+
+        /// Contains the message of the last failed validation. Empty if the text is valid.
+        validation_error: String,
+
+        /// If set to `true` the validation error message is shown beneath the box. The bottom margin
+        /// of the box is enlarged while the message is shown.
+        show_validation_error: bool
     }
 );
 
+impl TextBox {
+    /// Sets a closure that validates the text on each change.
+    pub fn validator<F: Fn(&str) -> Result<(), String> + 'static>(mut self, validator: F) -> Self {
+        self.state_mut().validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Validates the text with the given regular expression, that has to match the whole text.
+    /// On mismatch the given message is set as validation error. If the pattern is not a valid
+    /// regular expression, each validation fails with the error of the pattern.
+    pub fn validator_regex(self, pattern: &str, message: impl Into<String>) -> Self {
+        self.validator(regex_validator(pattern, message.into()))
+    }
+}
+
 impl Template for TextBox {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         let text_block = TextBlock::new()
@@ -91,6 +238,17 @@ impl Template for TextBox {
             .request_focus(id)
             .text(id)
             .selection(id)
+            .mask(id)
+            .build(ctx);
+
+        let validation_error = TextBlock::new()
+            .id(ID_VALIDATION_ERROR)
+            .style(STYLE_TEXT_BOX_ERROR)
+            .v_align("end")
+            .h_align("start")
+            .text(("validation_error", id))
+            .localizable(false)
+            .visibility("collapsed")
             .build(ctx);
 
         self.name("TextBox")
@@ -110,6 +268,9 @@ impl Template for TextBox {
             .focused(false)
             .lose_focus_on_activation(true)
             .select_all_on_focus(true)
+            .mask("")
+            .validation_error("")
+            .show_validation_error(false)
             .child(text_behavior)
             .child(
                 Container::new()
@@ -117,6 +278,7 @@ impl Template for TextBox {
                     .border_radius(id)
                     .border_width(id)
                     .border_brush(id)
+                    .border_brushes(id)
                     .padding(id)
                     .child(
                         Grid::new()
@@ -127,8 +289,72 @@ impl Template for TextBox {
                     )
                     .build(ctx),
             )
+            .child(validation_error)
             .on_changed("text", move |ctx, _| {
                 ctx.send_message(TextAction::ForceUpdate(false), text_behavior);
+                ctx.send_message(TextBoxAction::Validate, id);
             })
     }
 }
+
+// --- Helpers --
+
+// pushes the error state to the selector if the validation failed, otherwise it is removed
+fn set_error_state(selector: &mut Selector, error: bool) {
+    if error {
+        selector.push_state(ERROR_STATE);
+    } else {
+        selector.remove_state(ERROR_STATE);
+    }
+}
+
+// validates the whole text with the regular expression, an invalid pattern is reported as
+// validation error instead of the message
+fn regex_validator(pattern: &str, message: String) -> impl Fn(&str) -> Result<(), String> {
+    let regex = Regex::new(format!("^(?:{})$", pattern).as_str())
+        .map_err(|error| format!("Invalid validation regex: {}", error));
+
+    move |text| match &regex {
+        Ok(regex) if regex.is_match(text) => Ok(()),
+        Ok(_) => Err(message.clone()),
+        Err(error) => Err(error.clone()),
+    }
+}
+
+// --- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_validator() {
+        let validator = regex_validator(r"\d{4}-\d{2}-\d{2}", "Enter a complete date".into());
+
+        assert_eq!(validator("2021-01-15"), Ok(()));
+        assert_eq!(
+            validator("2021-01-1"),
+            Err("Enter a complete date".to_string())
+        );
+        // the whole text has to match
+        assert!(validator("x2021-01-15").is_err());
+
+        let validator = regex_validator(r"(\d{4}", "Enter a complete date".into());
+        let error = validator("2021").unwrap_err();
+        assert!(error.starts_with("Invalid validation regex"));
+    }
+
+    #[test]
+    fn test_error_state() {
+        let mut selector = Selector::new(STYLE_TEXT_BOX);
+
+        set_error_state(&mut selector, true);
+        assert_eq!(selector.states(), &vec![ERROR_STATE.to_string()]);
+
+        set_error_state(&mut selector, true);
+        assert_eq!(selector.states().len(), 1);
+
+        set_error_state(&mut selector, false);
+        assert!(selector.states().is_empty());
+    }
+}
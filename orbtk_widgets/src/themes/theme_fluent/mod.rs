@@ -75,3 +75,20 @@ pub fn register_fluent_fonts(theme: Theme) -> Theme {
 pub fn register_fluent_fonts(theme: Theme) -> Theme {
     theme
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_fluent() {
+        for colors in &[THEME_FLUENT_COLORS_DARK, THEME_FLUENT_COLORS_LIGHT] {
+            check_theme(
+                &ThemeConfig::from(THEME_FLUENT)
+                    .extend(ThemeConfig::from(*colors))
+                    .extend(ThemeConfig::from(THEME_FLUENT_FONTS))
+                    .extend(ThemeConfig::from(MDL2_ASSETS_ICONS)),
+            );
+        }
+    }
+}
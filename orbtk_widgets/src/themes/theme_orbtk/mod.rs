@@ -73,3 +73,20 @@ pub fn register_default_fonts(theme: Theme) -> Theme {
 pub fn register_default_fonts(theme: Theme) -> Theme {
     theme
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_default() {
+        for colors in &[THEME_DEFAULT_COLORS_DARK, THEME_DEFAULT_COLORS_LIGHT] {
+            check_theme(
+                &ThemeConfig::from(THEME_DEFAULT)
+                    .extend(ThemeConfig::from(*colors))
+                    .extend(ThemeConfig::from(THEME_DEFAULT_FONTS))
+                    .extend(ThemeConfig::from(MATERIAL_ICONS)),
+            );
+        }
+    }
+}
@@ -27,3 +27,18 @@ pub fn theme_redox() -> Theme {
             .extend(ThemeConfig::from(MATERIAL_ICONS)),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_redox() {
+        check_theme(
+            &ThemeConfig::from(THEME_REDOX)
+                .extend(ThemeConfig::from(THEME_REDOX_COLORS_DARK))
+                .extend(ThemeConfig::from(THEME_REDOX_FONTS))
+                .extend(ThemeConfig::from(MATERIAL_ICONS)),
+        );
+    }
+}
@@ -12,7 +12,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -20,6 +20,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border brushes of the single sides, which replace the border brush.
+        border_brushes: BorderBrushes,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -99,6 +102,7 @@ impl Template for ToggleButton {
                                     .border_radius(id)
                                     .border_width(id)
                                     .border_brush(id)
+                                    .border_brushes(id)
                                     .padding(id)
                                     .child(
                                         Stack::new()
@@ -98,6 +98,9 @@ impl State for WindowState {
                     WindowEvent::ActiveChanged(active) => {
                         self.active_changed(active, ctx);
                     }
+                    WindowEvent::ThemeChanged(theme) => {
+                        ctx.switch_theme(theme);
+                    }
                     _ => {}
                 },
                 Action::FocusEvent(focus_event) => match focus_event {
@@ -118,7 +121,7 @@ widget!(
     /// It also contains global properties like keyboard modifier and focused widget.
     ///
     /// **style:** `window`
-    Window<WindowState>: ActivateHandler {
+    Window<WindowState>: ActivateHandler, ClipboardHandler, ColorSchemeHandler {
         /// Sets or shares the background property.
         background: Brush,
 
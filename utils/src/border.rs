@@ -1,11 +1,12 @@
-use super::{Brush, Thickness};
+use super::{Brush, CornerRadius, Thickness, Value};
 
 /// Used to build a border, specifying additional details.
 #[derive(Default)]
 pub struct BorderBuilder {
     brush: Brush,
+    brushes: BorderBrushes,
     thickness: Thickness,
-    radius: f64,
+    radius: CornerRadius,
 }
 
 impl BorderBuilder {
@@ -20,15 +21,21 @@ impl BorderBuilder {
         self
     }
 
+    /// Inserts brushes for single sides, the other sides use the border brush.
+    pub fn brushes(mut self, brushes: BorderBrushes) -> Self {
+        self.brushes = brushes;
+        self
+    }
+
     /// Inserts a border thickness.
     pub fn thickness(mut self, thickness: Thickness) -> Self {
         self.thickness = thickness;
         self
     }
 
-    /// Inserts a border radius.
-    pub fn radius(mut self, radius: f64) -> Self {
-        self.radius = radius;
+    /// Inserts a border radius for all corners or per corner.
+    pub fn radius<R: Into<CornerRadius>>(mut self, radius: R) -> Self {
+        self.radius = radius.into();
         self
     }
 
@@ -36,6 +43,7 @@ impl BorderBuilder {
     pub fn build(self) -> Border {
         Border {
             brush: self.brush,
+            brushes: self.brushes,
             thickness: self.thickness,
             radius: self.radius,
         }
@@ -43,11 +51,13 @@ impl BorderBuilder {
 }
 
 /// Describes a border of a shape with border `brush`, `thickness` and `radius`.
+/// The brushes of single sides could be overwritten with `BorderBrushes`.
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct Border {
     brush: Brush,
+    brushes: BorderBrushes,
     thickness: Thickness,
-    radius: f64,
+    radius: CornerRadius,
 }
 
 impl Border {
@@ -66,6 +76,21 @@ impl Border {
         self.brush = brush.into();
     }
 
+    /// Gets the brushes of single sides.
+    pub fn brushes(&self) -> &BorderBrushes {
+        &self.brushes
+    }
+
+    /// Sets the brushes of single sides.
+    pub fn set_brushes(&mut self, brushes: BorderBrushes) {
+        self.brushes = brushes;
+    }
+
+    /// Gets the brushes of the left, top, right and bottom side.
+    pub fn side_brushes(&self) -> [&Brush; 4] {
+        self.brushes.resolve(&self.brush)
+    }
+
     /// Gets the thickness.
     pub fn thickness(&self) -> Thickness {
         self.thickness
@@ -77,13 +102,116 @@ impl Border {
     }
 
     /// Gets the radius.
-    pub fn radius(&self) -> f64 {
+    pub fn radius(&self) -> CornerRadius {
         self.radius
     }
 
     /// Sets the radius.
-    pub fn set_radius(&mut self, radius: f64) {
-        self.radius = radius
+    pub fn set_radius<R: Into<CornerRadius>>(&mut self, radius: R) {
+        self.radius = radius.into()
+    }
+}
+
+/// Overwrites the border brush of single sides e.g. to draw only a colored bottom line.
+/// Sides without a brush are drawn with the border brush.
+///
+/// In a theme the brushes are declared as a map of `left`, `top`, `right` and `bottom`,
+/// e.g. `"border_brushes": { "bottom": "#0078d7" }`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct BorderBrushes {
+    /// Brush of the left side.
+    pub left: Option<Brush>,
+
+    /// Brush of the top side.
+    pub top: Option<Brush>,
+
+    /// Brush of the right side.
+    pub right: Option<Brush>,
+
+    /// Brush of the bottom side.
+    pub bottom: Option<Brush>,
+}
+
+impl BorderBrushes {
+    /// Creates border brushes without overwritten sides.
+    pub fn new() -> Self {
+        BorderBrushes::default()
+    }
+
+    /// Sets the brush of the left side.
+    pub fn left<B: Into<Brush>>(mut self, brush: B) -> Self {
+        self.left = Some(brush.into());
+        self
+    }
+
+    /// Sets the brush of the top side.
+    pub fn top<B: Into<Brush>>(mut self, brush: B) -> Self {
+        self.top = Some(brush.into());
+        self
+    }
+
+    /// Sets the brush of the right side.
+    pub fn right<B: Into<Brush>>(mut self, brush: B) -> Self {
+        self.right = Some(brush.into());
+        self
+    }
+
+    /// Sets the brush of the bottom side.
+    pub fn bottom<B: Into<Brush>>(mut self, brush: B) -> Self {
+        self.bottom = Some(brush.into());
+        self
+    }
+
+    /// Returns `true` if no side is overwritten.
+    pub fn is_empty(&self) -> bool {
+        self.left.is_none() && self.top.is_none() && self.right.is_none() && self.bottom.is_none()
+    }
+
+    /// Gets the brushes of the left, top, right and bottom side. Sides without a brush get the given brush.
+    pub fn resolve<'a>(&'a self, brush: &'a Brush) -> [&'a Brush; 4] {
+        [
+            self.left.as_ref().unwrap_or(brush),
+            self.top.as_ref().unwrap_or(brush),
+            self.right.as_ref().unwrap_or(brush),
+            self.bottom.as_ref().unwrap_or(brush),
+        ]
+    }
+}
+
+impl<B: Into<Brush>> From<(B, B, B, B)> for BorderBrushes {
+    fn from(b: (B, B, B, B)) -> Self {
+        BorderBrushes::new()
+            .left(b.0)
+            .top(b.1)
+            .right(b.2)
+            .bottom(b.3)
+    }
+}
+
+impl From<Value> for BorderBrushes {
+    fn from(v: Value) -> Self {
+        let mut brushes = BorderBrushes::default();
+
+        if let ron::Value::Map(map) = v.0 {
+            for (key, value) in map.iter() {
+                if let (Ok(key), Ok(value)) = (
+                    key.clone().into_rust::<String>(),
+                    value.clone().into_rust::<String>(),
+                ) {
+                    let brush = Some(Brush::from(value));
+
+                    match key.as_str() {
+                        "left" => brushes.left = brush,
+                        "top" => brushes.top = brush,
+                        "right" => brushes.right = brush,
+                        "bottom" => brushes.bottom = brush,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        brushes
     }
 }
 
@@ -102,10 +230,10 @@ pub trait Bordered {
     fn set_border_brush(&mut self, brush: Brush);
 
     /// Gets the border radius.
-    fn border_radius(&self) -> f64;
+    fn border_radius(&self) -> CornerRadius;
 
     /// Sets the border radius.
-    fn set_border_radius(&mut self, radius: f64);
+    fn set_border_radius(&mut self, radius: CornerRadius);
 
     /// Gets the complete border.
     fn border(&self) -> &Border;
@@ -144,7 +272,11 @@ mod tests {
 
         let builder = BorderBuilder::new();
         let border = builder.radius(radius).build();
-        assert!(crate::f64_cmp(border.radius(), radius));
+        assert_eq!(border.radius(), CornerRadius::from(radius));
+
+        let border = BorderBuilder::new().radius((4, 4, 0, 0)).build();
+        assert_eq!(border.radius().top_right, 4.0);
+        assert_eq!(border.radius().bottom_right, 0.0);
     }
 
     #[test]
@@ -173,6 +305,23 @@ mod tests {
 
         let mut border = Border::default();
         border.set_radius(radius);
-        assert!(crate::f64_cmp(border.radius(), radius));
+        assert_eq!(border.radius(), CornerRadius::from(radius));
+    }
+
+    #[test]
+    fn test_side_brushes() {
+        let border = BorderBuilder::new()
+            .brush("#000000")
+            .brushes(BorderBrushes::new().bottom("#ffffff"))
+            .build();
+
+        let brushes = border.side_brushes();
+        assert_eq!(brushes[0], &Brush::from("#000000"));
+        assert_eq!(brushes[3], &Brush::from("#ffffff"));
+
+        let brushes = BorderBrushes::from(Value(
+            ron::de::from_str("{ \"bottom\": \"#ffffff\" }").unwrap(),
+        ));
+        assert_eq!(brushes, BorderBrushes::new().bottom("#ffffff"));
     }
 }
@@ -0,0 +1,213 @@
+use crate::prelude::*;
+
+/// Describes one shadow of a `BoxShadow`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Shadow {
+    /// Horizontal and vertical offset of the shadow.
+    pub offset: Point,
+
+    /// Blur radius of the shadow. `0` draws a sharp shadow.
+    pub blur: f64,
+
+    /// Grows the shadow on all sides, a negative value shrinks it.
+    pub spread: f64,
+
+    /// Color of the shadow.
+    pub color: Color,
+
+    /// If `true` the shadow is drawn inside of the shape.
+    pub inset: bool,
+}
+
+impl Shadow {
+    /// Creates a new shadow with the given offset, blur radius and color.
+    pub fn new(offset: impl Into<Point>, blur: f64, color: impl Into<Color>) -> Self {
+        Shadow {
+            offset: offset.into(),
+            blur,
+            color: color.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the spread of the shadow.
+    pub fn spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Draws the shadow inside of the shape.
+    pub fn inset(mut self) -> Self {
+        self.inset = true;
+        self
+    }
+
+    // reads a shadow like `inset 0 2px 4px 1px rgba(0, 0, 0, 0.3)`
+    fn from_expression(expression: &Expression) -> Option<Self> {
+        let parts = match expression {
+            Expression::Complex(parts) => parts.as_slice(),
+            _ => std::slice::from_ref(expression),
+        };
+
+        let mut lengths = vec![];
+        let mut color = None;
+        let mut inset = false;
+
+        for part in parts {
+            match part {
+                Expression::Other(keyword) if keyword == "inset" && !inset => inset = true,
+                Expression::Number(number, unit) if unit.is_empty() || unit == "px" => {
+                    lengths.push(f64::from(*number))
+                }
+                _ if color.is_none() && part.color().is_some() => color = part.color(),
+                _ => return None,
+            }
+        }
+
+        if lengths.len() < 2 || lengths.len() > 4 {
+            return None;
+        }
+
+        Some(Shadow {
+            offset: Point::new(lengths[0], lengths[1]),
+            blur: lengths.get(2).copied().unwrap_or_default().max(0.0),
+            spread: lengths.get(3).copied().unwrap_or_default(),
+            color: color.unwrap_or_else(|| Color::rgb(0, 0, 0)),
+            inset,
+        })
+    }
+}
+
+/// A `BoxShadow` describes a list of shadows that are drawn below or inside of a shape,
+/// e.g. to raise cards, popups and menus from the background.
+///
+/// The string declaration follows the CSS `box-shadow` syntax. Each shadow is composed of
+/// the optional `inset` keyword, the horizontal and vertical offset, the optional blur radius,
+/// the optional spread and the optional color (black if omitted). Multiple shadows are
+/// separated by a comma, the first shadow is drawn on top. `none` declares no shadow.
+///
+/// ## Examples
+///
+/// ```text
+/// .box_shadow("0 2 4 rgba(0, 0, 0, 0.3)")
+/// .box_shadow("0 8px 16px -4px #60000000, 0 1px 2px #40000000")
+/// .box_shadow("inset 0 1 3 #000000")
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BoxShadow {
+    shadows: Vec<Shadow>,
+}
+
+impl BoxShadow {
+    /// Creates a box shadow without shadows.
+    pub fn new() -> Self {
+        BoxShadow::default()
+    }
+
+    /// Parses a box shadow declaration. Returns `None` if the declaration is not valid.
+    pub fn parse(s: &str) -> Option<Self> {
+        if s.trim() == "none" {
+            return Some(BoxShadow::new());
+        }
+
+        let mut chrs = s.chars().peekable();
+        let mut shadows = vec![];
+
+        loop {
+            shadows.push(Shadow::from_expression(&parse_expression_with_complex(
+                &mut chrs,
+            )?)?);
+
+            match chrs.next() {
+                Some(',') => continue,
+                None => break,
+                _ => return None,
+            }
+        }
+
+        Some(BoxShadow { shadows })
+    }
+
+    /// Adds a shadow below the existing ones.
+    pub fn push(&mut self, shadow: Shadow) {
+        self.shadows.push(shadow);
+    }
+
+    /// Gets the shadows, the first shadow is drawn on top.
+    pub fn shadows(&self) -> &[Shadow] {
+        &self.shadows
+    }
+
+    /// Returns `true` if there is no visible shadow.
+    pub fn is_empty(&self) -> bool {
+        self.shadows.iter().all(|s| s.color.a() == 0)
+    }
+}
+
+impl From<Shadow> for BoxShadow {
+    fn from(shadow: Shadow) -> BoxShadow {
+        BoxShadow {
+            shadows: vec![shadow],
+        }
+    }
+}
+
+impl From<Vec<Shadow>> for BoxShadow {
+    fn from(shadows: Vec<Shadow>) -> BoxShadow {
+        BoxShadow { shadows }
+    }
+}
+
+impl From<&str> for BoxShadow {
+    fn from(s: &str) -> BoxShadow {
+        BoxShadow::parse(s).unwrap_or_default()
+    }
+}
+
+impl From<String> for BoxShadow {
+    fn from(s: String) -> BoxShadow {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Value> for BoxShadow {
+    fn from(v: Value) -> Self {
+        let value = v.get::<String>();
+        BoxShadow::from(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let shadow = BoxShadow::from("0 2px 4px #80000000");
+        assert_eq!(shadow.shadows().len(), 1);
+        assert_eq!(shadow.shadows()[0].offset, Point::new(0.0, 2.0));
+        assert_eq!(shadow.shadows()[0].blur, 4.0);
+        assert_eq!(shadow.shadows()[0].spread, 0.0);
+        assert_eq!(shadow.shadows()[0].color.a(), 0x80);
+        assert!(!shadow.shadows()[0].inset);
+
+        let shadow = BoxShadow::from("inset 1 -1 2 3 rgb(255, 0, 0), 0 8 16 black");
+        assert_eq!(shadow.shadows().len(), 2);
+        assert!(shadow.shadows()[0].inset);
+        assert_eq!(shadow.shadows()[0].offset, Point::new(1.0, -1.0));
+        assert_eq!(shadow.shadows()[0].spread, 3.0);
+        assert_eq!(shadow.shadows()[0].color, Color::rgb(255, 0, 0));
+        assert_eq!(shadow.shadows()[1].blur, 16.0);
+
+        assert_eq!(BoxShadow::parse("none"), Some(BoxShadow::new()));
+        assert_eq!(
+            BoxShadow::parse("2 2").unwrap().shadows()[0].color,
+            Color::rgb(0, 0, 0)
+        );
+        assert!(BoxShadow::parse("2").is_none());
+        assert!(BoxShadow::parse("2 2 2 2 2").is_none());
+        assert!(BoxShadow::parse("2em 2em").is_none());
+        assert!(BoxShadow::parse("0 2 4 black,").is_none());
+        assert!(BoxShadow::parse("").is_none());
+    }
+}
@@ -8,13 +8,14 @@ use crate::prelude::*;
 /// * gradients of colors
 /// * gradients with directions
 /// * gradients with angles
+/// * images
 ///
 /// The string declaration of a `Brush` is composed combining the following
 /// syntax elements:
 ///
 /// 1. The `color name`
 /// 2. The `gradient` string
-///    * the gradient type (linear, repeating-linear)
+///    * the gradient type (linear, radial, conic and their repeating variants)
 ///    * gradient attributes (direction-identifier, angles, color names )
 ///
 /// ## Examples
@@ -27,6 +28,9 @@ use crate::prelude::*;
 /// .background("repeating-linear-gradient(0.25turn, rgba(255, 255, 0, 0.6), dodgerblue, deepskyblue)")
 /// .background("linear-gradient(-90deg, hsv(201, 94%, 80.5%), steelblue)")
 /// .background("linear-gradient(to top right, white, skyblue 60%, lightskyblue 80%, yellow 83%, yellow)")
+/// .background("radial-gradient(circle at top left, white, steelblue)")
+/// .background("conic-gradient(red, yellow, lime, aqua, blue, fuchsia, red)")
+/// .background("url(assets/tile.png) repeat-x")
 /// ```
 /// Read on to see how the syntax is composed.
 ///
@@ -124,6 +128,29 @@ use crate::prelude::*;
 /// [repeating-]linear-gradient({direction-identifier}, {initial color-name}, {terminating color-name}
 /// ```
 ///
+/// Radial gradients spread from a center in the shape of a circle or an ellipse. Like in CSS
+/// the size is given by a keyword (`closest-side`, `closest-corner`, `farthest-side`,
+/// `farthest-corner`) or by lengths, the position by keywords (`left`, `center`, `top`, ...)
+/// or lengths.
+///
+/// ```text
+/// [repeating-]radial-gradient([circle|ellipse] [{size}] [at {position}], {Color} [{Stop position}{%|px}], ...)
+/// ```
+///
+/// Conic gradients rotate clockwise around a center, starting at the top. Stops are placed
+/// with angles or percentages.
+///
+/// ```text
+/// [repeating-]conic-gradient([from {angle}{deg|rad|turn}] [at {position}], {Color} [{Stop position}{deg|rad|turn|%}], ...)
+/// ```
+///
+/// ## Definition of an image
+/// An image brush paints the image with the given key or file path, see `ImageBrush`.
+///
+/// ```text
+/// url({key or path}) [no-repeat|repeat|repeat-x|repeat-y] [stretch|contain|cover] [{X Offset}px {Y Offset}px]
+/// ```
+///
 //#[cfg(feature = "nightly")]
 //#[doc(include = "../colors.md")]
 
@@ -134,6 +161,9 @@ pub enum Brush {
 
     /// Paints an area with a gradient.
     Gradient(Gradient),
+
+    /// Paints an area with an image.
+    Image(ImageBrush),
 }
 
 impl Brush {
@@ -181,6 +211,12 @@ impl From<Gradient> for Brush {
     }
 }
 
+impl From<ImageBrush> for Brush {
+    fn from(i: ImageBrush) -> Brush {
+        Brush::Image(i)
+    }
+}
+
 impl From<&str> for Brush {
     fn from(s: &str) -> Brush {
         Expression::from(s).brush().unwrap_or_default()
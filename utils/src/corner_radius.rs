@@ -0,0 +1,182 @@
+use crate::Value;
+
+/// Describes the radii of the four corners of a rectangle e.g. a border radius.
+///
+/// In tuples and theme sequences the corners are ordered like in CSS: top left, top right,
+/// bottom right and bottom left. `"border_radius": (4, 4, 0, 0)` rounds only the top corners.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct CornerRadius {
+    /// Radius of the top left corner.
+    pub top_left: f64,
+
+    /// Radius of the top right corner.
+    pub top_right: f64,
+
+    /// Radius of the bottom right corner.
+    pub bottom_right: f64,
+
+    /// Radius of the bottom left corner.
+    pub bottom_left: f64,
+}
+
+impl CornerRadius {
+    /// Create a new corner radius with the given parameters.
+    pub fn new(top_left: f64, top_right: f64, bottom_right: f64, bottom_left: f64) -> Self {
+        CornerRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Returns the radius if all corners have the same radius.
+    pub fn uniform(&self) -> Option<f64> {
+        if (self.top_left - self.top_right).abs() < f64::EPSILON
+            && (self.top_left - self.bottom_right).abs() < f64::EPSILON
+            && (self.top_left - self.bottom_left).abs() < f64::EPSILON
+        {
+            return Some(self.top_left);
+        }
+
+        None
+    }
+
+    /// Gets the largest radius of all corners.
+    pub fn max(&self) -> f64 {
+        self.top_left
+            .max(self.top_right)
+            .max(self.bottom_right)
+            .max(self.bottom_left)
+    }
+
+    /// Returns `true` if no corner is rounded.
+    pub fn is_zero(&self) -> bool {
+        self.max() <= 0.0
+    }
+
+    /// Returns the radius grown by the given value on each rounded corner, e.g. to surround a
+    /// shape with a spread. Radii are never negative.
+    pub fn grow(&self, value: f64) -> Self {
+        let grow = |r: f64| if r > 0.0 { (r + value).max(0.0) } else { 0.0 };
+
+        CornerRadius::new(
+            grow(self.top_left),
+            grow(self.top_right),
+            grow(self.bottom_right),
+            grow(self.bottom_left),
+        )
+    }
+
+    /// Limits the radii, so that the corners fit in a rectangle of the given size.
+    pub fn fit(&self, width: f64, height: f64) -> Self {
+        let max = (width / 2.0).min(height / 2.0).max(0.0);
+        let fit = |r: f64| r.max(0.0).min(max);
+
+        CornerRadius::new(
+            fit(self.top_left),
+            fit(self.top_right),
+            fit(self.bottom_right),
+            fit(self.bottom_left),
+        )
+    }
+}
+
+// --- Trait implementations ---
+
+impl From<(i32, i32, i32, i32)> for CornerRadius {
+    fn from(r: (i32, i32, i32, i32)) -> Self {
+        CornerRadius::new(r.0 as f64, r.1 as f64, r.2 as f64, r.3 as f64)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for CornerRadius {
+    fn from(r: (f64, f64, f64, f64)) -> Self {
+        CornerRadius::new(r.0, r.1, r.2, r.3)
+    }
+}
+
+impl From<i32> for CornerRadius {
+    fn from(r: i32) -> Self {
+        CornerRadius::from(r as f64)
+    }
+}
+
+impl From<f64> for CornerRadius {
+    fn from(r: f64) -> Self {
+        CornerRadius::new(r, r, r, r)
+    }
+}
+
+impl From<Value> for CornerRadius {
+    fn from(v: Value) -> Self {
+        let number = |value: &ron::Value| value.clone().into_rust::<f64>().unwrap_or_default();
+
+        match v.0 {
+            ron::Value::Number(value) => CornerRadius::from(value.into_f64()),
+            ron::Value::Seq(values) if values.len() == 4 => CornerRadius::new(
+                number(&values[0]),
+                number(&values[1]),
+                number(&values[2]),
+                number(&values[3]),
+            ),
+            ron::Value::Map(map) => {
+                let mut radius = CornerRadius::default();
+
+                for (key, value) in map.iter() {
+                    if let Ok(key) = key.clone().into_rust::<String>() {
+                        match key.as_str() {
+                            "top_left" => radius.top_left = number(value),
+                            "top_right" => radius.top_right = number(value),
+                            "bottom_right" => radius.bottom_right = number(value),
+                            "bottom_left" => radius.bottom_left = number(value),
+                            _ => {}
+                        }
+                    }
+                }
+
+                radius
+            }
+            _ => CornerRadius::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_ron(s: &str) -> CornerRadius {
+        CornerRadius::from(Value(ron::de::from_str(s).unwrap()))
+    }
+
+    #[test]
+    fn test_from_value() {
+        assert_eq!(from_ron("4"), CornerRadius::from(4.0));
+        assert_eq!(
+            from_ron("(4, 4, 0, 0)"),
+            CornerRadius::new(4.0, 4.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            from_ron("{ \"bottom_left\": 2.5 }"),
+            CornerRadius::new(0.0, 0.0, 0.0, 2.5)
+        );
+        assert_eq!(from_ron("(4, 4)"), CornerRadius::default());
+    }
+
+    #[test]
+    fn test_uniform() {
+        assert_eq!(CornerRadius::from(2).uniform(), Some(2.0));
+        assert_eq!(CornerRadius::from((2, 2, 0, 2)).uniform(), None);
+        assert!(CornerRadius::default().is_zero());
+        assert_eq!(CornerRadius::from((2, 6, 0, 4)).max(), 6.0);
+    }
+
+    #[test]
+    fn test_grow_and_fit() {
+        let radius = CornerRadius::from((4, 4, 0, 0));
+        assert_eq!(radius.grow(2.0), CornerRadius::from((6, 6, 0, 0)));
+        assert_eq!(radius.grow(-6.0), CornerRadius::default());
+        assert_eq!(radius.fit(20.0, 6.0), CornerRadius::from((3, 3, 0, 0)));
+    }
+}
@@ -78,7 +78,8 @@ impl Expression {
         }
     }
 
-    fn gradient_stop(&self) -> Option<GradientStop> {
+    // stops of conic gradients are placed with angles or percentages
+    fn gradient_stop(&self, conic: bool) -> Option<GradientStop> {
         if let Some(color) = self.color() {
             return Some(GradientStop { pos: None, color });
         }
@@ -89,6 +90,9 @@ impl Expression {
                     None => return None,
                 };
                 let pos = match v[1] {
+                    Expression::Number(_, ref m) if conic && m != "%" => {
+                        OnLinePos::from_unit_percent(v[1].angle()?.to_turn())
+                    }
                     Expression::Number(n, ref m) => OnLinePos::try_from((n, &m[..])).ok()?,
                     _ => return None,
                 };
@@ -136,6 +140,149 @@ impl Expression {
         }
     }
 
+    // parses a position like `center`, `left top` or `20% 40px` of a radial or conic gradient
+    fn gradient_position(exprs: &[Expression]) -> Option<OnPlanePos> {
+        let percent = |p| OnLinePos::new(p, OnLinePosKind::Percentage);
+        let keyword = |expr: &Expression| match expr {
+            Expression::Other(s) => Some(s.clone()),
+            _ => None,
+        };
+        let horizontal = |expr: &Expression| match expr {
+            Expression::Number(n, u) => OnLinePos::try_from((*n, &u[..])).ok(),
+            Expression::Other(s) => match &s[..] {
+                "left" => Some(percent(0.0)),
+                "center" => Some(percent(50.0)),
+                "right" => Some(percent(100.0)),
+                _ => None,
+            },
+            _ => None,
+        };
+        let vertical = |expr: &Expression| match expr {
+            Expression::Number(n, u) => OnLinePos::try_from((*n, &u[..])).ok(),
+            Expression::Other(s) => match &s[..] {
+                "top" => Some(percent(0.0)),
+                "center" => Some(percent(50.0)),
+                "bottom" => Some(percent(100.0)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match exprs {
+            [expr] => match keyword(expr).as_deref() {
+                Some("top") | Some("bottom") => {
+                    Some(OnPlanePos::new(percent(50.0), vertical(expr)?))
+                }
+                _ => Some(OnPlanePos::new(horizontal(expr)?, percent(50.0))),
+            },
+            [first, second] => {
+                let swap = matches!(keyword(first).as_deref(), Some("top") | Some("bottom"))
+                    || matches!(keyword(second).as_deref(), Some("left") | Some("right"));
+
+                if swap {
+                    Some(OnPlanePos::new(horizontal(second)?, vertical(first)?))
+                } else {
+                    Some(OnPlanePos::new(horizontal(first)?, vertical(second)?))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Try to convert `self` into the coordinates of a radial gradient e.g. `circle at top left`
+    /// or `ellipse closest-side at 20% 40%`.
+    pub fn radial_gradient_coords(&self) -> Option<RadialGradientCoords> {
+        let exprs = match self {
+            Expression::Complex(exprs) => &exprs[..],
+            expr => std::slice::from_ref(expr),
+        };
+        let at = exprs
+            .iter()
+            .position(|e| matches!(e, Expression::Other(s) if s == "at"))
+            .unwrap_or(exprs.len());
+
+        let mut coords = RadialGradientCoords::default();
+        let mut shape = None;
+        let mut size = None;
+        let mut lengths = vec![];
+
+        for expr in &exprs[..at] {
+            match expr {
+                Expression::Other(s) => match &s[..] {
+                    "circle" if shape.is_none() => shape = Some(RadialGradientShape::Circle),
+                    "ellipse" if shape.is_none() => shape = Some(RadialGradientShape::Ellipse),
+                    "closest-side" if size.is_none() => {
+                        size = Some(RadialGradientSize::ClosestSide)
+                    }
+                    "closest-corner" if size.is_none() => {
+                        size = Some(RadialGradientSize::ClosestCorner)
+                    }
+                    "farthest-side" if size.is_none() => {
+                        size = Some(RadialGradientSize::FarthestSide)
+                    }
+                    "farthest-corner" if size.is_none() => {
+                        size = Some(RadialGradientSize::FarthestCorner)
+                    }
+                    _ => return None,
+                },
+                Expression::Number(n, u) => lengths.push(OnLinePos::try_from((*n, &u[..])).ok()?),
+                _ => return None,
+            }
+        }
+
+        // like in css a single length describes a circle and two lengths an ellipse
+        match (shape, size, &lengths[..]) {
+            (_, _, []) => {
+                coords.shape = shape.unwrap_or_default();
+                coords.size = size.unwrap_or_default();
+            }
+            (None, None, [radius]) | (Some(RadialGradientShape::Circle), None, [radius]) => {
+                coords.shape = RadialGradientShape::Circle;
+                coords.size = RadialGradientSize::Length(*radius, *radius);
+            }
+            (None, None, [x, y]) | (Some(RadialGradientShape::Ellipse), None, [x, y]) => {
+                coords.shape = RadialGradientShape::Ellipse;
+                coords.size = RadialGradientSize::Length(*x, *y);
+            }
+            _ => return None,
+        }
+
+        if at < exprs.len() {
+            coords.position = Self::gradient_position(&exprs[at + 1..])?;
+        } else if at == 0 {
+            return None;
+        }
+
+        Some(coords)
+    }
+
+    /// Try to convert `self` into the coordinates of a conic gradient e.g. `from 90deg at center`.
+    pub fn conic_gradient_coords(&self) -> Option<ConicGradientCoords> {
+        let exprs = match self {
+            Expression::Complex(exprs) => &exprs[..],
+            expr => std::slice::from_ref(expr),
+        };
+
+        let mut coords = ConicGradientCoords::default();
+        let mut i = 0;
+
+        if matches!(exprs.get(i), Some(Expression::Other(s)) if s == "from") {
+            coords.angle = exprs.get(i + 1)?.angle()?;
+            i += 2;
+        }
+
+        if matches!(exprs.get(i), Some(Expression::Other(s)) if s == "at") {
+            coords.position = Self::gradient_position(&exprs[i + 1..])?;
+            i = exprs.len();
+        }
+
+        if i == 0 || i < exprs.len() {
+            return None;
+        }
+
+        Some(coords)
+    }
+
     pub fn css_gradient(&self) -> Option<Gradient> {
         let mut displacement = OnPlanePos::new(
             OnLinePos::new(0.0, OnLinePosKind::Pixels),
@@ -176,21 +323,27 @@ impl Expression {
         if args.is_empty() {
             return None;
         }
-        let (radial, repeat) = match &name[..] {
-            "repeating-linear-gradient" => (false, true),
-            "linear-gradient" => (false, false),
-            "radial-gradient" => (true, false),
-            "repeating-radial-gradient" => (true, true),
-            _ => {
-                return None;
-            }
+        let (name, repeat) = match name.strip_prefix("repeating-") {
+            Some(name) => (name, true),
+            None => (&name[..], false),
         };
         let mut i = 0;
         let kind;
-        if radial {
-            // TODO: Implement radial gradients
-            return None;
-        } else {
+        if name == "radial-gradient" {
+            let mut coords = RadialGradientCoords::default();
+            if args[0].gradient_stop(false).is_none() {
+                coords = args[0].radial_gradient_coords()?;
+                i += 1;
+            }
+            kind = GradientKind::Radial(coords);
+        } else if name == "conic-gradient" {
+            let mut coords = ConicGradientCoords::default();
+            if args[0].gradient_stop(true).is_none() {
+                coords = args[0].conic_gradient_coords()?;
+                i += 1;
+            }
+            kind = GradientKind::Conic(coords);
+        } else if name == "linear-gradient" {
             let mut coords = LinearGradientCoords::Angle {
                 displacement,
                 angle: Angle::zero(),
@@ -208,11 +361,14 @@ impl Expression {
                 i += 1;
             }
             kind = GradientKind::Linear(coords);
+        } else {
+            return None;
         }
+        let conic = matches!(kind, GradientKind::Conic(_));
         let stops: Vec<GradientStop> = args
             .iter()
             .skip(i)
-            .filter_map(|stop| stop.gradient_stop())
+            .filter_map(|stop| stop.gradient_stop(conic))
             .collect();
         if stops.is_empty() {
             return None;
@@ -231,6 +387,9 @@ impl Expression {
         if let Some(g) = self.css_gradient() {
             return Some(Brush::from(g));
         }
+        if let Some(i) = ImageBrush::from_expression(self) {
+            return Some(Brush::from(i));
+        }
         None
     }
 }
@@ -365,6 +524,14 @@ impl OnPlanePos {
         OnPlanePos { x, y }
     }
 
+    /// Returns the center of a plane.
+    pub fn center() -> OnPlanePos {
+        OnPlanePos::new(
+            OnLinePos::new(50.0, OnLinePosKind::Percentage),
+            OnLinePos::new(50.0, OnLinePosKind::Percentage),
+        )
+    }
+
     pub fn x(&self) -> OnLinePos {
         self.x
     }
@@ -507,3 +674,89 @@ impl Neg for OnLinePos {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(s: &str) -> Option<Gradient> {
+        Expression::from(s).css_gradient()
+    }
+
+    fn percent(pos: f64) -> OnLinePos {
+        OnLinePos::new(pos, OnLinePosKind::Percentage)
+    }
+
+    #[test]
+    fn test_radial_gradient() {
+        let g = gradient("radial-gradient(red, blue)").unwrap();
+        assert_eq!(
+            g.kind,
+            GradientKind::Radial(RadialGradientCoords::default())
+        );
+        assert_eq!(g.stops.len(), 2);
+
+        let g =
+            gradient("radial-gradient(circle closest-side at left top, red, blue 80%)").unwrap();
+        assert_eq!(
+            g.kind,
+            GradientKind::Radial(RadialGradientCoords {
+                shape: RadialGradientShape::Circle,
+                size: RadialGradientSize::ClosestSide,
+                position: OnPlanePos::new(percent(0.0), percent(0.0)),
+            })
+        );
+        assert_eq!(g.stops[1].pos, Some(percent(80.0)));
+
+        let g = gradient("repeating-radial-gradient(20px 10px at 30% bottom, red, blue)").unwrap();
+        assert!(g.repeat);
+        assert_eq!(
+            g.kind,
+            GradientKind::Radial(RadialGradientCoords {
+                shape: RadialGradientShape::Ellipse,
+                size: RadialGradientSize::Length(
+                    OnLinePos::new(20.0, OnLinePosKind::Pixels),
+                    OnLinePos::new(10.0, OnLinePosKind::Pixels)
+                ),
+                position: OnPlanePos::new(percent(30.0), percent(100.0)),
+            })
+        );
+
+        assert!(gradient("radial-gradient(circle 10px 20px, red, blue)").is_none());
+        assert!(gradient("radial-gradient(square, red, blue)").is_none());
+        assert!(gradient("radial-gradient(circle at, red, blue)").is_none());
+    }
+
+    #[test]
+    fn test_conic_gradient() {
+        let g = gradient("conic-gradient(red, yellow, lime, aqua, blue, fuchsia, red)").unwrap();
+        assert_eq!(g.kind, GradientKind::Conic(ConicGradientCoords::default()));
+        assert_eq!(g.stops.len(), 7);
+
+        let g = gradient("conic-gradient(from 90deg at 25% top, red, blue 0.5turn, red 270deg)")
+            .unwrap();
+        assert_eq!(
+            g.kind,
+            GradientKind::Conic(ConicGradientCoords {
+                angle: Angle::from_degrees(90.0),
+                position: OnPlanePos::new(percent(25.0), percent(0.0)),
+            })
+        );
+        assert_eq!(g.stops[1].pos, Some(percent(50.0)));
+        assert_eq!(g.stops[2].pos, Some(percent(75.0)));
+
+        assert!(gradient("conic-gradient(to 90deg, red, blue)").is_none());
+        assert_eq!(
+            gradient("conic-gradient(red, blue 10px)")
+                .unwrap()
+                .stops
+                .len(),
+            1
+        );
+        assert!(gradient("linear-gradient(red 90deg, blue)")
+            .unwrap()
+            .stops
+            .iter()
+            .all(|stop| stop.pos.is_none()));
+    }
+}
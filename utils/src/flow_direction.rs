@@ -0,0 +1,54 @@
+/// Describes the direction in which content flows horizontally. Used to mirror the layout for
+/// right-to-left languages like arabic or hebrew.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FlowDirection {
+    /// Content flows from left to right.
+    LeftToRight,
+
+    /// Content flows from right to left. Horizontal layouts, alignments and margins are mirrored.
+    RightToLeft,
+}
+
+impl FlowDirection {
+    /// Returns `true` if the content flows from right to left.
+    pub fn is_right_to_left(self) -> bool {
+        self == FlowDirection::RightToLeft
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for FlowDirection {
+    fn from(t: &str) -> Self {
+        match t {
+            "RightToLeft" | "right_to_left" | "rtl" | "RTL" => FlowDirection::RightToLeft,
+            _ => FlowDirection::LeftToRight,
+        }
+    }
+}
+
+impl Default for FlowDirection {
+    fn default() -> FlowDirection {
+        FlowDirection::LeftToRight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let flow_direction: FlowDirection = "RightToLeft".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "rtl".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "LeftToRight".into();
+        assert_eq!(flow_direction, FlowDirection::LeftToRight);
+
+        let flow_direction: FlowDirection = "other".into();
+        assert_eq!(flow_direction, FlowDirection::LeftToRight);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Angle, Color, OnLinePos, OnPlanePos, Point, RelativeDir};
+use std::f64::consts::SQRT_2;
+
+use crate::{Angle, Color, OnLinePos, OnPlanePos, Point, RelativeDir, Size, TAU};
 
 /// Describes a position on a colorful gradient.
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -104,6 +106,140 @@ impl Default for LinearGradientCoords {
     }
 }
 
+/// Describes the shape of a radial gradient.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RadialGradientShape {
+    Circle,
+    Ellipse,
+}
+
+impl Default for RadialGradientShape {
+    fn default() -> RadialGradientShape {
+        RadialGradientShape::Ellipse
+    }
+}
+
+/// Describes how far a radial gradient reaches from its center.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RadialGradientSize {
+    /// Ends at the side of the target shape that is closest to the center.
+    ClosestSide,
+    /// Ends at the corner of the target shape that is closest to the center.
+    ClosestCorner,
+    /// Ends at the side of the target shape that is farthest from the center.
+    FarthestSide,
+    /// Ends at the corner of the target shape that is farthest from the center.
+    FarthestCorner,
+    /// Defines the horizontal and vertical radius, circles use only the horizontal one.
+    Length(OnLinePos, OnLinePos),
+}
+
+impl Default for RadialGradientSize {
+    fn default() -> RadialGradientSize {
+        RadialGradientSize::FarthestCorner
+    }
+}
+
+/// Describes the coordinates of a colorful radial gradient.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RadialGradientCoords {
+    pub shape: RadialGradientShape,
+    pub size: RadialGradientSize,
+    /// Center of the gradient inside of the target shape.
+    pub position: OnPlanePos,
+}
+
+impl RadialGradientCoords {
+    /// Returns the center of the gradient in pixels relative to the target shape.
+    pub fn center(&self, size: Size) -> Point {
+        self.position.pixels(size)
+    }
+
+    /// Returns the horizontal and vertical radius of the gradient in pixels for a target shape
+    /// of the given size.
+    pub fn radii(&self, size: Size) -> (f64, f64) {
+        let center = self.center(size);
+        let (near_x, far_x) = (
+            center.x().min(size.width() - center.x()).abs(),
+            center.x().max(size.width() - center.x()).abs(),
+        );
+        let (near_y, far_y) = (
+            center.y().min(size.height() - center.y()).abs(),
+            center.y().max(size.height() - center.y()).abs(),
+        );
+
+        match (self.shape, self.size) {
+            (_, RadialGradientSize::Length(x, y)) => match self.shape {
+                RadialGradientShape::Circle => (x.pixels(size.width()), x.pixels(size.width())),
+                RadialGradientShape::Ellipse => (x.pixels(size.width()), y.pixels(size.height())),
+            },
+            (RadialGradientShape::Circle, RadialGradientSize::ClosestSide) => {
+                (near_x.min(near_y), near_x.min(near_y))
+            }
+            (RadialGradientShape::Circle, RadialGradientSize::FarthestSide) => {
+                (far_x.max(far_y), far_x.max(far_y))
+            }
+            (RadialGradientShape::Circle, RadialGradientSize::ClosestCorner) => {
+                (near_x.hypot(near_y), near_x.hypot(near_y))
+            }
+            (RadialGradientShape::Circle, RadialGradientSize::FarthestCorner) => {
+                (far_x.hypot(far_y), far_x.hypot(far_y))
+            }
+            (RadialGradientShape::Ellipse, RadialGradientSize::ClosestSide) => (near_x, near_y),
+            (RadialGradientShape::Ellipse, RadialGradientSize::FarthestSide) => (far_x, far_y),
+            // an ellipse through the corner keeps the aspect ratio of the sides
+            (RadialGradientShape::Ellipse, RadialGradientSize::ClosestCorner) => {
+                (near_x * SQRT_2, near_y * SQRT_2)
+            }
+            (RadialGradientShape::Ellipse, RadialGradientSize::FarthestCorner) => {
+                (far_x * SQRT_2, far_y * SQRT_2)
+            }
+        }
+    }
+}
+
+impl Default for RadialGradientCoords {
+    fn default() -> RadialGradientCoords {
+        RadialGradientCoords {
+            shape: RadialGradientShape::default(),
+            size: RadialGradientSize::default(),
+            position: OnPlanePos::center(),
+        }
+    }
+}
+
+/// Describes the coordinates of a colorful conic gradient. The colors are rotated clockwise
+/// around the center, starting at the top.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ConicGradientCoords {
+    /// Rotation of the start of the gradient.
+    pub angle: Angle,
+    /// Center of the gradient inside of the target shape.
+    pub position: OnPlanePos,
+}
+
+impl ConicGradientCoords {
+    /// Returns the center of the gradient in pixels relative to the target shape.
+    pub fn center(&self, size: Size) -> Point {
+        self.position.pixels(size)
+    }
+
+    /// Returns the position of the given point on the gradient in a range from 0.0 to 1.0.
+    pub fn unit_percent(&self, center: Point, point: Point) -> f64 {
+        let angle = (point.x() - center.x()).atan2(center.y() - point.y());
+        ((angle - self.angle.to_radians()) / TAU).rem_euclid(1.0)
+    }
+}
+
+impl Default for ConicGradientCoords {
+    fn default() -> ConicGradientCoords {
+        ConicGradientCoords {
+            angle: Angle::zero(),
+            position: OnPlanePos::center(),
+        }
+    }
+}
+
 /// Describes a colorful gradient.
 #[derive(Clone, PartialEq, Debug)]
 pub struct Gradient {
@@ -135,7 +271,8 @@ impl Default for Gradient {
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum GradientKind {
     Linear(LinearGradientCoords),
-    // Radial(RadialGradient),
+    Radial(RadialGradientCoords),
+    Conic(ConicGradientCoords),
 }
 
 impl Default for GradientKind {
@@ -143,3 +280,55 @@ impl Default for GradientKind {
         GradientKind::Linear(LinearGradientCoords::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OnLinePosKind;
+
+    #[test]
+    fn test_radial_radii() {
+        let size = Size::new(40.0, 20.0);
+        let mut coords = RadialGradientCoords {
+            shape: RadialGradientShape::Circle,
+            size: RadialGradientSize::ClosestSide,
+            position: OnPlanePos::new(
+                OnLinePos::new(10.0, OnLinePosKind::Pixels),
+                OnLinePos::new(50.0, OnLinePosKind::Percentage),
+            ),
+        };
+        assert_eq!(coords.center(size), Point::new(10.0, 10.0));
+        assert_eq!(coords.radii(size), (10.0, 10.0));
+
+        coords.size = RadialGradientSize::FarthestSide;
+        assert_eq!(coords.radii(size), (30.0, 30.0));
+
+        coords.size = RadialGradientSize::FarthestCorner;
+        assert_eq!(
+            coords.radii(size),
+            (30.0f64.hypot(10.0), 30.0f64.hypot(10.0))
+        );
+
+        coords.shape = RadialGradientShape::Ellipse;
+        coords.size = RadialGradientSize::ClosestSide;
+        assert_eq!(coords.radii(size), (10.0, 10.0));
+
+        coords.size = RadialGradientSize::Length(
+            OnLinePos::new(50.0, OnLinePosKind::Percentage),
+            OnLinePos::new(4.0, OnLinePosKind::Pixels),
+        );
+        assert_eq!(coords.radii(size), (20.0, 4.0));
+    }
+
+    #[test]
+    fn test_conic_unit_percent() {
+        let mut coords = ConicGradientCoords::default();
+        let center = Point::new(10.0, 10.0);
+        assert!((coords.unit_percent(center, Point::new(10.0, 0.0))).abs() < 1e-9);
+        assert!((coords.unit_percent(center, Point::new(20.0, 10.0)) - 0.25).abs() < 1e-9);
+        assert!((coords.unit_percent(center, Point::new(0.0, 10.0)) - 0.75).abs() < 1e-9);
+
+        coords.angle = Angle::from_degrees(90.0);
+        assert!((coords.unit_percent(center, Point::new(10.0, 20.0)) - 0.25).abs() < 1e-9);
+    }
+}
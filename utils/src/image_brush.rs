@@ -0,0 +1,233 @@
+use crate::prelude::*;
+
+/// Describes how an `ImageBrush` repeats its image.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ImageRepeat {
+    /// Draws the image once.
+    NoRepeat,
+
+    /// Tiles the image horizontally and vertically.
+    Repeat,
+
+    /// Tiles the image only horizontally.
+    RepeatX,
+
+    /// Tiles the image only vertically.
+    RepeatY,
+}
+
+impl Default for ImageRepeat {
+    fn default() -> Self {
+        ImageRepeat::Repeat
+    }
+}
+
+impl From<&str> for ImageRepeat {
+    fn from(s: &str) -> ImageRepeat {
+        match s {
+            "no-repeat" | "NoRepeat" => ImageRepeat::NoRepeat,
+            "repeat-x" | "RepeatX" => ImageRepeat::RepeatX,
+            "repeat-y" | "RepeatY" => ImageRepeat::RepeatY,
+            _ => ImageRepeat::Repeat,
+        }
+    }
+}
+
+/// Describes how an `ImageBrush` scales its image to the painted area.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ImageFit {
+    /// Keeps the original size of the image.
+    None,
+
+    /// Scales the image to the size of the area, the aspect ratio is not kept.
+    Stretch,
+
+    /// Scales the image to the largest size that fits completely into the area.
+    Contain,
+
+    /// Scales the image to the smallest size that covers the whole area.
+    Cover,
+}
+
+impl Default for ImageFit {
+    fn default() -> Self {
+        ImageFit::None
+    }
+}
+
+impl From<&str> for ImageFit {
+    fn from(s: &str) -> ImageFit {
+        match s {
+            "stretch" | "Stretch" => ImageFit::Stretch,
+            "contain" | "Contain" => ImageFit::Contain,
+            "cover" | "Cover" => ImageFit::Cover,
+            _ => ImageFit::None,
+        }
+    }
+}
+
+/// Paints an area with an image e.g. a texture or a tiled pattern.
+///
+/// The source is the key of an image registered on the theme or the window or the path of an
+/// image file. In themes an image brush is written as `url({source}) [{repeat}] [{fit}] [{x} {y}]`
+/// e.g. `url(header) no-repeat cover` or `url(assets/tile.png) repeat-x 0 8`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImageBrush {
+    /// Key of a registered image or path of an image file.
+    pub source: String,
+
+    /// Describes how the image is repeated.
+    pub repeat: ImageRepeat,
+
+    /// Describes how the image is scaled.
+    pub fit: ImageFit,
+
+    /// Offset of the image from the top left corner of the painted area.
+    pub offset: Point,
+}
+
+impl ImageBrush {
+    /// Creates a new image brush that tiles the image with the given source.
+    pub fn new(source: impl Into<String>) -> Self {
+        ImageBrush {
+            source: source.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the repeat mode.
+    pub fn repeat(mut self, repeat: impl Into<ImageRepeat>) -> Self {
+        self.repeat = repeat.into();
+        self
+    }
+
+    /// Sets the fit mode.
+    pub fn fit(mut self, fit: impl Into<ImageFit>) -> Self {
+        self.fit = fit.into();
+        self
+    }
+
+    /// Sets the offset.
+    pub fn offset(mut self, offset: impl Into<Point>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Returns the size of one tile of the image inside of an area of the given size.
+    pub fn tile_size(&self, area: Size, image: Size) -> Size {
+        if image.width() <= 0.0 || image.height() <= 0.0 {
+            return Size::new(0.0, 0.0);
+        }
+
+        let scale_x = area.width() / image.width();
+        let scale_y = area.height() / image.height();
+        let scale = |s: f64| Size::new(image.width() * s, image.height() * s);
+
+        match self.fit {
+            ImageFit::None => image,
+            ImageFit::Stretch => area,
+            ImageFit::Contain => scale(scale_x.min(scale_y)),
+            ImageFit::Cover => scale(scale_x.max(scale_y)),
+        }
+    }
+
+    // reads a brush like `url(header.png) no-repeat cover 0 8`
+    pub(crate) fn from_expression(expression: &Expression) -> Option<Self> {
+        let parts = match expression {
+            Expression::Complex(parts) => parts.as_slice(),
+            _ => std::slice::from_ref(expression),
+        };
+
+        let mut brush = match parts.first() {
+            Some(Expression::Method(name, args)) if name == "url" && args.len() == 1 => {
+                match &args[0] {
+                    Expression::Other(source) => ImageBrush::new(source.trim_matches('\'')),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        let mut offset = vec![];
+
+        for part in &parts[1..] {
+            match part {
+                Expression::Other(keyword) => match &keyword[..] {
+                    "no-repeat" | "repeat" | "repeat-x" | "repeat-y" => {
+                        brush.repeat = ImageRepeat::from(&keyword[..])
+                    }
+                    "stretch" | "contain" | "cover" => brush.fit = ImageFit::from(&keyword[..]),
+                    _ => return None,
+                },
+                Expression::Number(number, unit) if unit.is_empty() || unit == "px" => {
+                    offset.push(f64::from(*number))
+                }
+                _ => return None,
+            }
+        }
+
+        match offset[..] {
+            [] => {}
+            [x, y] => brush.offset = Point::new(x, y),
+            _ => return None,
+        }
+
+        Some(brush)
+    }
+}
+
+impl From<&str> for ImageBrush {
+    fn from(s: &str) -> ImageBrush {
+        ImageBrush::new(s)
+    }
+}
+
+impl From<String> for ImageBrush {
+    fn from(s: String) -> ImageBrush {
+        ImageBrush::new(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let brush = Brush::from("url(assets/tile.png)");
+        assert_eq!(brush, Brush::Image(ImageBrush::new("assets/tile.png")));
+
+        let brush = Brush::from("url(header) no-repeat cover 0 8px");
+        assert_eq!(
+            brush,
+            Brush::Image(
+                ImageBrush::new("header")
+                    .repeat(ImageRepeat::NoRepeat)
+                    .fit(ImageFit::Cover)
+                    .offset((0.0, 8.0))
+            )
+        );
+
+        assert!(Expression::from("url(header) sideways").brush().is_none());
+        assert!(Expression::from("url(header) 4").brush().is_none());
+        assert!(!Brush::from("url(header)").is_transparent());
+    }
+
+    #[test]
+    fn test_tile_size() {
+        let area = Size::new(200.0, 100.0);
+        let image = Size::new(50.0, 50.0);
+
+        let brush = ImageBrush::new("tile");
+        assert_eq!(brush.tile_size(area, image), image);
+        assert_eq!(brush.clone().fit("stretch").tile_size(area, image), area);
+        assert_eq!(
+            brush.clone().fit("contain").tile_size(area, image),
+            Size::new(100.0, 100.0)
+        );
+        assert_eq!(
+            brush.fit("cover").tile_size(area, image),
+            Size::new(200.0, 200.0)
+        );
+    }
+}
@@ -3,15 +3,19 @@
 pub use self::alignment::*;
 pub use self::angle::*;
 pub use self::border::*;
+pub use self::box_shadow::*;
 pub use self::brush::*;
 pub use self::color::*;
 pub use self::constraint::*;
+pub use self::corner_radius::*;
 pub use self::dirty_size::*;
 pub use self::expression::*;
 pub use self::f32_cmp::*;
 pub use self::f64_cmp::*;
 pub use self::filter::*;
+pub use self::flow_direction::*;
 pub use self::gradients::*;
+pub use self::image_brush::*;
 pub use self::number::*;
 pub use self::orientation::*;
 pub use self::point::*;
@@ -29,15 +33,19 @@ pub use self::visibility::*;
 mod alignment;
 mod angle;
 mod border;
+mod box_shadow;
 mod brush;
 mod color;
 mod constraint;
+mod corner_radius;
 mod dirty_size;
 mod expression;
 mod f32_cmp;
 mod f64_cmp;
 mod filter;
+mod flow_direction;
 mod gradients;
+mod image_brush;
 mod number;
 mod orientation;
 mod point;